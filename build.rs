@@ -1,17 +1,47 @@
+#![allow(non_local_definitions)]
+
 #[macro_use]
 extern crate failure;
 extern crate tonic_build;
 extern crate walkdir;
 
+use std::env;
+use std::fs;
 use std::io::Error as IoError;
 use std::path::{Path, PathBuf};
 use std::process::exit;
 
 use walkdir::{DirEntry, Error as WalkDirError, WalkDir};
 
+/// Messages in the config proto that are read from/written to pachctl's
+/// config file
+const CONFIG_MESSAGES: &[&str] = &[
+    ".config.Config",
+    ".config.ConfigV1",
+    ".config.ConfigV2",
+    ".config.Context",
+];
+
+/// The Cargo feature that enables each proto, by the prefix of its path
+/// under `./proto`. The first matching prefix wins, and protos without one
+/// are always compiled.
+const FEATURE_PROTOS: &[(&str, &str)] = &[
+    ("client/admin/v1_", "legacy-versions"),
+    ("client/admin/", "admin"),
+    ("client/auth/", "auth"),
+    ("client/debug/", "debug"),
+    ("client/enterprise/", "enterprise"),
+    ("client/pfs/", "pfs"),
+    ("client/pps/", "pps"),
+    ("client/transaction/", "transaction"),
+];
+
 #[derive(Debug, Fail)]
 enum BuildError {
-    #[fail(display = "Could not walk dir, have you run `make init`?: {}", err)]
+    #[fail(
+        display = "Could not walk dir, have you run `make proto`, or else enabled the `pregenerated` feature?: {}",
+        err
+    )]
     WalkDir { err: WalkDirError },
     #[fail(display = "{}", err)]
     Io { err: IoError },
@@ -55,19 +85,74 @@ where
     Ok(entries)
 }
 
-fn run() -> Result<(), BuildError> {
-    let protos: Vec<PathBuf> = find("./proto", "proto")?.into_iter().map(|e| e.into_path()).collect();
+/// Where the bindings are written to with the `regenerate` feature, and
+/// read from with the `pregenerated` feature.
+const GEN_DIR: &str = "./src/gen";
+
+/// Checks whether a Cargo feature is enabled.
+fn has_feature(feature: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    env::var_os(var).is_some()
+}
+
+/// Checks whether the feature that enables a proto, if any, is enabled.
+fn is_enabled(proto: &Path) -> bool {
+    let path = proto.strip_prefix("./proto").unwrap_or(proto).to_string_lossy();
+    match FEATURE_PROTOS.iter().find(|(prefix, _)| path.starts_with(prefix)) {
+        Some((_, feature)) => has_feature(feature),
+        None => true,
+    }
+}
 
-    tonic_build::configure()
-        .build_server(false)
-        .format(false) // disable code formatting since docs.rs will otherwise break
-        .compile(&protos.as_slice(), &["./proto".into()])?;
+fn run(regenerate: bool) -> Result<(), BuildError> {
+    // The committed bindings need to cover every feature, so they're
+    // generated from every proto
+    let protos: Vec<PathBuf> = find("./proto", "proto")?
+        .into_iter()
+        .map(|e| e.into_path())
+        .filter(|path| regenerate || is_enabled(path))
+        .collect();
+
+    // Servers are only needed for the fake pachd in `testing`, but the
+    // committed bindings include them so that it works with `pregenerated`
+    let mut builder = tonic_build::configure().build_server(regenerate || has_feature("testing"));
+    if regenerate {
+        fs::create_dir_all(GEN_DIR)?;
+        builder = builder.out_dir(GEN_DIR);
+    } else {
+        // disable code formatting since docs.rs will otherwise break
+        builder = builder.format(false);
+    }
+
+    // pachctl's config file is JSON, so the config messages need to be
+    // (de)serializable
+    for message in CONFIG_MESSAGES {
+        builder = builder.type_attribute(
+            message,
+            "#[derive(serde::Serialize, serde::Deserialize)] #[serde(default)]",
+        );
+    }
+
+    // pachctl omits empty config versions, so we do too
+    for field in &[".config.Config.v1", ".config.Config.v2"] {
+        builder = builder.field_attribute(field, "#[serde(skip_serializing_if = \"Option::is_none\")]");
+    }
+
+    builder.compile(protos.as_slice(), &["./proto".into()])?;
 
     Ok(())
 }
 
 fn main() {
-    if let Err(err) = run() {
+    // The committed bindings are used as-is, so there's nothing to build,
+    // unless a maintainer is regenerating them
+    let regenerate = has_feature("regenerate");
+    if has_feature("pregenerated") && !regenerate {
+        println!("cargo:rerun-if-changed=build.rs");
+        return;
+    }
+
+    if let Err(err) = run(regenerate) {
         eprintln!("{}", err);
         exit(1);
     }
@@ -1,4 +1,17 @@
 #!/bin/bash
 set -ex
+
+# Every feature but `regenerate`, which rewrites the bindings under src/gen
+FEATURES=admin,auth,blocking,debug,enterprise,legacy-versions,pfs,pps,put-dir,testing,transaction
+
+# Against bindings generated from ./proto, and against the committed ones
+cargo test --features $FEATURES
+cargo test --no-default-features --features pregenerated,$FEATURES
+
+# The committed bindings must be what ./proto generates
+cargo build --features regenerate
+git diff --exit-code src/gen
+test -z "$(git ls-files --others --exclude-standard src/gen)"
+
 cargo run --example hello_world -- grpc://$(minikube ip):30650
 cargo run --example opencv -- grpc://$(minikube ip):30650
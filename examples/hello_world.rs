@@ -7,19 +7,20 @@ extern crate tonic;
 use std::env;
 use std::error::Error;
 
-use pachyderm::pfs::{api_client::ApiClient as PfsClient, CreateRepoRequest, Repo};
+use pachyderm::pfs::{CreateRepoRequest, Repo};
+use pachyderm::Client;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    // Connect to the address passed in, or otherwise fallback to the active
+    // pachctl context
     let mut args = env::args().collect::<Vec<String>>();
-    let pachd_address = if args.len() > 1 {
-        args.pop().unwrap()
+    let client = if args.len() > 1 {
+        Client::connect(&args.pop().unwrap()).await?
     } else {
-        "grpc://localhost:30650".into()
+        Client::from_config().await?
     };
 
-    let mut client = PfsClient::connect(pachd_address.clone()).await?;
-
     let request = tonic::Request::new(CreateRepoRequest {
         repo: Some(Repo {
             name: "hello-world".into(),
@@ -28,7 +29,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         update: false,
     });
 
-    let response = client.create_repo(request).await?;
+    let response = client.pfs().create_repo(request).await?;
 
     println!("Response: {:?}", response);
 
@@ -3,6 +3,8 @@
 //! See also the equivalent example in the python library:
 //! https://github.com/pachyderm/python-pachyderm/blob/master/examples/opencv/opencv.py
 
+#![allow(clippy::field_reassign_with_default)]
+
 extern crate futures;
 extern crate pachyderm;
 extern crate tokio;
@@ -12,10 +14,11 @@ use std::env;
 use std::error::Error;
 
 use pachyderm::pfs::{
-    api_client::ApiClient as PfsClient, Commit, CreateRepoRequest, File, FinishCommitRequest, PutFileRequest, Repo,
+    api_client::ApiClient as PfsClient, Commit, CreateRepoRequest, File, FinishCommitRequest, PutFileRequest,
     StartCommitRequest,
 };
 use pachyderm::pps::{api_client::ApiClient as PpsClient, CreatePipelineRequest, Input, PfsInput, Pipeline, Transform};
+use pachyderm::Client;
 
 use futures::stream;
 use tonic::transport::Channel;
@@ -77,7 +80,7 @@ async fn put_file_url(
 
 async fn create_images_repo(pfs_client: &mut PfsClient<Channel>) -> Result<(), Box<dyn Error>> {
     let mut request = CreateRepoRequest::default();
-    request.repo = Some(Repo { name: "images".into() });
+    request.repo = Some("images".parse()?);
     pfs_client.create_repo(Request::new(request)).await?;
     Ok(())
 }
@@ -116,10 +119,7 @@ async fn put_example_images(pfs_client: &mut PfsClient<Channel>) -> Result<(), B
     // put a file from URL
     put_file_url(
         pfs_client,
-        Commit {
-            repo: Some(Repo { name: "images".into() }),
-            id: "master".into(),
-        },
+        "images@master".parse()?,
         "46Q8nDz.jpg",
         "http://imgur.com/46Q8nDz.jpg",
     )
@@ -127,7 +127,7 @@ async fn put_example_images(pfs_client: &mut PfsClient<Channel>) -> Result<(), B
 
     // put multiple files from URLs in a single a commit
     let mut parent = Commit::default();
-    parent.repo = Some(Repo { name: "images".into() });
+    parent.repo = Some("images".parse()?);
 
     let mut commit = StartCommitRequest::default();
     commit.parent = Some(parent);
@@ -158,15 +158,17 @@ async fn put_example_images(pfs_client: &mut PfsClient<Channel>) -> Result<(), B
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    // Connect to the address passed in, or otherwise fallback to the active
+    // pachctl context
     let mut args = env::args().collect::<Vec<String>>();
-    let pachd_address = if args.len() > 1 {
-        args.pop().unwrap()
+    let client = if args.len() > 1 {
+        Client::connect(&args.pop().unwrap()).await?
     } else {
-        "grpc://localhost:30650".into()
+        Client::from_config().await?
     };
 
-    let mut pfs_client = PfsClient::connect(pachd_address.clone()).await?;
-    let mut pps_client = PpsClient::connect(pachd_address.clone()).await?;
+    let mut pfs_client = client.pfs();
+    let mut pps_client = client.pps();
     create_images_repo(&mut pfs_client).await?;
     create_edges_pipeline(&mut pps_client).await?;
     create_montage_pipeline(&mut pps_client).await?;
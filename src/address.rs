@@ -0,0 +1,157 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// The port pachd is exposed on by default.
+pub const DEFAULT_PACHD_PORT: u16 = 30650;
+
+/// The scheme of a pachd address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    /// `grpc://`, i.e. plaintext.
+    Grpc,
+    /// `grpcs://`, i.e. TLS.
+    Grpcs,
+    /// `http://`, i.e. plaintext.
+    Http,
+    /// `https://`, i.e. TLS.
+    Https,
+}
+
+impl Scheme {
+    /// Whether connections with this scheme use TLS.
+    pub fn is_secured(self) -> bool {
+        match self {
+            Scheme::Grpcs | Scheme::Https => true,
+            Scheme::Grpc | Scheme::Http => false,
+        }
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Scheme::Grpc => "grpc",
+            Scheme::Grpcs => "grpcs",
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+        })
+    }
+}
+
+/// A pachd address, as accepted by pachctl, e.g. `grpcs://pachd.example.com`.
+///
+/// The scheme and port are optional. Without a scheme, TLS is used only if
+/// it has been configured, e.g. via a context's `server_cas`. The port
+/// defaults to 30650.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PachdAddress {
+    /// The scheme, if one was given.
+    pub scheme: Option<Scheme>,
+    /// The hostname or IP address of pachd.
+    pub host: String,
+    /// The port of pachd.
+    pub port: u16,
+}
+
+impl PachdAddress {
+    /// The address of a pachd running on this machine at the default port.
+    pub fn localhost() -> Self {
+        PachdAddress {
+            scheme: None,
+            host: "localhost".into(),
+            port: DEFAULT_PACHD_PORT,
+        }
+    }
+
+    /// Whether the address explicitly asks for TLS, i.e. it uses `grpcs://`
+    /// or `https://`.
+    pub fn is_secured(&self) -> bool {
+        self.scheme.is_some_and(Scheme::is_secured)
+    }
+
+    /// Gets the URI to connect to via tonic.
+    pub(crate) fn uri(&self, secured: bool) -> String {
+        format!("{}://{}", if secured { "https" } else { "http" }, self.authority())
+    }
+
+    fn authority(&self) -> String {
+        if self.host.contains(':') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+impl FromStr for PachdAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |msg: &str| Error::InvalidAddress(format!("{} in `{}`", msg, s));
+
+        let (scheme, authority) = match s.find("://") {
+            Some(i) => {
+                let scheme = match &s[..i] {
+                    "grpc" => Scheme::Grpc,
+                    "grpcs" => Scheme::Grpcs,
+                    "http" => Scheme::Http,
+                    "https" => Scheme::Https,
+                    _ => return Err(invalid("unrecognized scheme")),
+                };
+                (Some(scheme), &s[i + 3..])
+            }
+            None => (None, s),
+        };
+        let authority = authority.strip_suffix('/').unwrap_or(authority);
+
+        if authority.is_empty() {
+            return Err(invalid("missing host"));
+        } else if authority.contains('@') {
+            return Err(invalid("unexpected login credentials"));
+        } else if authority.contains('/') || authority.contains('?') || authority.contains('#') {
+            return Err(invalid("unexpected path, query string or fragment"));
+        }
+
+        // Split off the port, taking care not to mistake the colons in a
+        // bracketed IPv6 address for one
+        let (host, port) = if let Some(rest) = authority.strip_prefix('[') {
+            let end = rest.find(']').ok_or_else(|| invalid("unterminated IPv6 address"))?;
+            match &rest[end + 1..] {
+                "" => (&rest[..end], None),
+                port => match port.strip_prefix(':') {
+                    Some(port) => (&rest[..end], Some(port)),
+                    None => return Err(invalid("unexpected characters after IPv6 address")),
+                },
+            }
+        } else {
+            match authority.rfind(':') {
+                Some(i) if authority[..i].contains(':') => (authority, None),
+                Some(i) => (&authority[..i], Some(&authority[i + 1..])),
+                None => (authority, None),
+            }
+        };
+
+        if host.is_empty() {
+            return Err(invalid("missing host"));
+        }
+
+        let port = match port {
+            Some(port) => port.parse().map_err(|_| invalid("invalid port"))?,
+            None => DEFAULT_PACHD_PORT,
+        };
+
+        Ok(PachdAddress {
+            scheme,
+            host: host.into(),
+            port,
+        })
+    }
+}
+
+impl fmt::Display for PachdAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme.unwrap_or(Scheme::Grpc), self.authority())
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use tonic::Status;
+
+use crate::api::PfsApi;
+use crate::pfs::{Commit, CommitInfo, InspectCommitRequest, Repo};
+use crate::reference::parse_ancestry;
+use crate::Error;
+
+/// Resolves commits given by branch or with ancestry suffixes, e.g.
+/// `master`, `master^2`, `master~3` or `<id>^`, to commit IDs, by walking
+/// back through commits' parents on the client.
+///
+/// pachd is only ever asked about plain branches and commit IDs, so this
+/// works with versions of pachd that don't understand ancestry suffixes,
+/// e.g. via `Client::versioned_pfs`, as well as with mocks of `PfsApi`.
+///
+/// The info of each finished commit inspected along the way is cached, as
+/// finished commits don't change, so resolving several commits on the same
+/// branch only inspects each commit once. Branch heads are never cached,
+/// since they move; call `clear` after deleting commits, which reparents
+/// their children.
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+/// use pachyderm::CommitResolver;
+///
+/// let mut resolver = CommitResolver::new();
+/// let mut pfs = client.pfs();
+/// let grandparent = resolver.resolve(&mut pfs, &"images@master^2".parse()?).await?;
+/// println!("{}", grandparent.id);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CommitResolver {
+    /// Finished commits' infos, by repo and commit ID
+    cache: HashMap<(String, String), CommitInfo>,
+}
+
+impl CommitResolver {
+    /// Creates a resolver with an empty cache.
+    pub fn new() -> Self {
+        CommitResolver::default()
+    }
+
+    /// Resolves a commit to its ID, returning it in the same repo. Fails
+    /// with `Error::CommitNotFound` if the ancestry goes back further than
+    /// the commit's oldest ancestor.
+    pub async fn resolve<P: PfsApi>(&mut self, pfs: &mut P, commit: &Commit) -> Result<Commit, Error> {
+        let info = self.inspect(pfs, commit).await?;
+        Ok(Commit {
+            repo: commit.repo.clone(),
+            id: info.commit.map(|commit| commit.id).unwrap_or_default(),
+        })
+    }
+
+    /// Gets the info of a commit, which may be given by branch or with
+    /// ancestry suffixes.
+    pub async fn inspect<P: PfsApi>(&mut self, pfs: &mut P, commit: &Commit) -> Result<CommitInfo, Error> {
+        let repo = commit
+            .repo
+            .as_ref()
+            .ok_or_else(|| Error::InvalidReference(format!("missing repo in `{}`", commit)))?;
+        let (base, generations) =
+            parse_ancestry(&commit.id).map_err(|msg| Error::InvalidReference(format!("{} in `{}`", msg, commit)))?;
+
+        let mut info = self.inspect_id(pfs, repo, base).await?;
+        for _ in 0..generations {
+            let parent = match &info.parent_commit {
+                Some(parent) => parent.id.clone(),
+                None => {
+                    return Err(Error::CommitNotFound(Status::not_found(format!(
+                        "commit {} not found in repo {}",
+                        commit.id, repo.name
+                    ))))
+                }
+            };
+            info = self.inspect_id(pfs, repo, &parent).await?;
+        }
+        Ok(info)
+    }
+
+    /// Forgets every cached commit.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Inspects a commit given by ID or branch, from the cache if it's
+    /// there.
+    async fn inspect_id<P: PfsApi>(&mut self, pfs: &mut P, repo: &Repo, id: &str) -> Result<CommitInfo, Error> {
+        if let Some(info) = self.cache.get(&(repo.name.clone(), id.to_string())) {
+            return Ok(info.clone());
+        }
+
+        let request = InspectCommitRequest {
+            commit: Some(Commit {
+                repo: Some(repo.clone()),
+                id: id.into(),
+            }),
+            ..Default::default()
+        };
+        let info = pfs.inspect_commit(request).await?;
+        if let (Some(commit), Some(_)) = (&info.commit, &info.finished) {
+            self.cache.insert((repo.name.clone(), commit.id.clone()), info.clone());
+        }
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Timestamp;
+
+    use super::*;
+
+    /// A PFS holding a chain of commits in one repo, which records the
+    /// commits it's asked to inspect.
+    #[derive(Default)]
+    struct MockPfs {
+        commits: HashMap<String, CommitInfo>,
+        branches: HashMap<String, String>,
+        inspected: Vec<String>,
+    }
+
+    impl MockPfs {
+        /// Creates `a` <- `b` <- `c` <- `d`, where `master` is at `c` and
+        /// `dev` is at `d`, which is still open.
+        fn new() -> Self {
+            let mut pfs = MockPfs::default();
+            pfs.add("a", None, true);
+            pfs.add("b", Some("a"), true);
+            pfs.add("c", Some("b"), true);
+            pfs.add("d", Some("c"), false);
+            pfs.branches.insert("master".into(), "c".into());
+            pfs.branches.insert("dev".into(), "d".into());
+            pfs
+        }
+
+        fn add(&mut self, id: &str, parent: Option<&str>, finished: bool) {
+            let info = CommitInfo {
+                commit: Some(commit(id)),
+                parent_commit: parent.map(commit),
+                finished: Some(Timestamp::default()).filter(|_| finished),
+                ..Default::default()
+            };
+            self.commits.insert(id.into(), info);
+        }
+    }
+
+    #[tonic::async_trait]
+    impl PfsApi for MockPfs {
+        async fn inspect_commit(&mut self, request: InspectCommitRequest) -> Result<CommitInfo, Error> {
+            let id = request.commit.unwrap().id;
+            self.inspected.push(id.clone());
+            let id = self.branches.get(&id).unwrap_or(&id);
+            self.commits
+                .get(id)
+                .cloned()
+                .ok_or_else(|| Error::CommitNotFound(Status::not_found(format!("commit {} not found", id))))
+        }
+    }
+
+    fn commit(id: &str) -> Commit {
+        Commit {
+            repo: Some(Repo { name: "images".into() }),
+            id: id.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_ancestry() {
+        let mut pfs = MockPfs::new();
+        let mut resolver = CommitResolver::new();
+
+        for (given, expected) in &[
+            ("master", "c"),
+            ("master^", "b"),
+            ("master~2", "a"),
+            ("master^^", "a"),
+            ("dev^1~1", "b"),
+            ("c~0", "c"),
+            ("d^3", "a"),
+        ] {
+            let resolved = resolver.resolve(&mut pfs, &commit(given)).await.unwrap();
+            assert_eq!(resolved, commit(expected), "resolving `{}`", given);
+        }
+        // pachd is only asked about plain branches and commit IDs
+        assert!(pfs.inspected.iter().all(|id| parse_ancestry(id).unwrap().1 == 0));
+    }
+
+    #[tokio::test]
+    async fn fails_past_the_oldest_ancestor() {
+        let mut pfs = MockPfs::new();
+        let mut resolver = CommitResolver::new();
+
+        let err = resolver.resolve(&mut pfs, &commit("master~3")).await.unwrap_err();
+        assert!(matches!(err, Error::CommitNotFound(_)), "{:?}", err);
+        let err = resolver.resolve(&mut pfs, &commit("a^")).await.unwrap_err();
+        assert!(matches!(err, Error::CommitNotFound(_)), "{:?}", err);
+        let err = resolver.resolve(&mut pfs, &commit("missing^")).await.unwrap_err();
+        assert!(matches!(err, Error::CommitNotFound(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_ancestry() {
+        let mut pfs = MockPfs::new();
+        let mut resolver = CommitResolver::new();
+
+        let err = resolver.resolve(&mut pfs, &commit("master^x")).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidReference(_)), "{:?}", err);
+        let no_repo = Commit {
+            repo: None,
+            id: "master".into(),
+        };
+        let err = resolver.resolve(&mut pfs, &no_repo).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidReference(_)), "{:?}", err);
+        assert!(pfs.inspected.is_empty());
+    }
+
+    #[tokio::test]
+    async fn re_resolves_branch_heads() {
+        let mut pfs = MockPfs::new();
+        let mut resolver = CommitResolver::new();
+
+        assert_eq!(
+            resolver.resolve(&mut pfs, &commit("master")).await.unwrap(),
+            commit("c")
+        );
+        pfs.add("e", Some("c"), true);
+        pfs.branches.insert("master".into(), "e".into());
+        assert_eq!(
+            resolver.resolve(&mut pfs, &commit("master")).await.unwrap(),
+            commit("e")
+        );
+        assert_eq!(
+            resolver.resolve(&mut pfs, &commit("master^")).await.unwrap(),
+            commit("c")
+        );
+        assert_eq!(pfs.inspected.iter().filter(|id| *id == "master").count(), 3);
+    }
+
+    #[tokio::test]
+    async fn caches_only_finished_commits() {
+        let mut pfs = MockPfs::new();
+        let mut resolver = CommitResolver::new();
+
+        resolver.resolve(&mut pfs, &commit("d~3")).await.unwrap();
+        assert_eq!(pfs.inspected, vec!["d", "c", "b", "a"]);
+
+        // Only `d`, which is open, is inspected again
+        pfs.inspected.clear();
+        resolver.resolve(&mut pfs, &commit("d~3")).await.unwrap();
+        resolver.resolve(&mut pfs, &commit("b")).await.unwrap();
+        assert_eq!(pfs.inspected, vec!["d"]);
+
+        // Once it's finished, it's cached too
+        pfs.add("d", Some("c"), true);
+        resolver.resolve(&mut pfs, &commit("d")).await.unwrap();
+        resolver.resolve(&mut pfs, &commit("d^")).await.unwrap();
+        assert_eq!(pfs.inspected, vec!["d", "d"]);
+
+        resolver.clear();
+        resolver.resolve(&mut pfs, &commit("c")).await.unwrap();
+        assert_eq!(pfs.inspected, vec!["d", "d", "c"]);
+    }
+}
@@ -0,0 +1,445 @@
+//! A trait per service, implemented by the service clients `Client` hands
+//! out, so that code using them can be unit tested against mocks rather than
+//! a live pachd. With `legacy-versions`, `versioned::PfsClient` implements
+//! `PfsApi` as well, for code that has to work with older versions of pachd.
+//!
+//! Each trait has a method per RPC, taking the request and returning the
+//! response's message. Calls that stream back responses return a
+//! `Streaming`, and calls that stream requests take a `RequestStream`.
+//! Bidirectional streaming calls aren't included. Every method has a default
+//! implementation that fails with `Code::Unimplemented`, so a mock only needs
+//! to implement the calls the code under test makes:
+//!
+//! ```
+//! use pachyderm::api::PfsApi;
+//! use pachyderm::pfs::{InspectRepoRequest, Repo, RepoInfo};
+//! use pachyderm::Error;
+//!
+//! /// Gets the size of a repo. In production, this is passed `client.pfs()`.
+//! async fn repo_size<P: PfsApi>(pfs: &mut P, name: &str) -> Result<u64, Error> {
+//!     let request = InspectRepoRequest {
+//!         repo: Some(Repo { name: name.into() }),
+//!     };
+//!     Ok(pfs.inspect_repo(request).await?.size_bytes)
+//! }
+//!
+//! struct MockPfs;
+//!
+//! #[tonic::async_trait]
+//! impl PfsApi for MockPfs {
+//!     async fn inspect_repo(&mut self, request: InspectRepoRequest) -> Result<RepoInfo, Error> {
+//!         Ok(RepoInfo {
+//!             repo: request.repo,
+//!             size_bytes: 42,
+//!             ..Default::default()
+//!         })
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! assert_eq!(repo_size(&mut MockPfs, "images").await.unwrap(), 42);
+//! # }
+//! ```
+
+use std::pin::Pin;
+
+use futures::stream::{Stream, StreamExt};
+use tonic::transport::Channel;
+use tonic::Status;
+
+#[cfg(feature = "admin")]
+use crate::admin::{self, api_client::ApiClient as AdminClient};
+#[cfg(feature = "auth")]
+use crate::auth::{self, api_client::ApiClient as AuthClient};
+#[cfg(feature = "debug")]
+use crate::debug::{self, debug_client::DebugClient};
+#[cfg(feature = "enterprise")]
+use crate::enterprise::{self, api_client::ApiClient as EnterpriseClient};
+use crate::health::health_client::HealthClient;
+#[cfg(feature = "pfs")]
+use crate::pfs::{self, api_client::ApiClient as PfsClient, object_api_client::ObjectApiClient};
+#[cfg(feature = "pps")]
+use crate::pps::{self, api_client::ApiClient as PpsClient};
+#[cfg(feature = "transaction")]
+use crate::transaction::{self, api_client::ApiClient as TransactionClient, TransactionChannel};
+use crate::version::{self, api_client::ApiClient as VersionClient};
+use crate::Error;
+
+/// A stream of responses from pachd.
+pub type Streaming<T> = Pin<Box<dyn Stream<Item = Result<T, Error>> + Send + 'static>>;
+
+/// A stream of requests to pachd.
+pub type RequestStream<T> = Pin<Box<dyn Stream<Item = T> + Send + Sync + 'static>>;
+
+/// Defines a service trait, and implements it for the generated client over
+/// each of the given channel types. Methods are listed as `unary`,
+/// `server_streaming` or `client_streaming`, followed by their signature, in
+/// which the request is left out for calls that take `google.protobuf.Empty`.
+macro_rules! service_trait {
+    (
+        $(#[$attr:meta])*
+        pub trait $trait:ident for $client:ident<$($(#[$channel_attr:meta])* $channel:ty),+ $(,)?> $methods:tt
+    ) => {
+        service_trait!(@trait [$(#[$attr])* $trait] [] $methods);
+        $(service_trait!(@impl [$(#[$channel_attr])* $trait $client $channel] [] $methods);)+
+    };
+
+    // Declares each method in the trait, by default failing as unimplemented
+    (@trait [$(#[$attr:meta])* $trait:ident] [$($acc:tt)*] {}) => {
+        $(#[$attr])*
+        #[tonic::async_trait]
+        pub trait $trait: Send {
+            $($acc)*
+        }
+    };
+    (@trait $def:tt [$($acc:tt)*] { unary $method:ident() -> $response:ty; $($rest:tt)* }) => {
+        service_trait!(@trait $def [$($acc)*
+            #[allow(missing_docs)]
+            async fn $method(&mut self) -> Result<$response, Error> {
+                Err(unimplemented(stringify!($method)))
+            }
+        ] { $($rest)* });
+    };
+    (@trait $def:tt [$($acc:tt)*] { unary $method:ident($request:ty) -> $response:ty; $($rest:tt)* }) => {
+        service_trait!(@trait $def [$($acc)*
+            #[allow(missing_docs)]
+            async fn $method(&mut self, _request: $request) -> Result<$response, Error> {
+                Err(unimplemented(stringify!($method)))
+            }
+        ] { $($rest)* });
+    };
+    (@trait $def:tt [$($acc:tt)*] { server_streaming $method:ident($request:ty) -> $item:ty; $($rest:tt)* }) => {
+        service_trait!(@trait $def [$($acc)*
+            #[allow(missing_docs)]
+            async fn $method(&mut self, _request: $request) -> Result<Streaming<$item>, Error> {
+                Err(unimplemented(stringify!($method)))
+            }
+        ] { $($rest)* });
+    };
+    (@trait $def:tt [$($acc:tt)*] { client_streaming $method:ident($request:ty) -> $response:ty; $($rest:tt)* }) => {
+        service_trait!(@trait $def [$($acc)*
+            #[allow(missing_docs)]
+            async fn $method(&mut self, _requests: RequestStream<$request>) -> Result<$response, Error> {
+                Err(unimplemented(stringify!($method)))
+            }
+        ] { $($rest)* });
+    };
+
+    // Implements each method by calling the generated client's method of the
+    // same name, which takes precedence since it's inherent
+    (@impl [$(#[$attr:meta])* $trait:ident $client:ident $channel:ty] [$($acc:tt)*] {}) => {
+        $(#[$attr])*
+        #[tonic::async_trait]
+        impl $trait for $client<$channel> {
+            $($acc)*
+        }
+    };
+    (@impl $def:tt [$($acc:tt)*] { unary $method:ident() -> $response:ty; $($rest:tt)* }) => {
+        service_trait!(@impl $def [$($acc)*
+            async fn $method(&mut self) -> Result<$response, Error> {
+                Ok(self.$method(()).await?.into_inner())
+            }
+        ] { $($rest)* });
+    };
+    (@impl $def:tt [$($acc:tt)*] { unary $method:ident($request:ty) -> $response:ty; $($rest:tt)* }) => {
+        service_trait!(@impl $def [$($acc)*
+            async fn $method(&mut self, request: $request) -> Result<$response, Error> {
+                Ok(self.$method(request).await?.into_inner())
+            }
+        ] { $($rest)* });
+    };
+    (@impl $def:tt [$($acc:tt)*] { server_streaming $method:ident($request:ty) -> $item:ty; $($rest:tt)* }) => {
+        service_trait!(@impl $def [$($acc)*
+            async fn $method(&mut self, request: $request) -> Result<Streaming<$item>, Error> {
+                Ok(streaming(self.$method(request).await?.into_inner()))
+            }
+        ] { $($rest)* });
+    };
+    (@impl $def:tt [$($acc:tt)*] { client_streaming $method:ident($request:ty) -> $response:ty; $($rest:tt)* }) => {
+        service_trait!(@impl $def [$($acc)*
+            async fn $method(&mut self, requests: RequestStream<$request>) -> Result<$response, Error> {
+                // Boxing the call proves to the compiler that it's `Send`
+                let call: futures::future::BoxFuture<_> = Box::pin(self.$method(requests));
+                Ok(call.await?.into_inner())
+            }
+        ] { $($rest)* });
+    };
+}
+
+#[cfg(feature = "pfs")]
+service_trait! {
+    /// The PFS API.
+    pub trait PfsApi for PfsClient<
+        Channel,
+        #[cfg(feature = "transaction")]
+        TransactionChannel,
+    > {
+        unary create_repo(pfs::CreateRepoRequest) -> ();
+        unary inspect_repo(pfs::InspectRepoRequest) -> pfs::RepoInfo;
+        unary list_repo(pfs::ListRepoRequest) -> pfs::ListRepoResponse;
+        unary delete_repo(pfs::DeleteRepoRequest) -> ();
+        unary start_commit(pfs::StartCommitRequest) -> pfs::Commit;
+        unary finish_commit(pfs::FinishCommitRequest) -> ();
+        unary inspect_commit(pfs::InspectCommitRequest) -> pfs::CommitInfo;
+        unary list_commit(pfs::ListCommitRequest) -> pfs::CommitInfos;
+        server_streaming list_commit_stream(pfs::ListCommitRequest) -> pfs::CommitInfo;
+        unary delete_commit(pfs::DeleteCommitRequest) -> ();
+        server_streaming flush_commit(pfs::FlushCommitRequest) -> pfs::CommitInfo;
+        server_streaming subscribe_commit(pfs::SubscribeCommitRequest) -> pfs::CommitInfo;
+        unary build_commit(pfs::BuildCommitRequest) -> pfs::Commit;
+        unary create_branch(pfs::CreateBranchRequest) -> ();
+        unary inspect_branch(pfs::InspectBranchRequest) -> pfs::BranchInfo;
+        unary list_branch(pfs::ListBranchRequest) -> pfs::BranchInfos;
+        unary delete_branch(pfs::DeleteBranchRequest) -> ();
+        client_streaming put_file(pfs::PutFileRequest) -> ();
+        unary copy_file(pfs::CopyFileRequest) -> ();
+        server_streaming get_file(pfs::GetFileRequest) -> Vec<u8>;
+        unary inspect_file(pfs::InspectFileRequest) -> pfs::FileInfo;
+        unary list_file(pfs::ListFileRequest) -> pfs::FileInfos;
+        server_streaming list_file_stream(pfs::ListFileRequest) -> pfs::FileInfo;
+        server_streaming walk_file(pfs::WalkFileRequest) -> pfs::FileInfo;
+        unary glob_file(pfs::GlobFileRequest) -> pfs::FileInfos;
+        server_streaming glob_file_stream(pfs::GlobFileRequest) -> pfs::FileInfo;
+        unary diff_file(pfs::DiffFileRequest) -> pfs::DiffFileResponse;
+        unary delete_file(pfs::DeleteFileRequest) -> ();
+        unary delete_all() -> ();
+        server_streaming fsck(pfs::FsckRequest) -> pfs::FsckResponse;
+        client_streaming file_operation_v2(pfs::FileOperationRequestV2) -> ();
+        server_streaming get_tar_v2(pfs::GetTarRequestV2) -> Vec<u8>;
+        server_streaming list_file_v2(pfs::ListFileRequest) -> pfs::FileInfoV2;
+        server_streaming glob_file_v2(pfs::GlobFileRequest) -> pfs::FileInfoV2;
+    }
+}
+
+/// Implements a service trait for one of the `versioned` clients, whose
+/// methods already convert to and from pachd's API version, and return this
+/// library's errors and streams. Methods are listed by signature, followed by
+/// any that have to be written out. Calls that the client doesn't have, as
+/// they're newer than the versions it supports, are left unimplemented.
+#[cfg(feature = "legacy-versions")]
+macro_rules! versioned_impl {
+    (
+        impl $trait:ident for $client:ty {
+            $($method:ident($request:ty) -> $response:ty;)*
+        }
+        $($manual:tt)*
+    ) => {
+        #[tonic::async_trait]
+        impl $trait for $client {
+            $(
+                async fn $method(&mut self, request: $request) -> Result<$response, Error> {
+                    self.$method(request).await
+                }
+            )*
+            $($manual)*
+        }
+    };
+}
+
+#[cfg(feature = "legacy-versions")]
+versioned_impl! {
+    impl PfsApi for crate::versioned::PfsClient {
+        create_repo(pfs::CreateRepoRequest) -> ();
+        inspect_repo(pfs::InspectRepoRequest) -> pfs::RepoInfo;
+        list_repo(pfs::ListRepoRequest) -> pfs::ListRepoResponse;
+        delete_repo(pfs::DeleteRepoRequest) -> ();
+        start_commit(pfs::StartCommitRequest) -> pfs::Commit;
+        finish_commit(pfs::FinishCommitRequest) -> ();
+        inspect_commit(pfs::InspectCommitRequest) -> pfs::CommitInfo;
+        list_commit(pfs::ListCommitRequest) -> pfs::CommitInfos;
+        list_commit_stream(pfs::ListCommitRequest) -> Streaming<pfs::CommitInfo>;
+        delete_commit(pfs::DeleteCommitRequest) -> ();
+        flush_commit(pfs::FlushCommitRequest) -> Streaming<pfs::CommitInfo>;
+        subscribe_commit(pfs::SubscribeCommitRequest) -> Streaming<pfs::CommitInfo>;
+        build_commit(pfs::BuildCommitRequest) -> pfs::Commit;
+        create_branch(pfs::CreateBranchRequest) -> ();
+        inspect_branch(pfs::InspectBranchRequest) -> pfs::BranchInfo;
+        list_branch(pfs::ListBranchRequest) -> pfs::BranchInfos;
+        delete_branch(pfs::DeleteBranchRequest) -> ();
+        copy_file(pfs::CopyFileRequest) -> ();
+        get_file(pfs::GetFileRequest) -> Streaming<Vec<u8>>;
+        inspect_file(pfs::InspectFileRequest) -> pfs::FileInfo;
+        list_file(pfs::ListFileRequest) -> pfs::FileInfos;
+        list_file_stream(pfs::ListFileRequest) -> Streaming<pfs::FileInfo>;
+        glob_file(pfs::GlobFileRequest) -> pfs::FileInfos;
+        glob_file_stream(pfs::GlobFileRequest) -> Streaming<pfs::FileInfo>;
+        diff_file(pfs::DiffFileRequest) -> pfs::DiffFileResponse;
+        delete_file(pfs::DeleteFileRequest) -> ();
+    }
+
+    async fn put_file(&mut self, requests: RequestStream<pfs::PutFileRequest>) -> Result<(), Error> {
+        // Boxing the call proves to the compiler that it's `Send`
+        let call: futures::future::BoxFuture<_> = Box::pin(self.put_file(requests));
+        call.await
+    }
+
+    async fn delete_all(&mut self) -> Result<(), Error> {
+        self.delete_all().await
+    }
+}
+
+#[cfg(feature = "pfs")]
+service_trait! {
+    /// PFS's object API.
+    pub trait ObjectApi for ObjectApiClient<Channel> {
+        client_streaming put_object(pfs::PutObjectRequest) -> pfs::Object;
+        client_streaming put_object_split(pfs::PutObjectRequest) -> pfs::Objects;
+        client_streaming put_objects(pfs::PutObjectRequest) -> ();
+        unary create_object(pfs::CreateObjectRequest) -> ();
+        server_streaming get_object(pfs::Object) -> Vec<u8>;
+        server_streaming get_objects(pfs::GetObjectsRequest) -> Vec<u8>;
+        client_streaming put_block(pfs::PutBlockRequest) -> ();
+        server_streaming get_block(pfs::GetBlockRequest) -> Vec<u8>;
+        server_streaming get_blocks(pfs::GetBlocksRequest) -> Vec<u8>;
+        server_streaming list_block(pfs::ListBlockRequest) -> pfs::Block;
+        unary tag_object(pfs::TagObjectRequest) -> ();
+        unary inspect_object(pfs::Object) -> pfs::ObjectInfo;
+        unary check_object(pfs::CheckObjectRequest) -> pfs::CheckObjectResponse;
+        server_streaming list_objects(pfs::ListObjectsRequest) -> pfs::ObjectInfo;
+        unary delete_objects(pfs::DeleteObjectsRequest) -> pfs::DeleteObjectsResponse;
+    }
+}
+
+#[cfg(feature = "pps")]
+service_trait! {
+    /// The PPS API.
+    pub trait PpsApi for PpsClient<
+        Channel,
+        #[cfg(feature = "transaction")]
+        TransactionChannel,
+    > {
+        unary create_job(pps::CreateJobRequest) -> pps::Job;
+        unary inspect_job(pps::InspectJobRequest) -> pps::JobInfo;
+        unary list_job(pps::ListJobRequest) -> pps::JobInfos;
+        server_streaming list_job_stream(pps::ListJobRequest) -> pps::JobInfo;
+        server_streaming flush_job(pps::FlushJobRequest) -> pps::JobInfo;
+        unary delete_job(pps::DeleteJobRequest) -> ();
+        unary stop_job(pps::StopJobRequest) -> ();
+        unary inspect_datum(pps::InspectDatumRequest) -> pps::DatumInfo;
+        unary list_datum(pps::ListDatumRequest) -> pps::ListDatumResponse;
+        server_streaming list_datum_stream(pps::ListDatumRequest) -> pps::ListDatumStreamResponse;
+        unary restart_datum(pps::RestartDatumRequest) -> ();
+        unary create_pipeline(pps::CreatePipelineRequest) -> ();
+        unary inspect_pipeline(pps::InspectPipelineRequest) -> pps::PipelineInfo;
+        unary list_pipeline(pps::ListPipelineRequest) -> pps::PipelineInfos;
+        unary delete_pipeline(pps::DeletePipelineRequest) -> ();
+        unary start_pipeline(pps::StartPipelineRequest) -> ();
+        unary stop_pipeline(pps::StopPipelineRequest) -> ();
+        unary run_pipeline(pps::RunPipelineRequest) -> ();
+        unary run_cron(pps::RunCronRequest) -> ();
+        unary create_secret(pps::CreateSecretRequest) -> ();
+        unary delete_secret(pps::DeleteSecretRequest) -> ();
+        unary list_secret() -> pps::SecretInfos;
+        unary inspect_secret(pps::InspectSecretRequest) -> pps::SecretInfo;
+        unary delete_all() -> ();
+        server_streaming get_logs(pps::GetLogsRequest) -> pps::LogMessage;
+        unary garbage_collect(pps::GarbageCollectRequest) -> pps::GarbageCollectResponse;
+        unary activate_auth(pps::ActivateAuthRequest) -> pps::ActivateAuthResponse;
+        unary update_job_state(pps::UpdateJobStateRequest) -> ();
+    }
+}
+
+#[cfg(feature = "auth")]
+service_trait! {
+    /// The auth API.
+    pub trait AuthApi for AuthClient<Channel> {
+        unary activate(auth::ActivateRequest) -> auth::ActivateResponse;
+        unary deactivate(auth::DeactivateRequest) -> auth::DeactivateResponse;
+        unary get_configuration(auth::GetConfigurationRequest) -> auth::GetConfigurationResponse;
+        unary set_configuration(auth::SetConfigurationRequest) -> auth::SetConfigurationResponse;
+        unary get_admins(auth::GetAdminsRequest) -> auth::GetAdminsResponse;
+        unary modify_admins(auth::ModifyAdminsRequest) -> auth::ModifyAdminsResponse;
+        unary get_cluster_role_bindings(auth::GetClusterRoleBindingsRequest) -> auth::GetClusterRoleBindingsResponse;
+        unary modify_cluster_role_binding(auth::ModifyClusterRoleBindingRequest) -> auth::ModifyClusterRoleBindingResponse;
+        unary authenticate(auth::AuthenticateRequest) -> auth::AuthenticateResponse;
+        unary authorize(auth::AuthorizeRequest) -> auth::AuthorizeResponse;
+        unary who_am_i(auth::WhoAmIRequest) -> auth::WhoAmIResponse;
+        unary get_scope(auth::GetScopeRequest) -> auth::GetScopeResponse;
+        unary set_scope(auth::SetScopeRequest) -> auth::SetScopeResponse;
+        unary get_acl(auth::GetAclRequest) -> auth::GetAclResponse;
+        unary set_acl(auth::SetAclRequest) -> auth::SetAclResponse;
+        unary get_oidc_login(auth::GetOidcLoginRequest) -> auth::GetOidcLoginResponse;
+        unary get_auth_token(auth::GetAuthTokenRequest) -> auth::GetAuthTokenResponse;
+        unary extend_auth_token(auth::ExtendAuthTokenRequest) -> auth::ExtendAuthTokenResponse;
+        unary revoke_auth_token(auth::RevokeAuthTokenRequest) -> auth::RevokeAuthTokenResponse;
+        unary set_groups_for_user(auth::SetGroupsForUserRequest) -> auth::SetGroupsForUserResponse;
+        unary modify_members(auth::ModifyMembersRequest) -> auth::ModifyMembersResponse;
+        unary get_groups(auth::GetGroupsRequest) -> auth::GetGroupsResponse;
+        unary get_users(auth::GetUsersRequest) -> auth::GetUsersResponse;
+        unary get_one_time_password(auth::GetOneTimePasswordRequest) -> auth::GetOneTimePasswordResponse;
+    }
+}
+
+#[cfg(feature = "admin")]
+service_trait! {
+    /// The admin API.
+    pub trait AdminApi for AdminClient<Channel> {
+        server_streaming extract(admin::ExtractRequest) -> admin::Op;
+        unary extract_pipeline(admin::ExtractPipelineRequest) -> admin::Op;
+        client_streaming restore(admin::RestoreRequest) -> ();
+        unary inspect_cluster() -> admin::ClusterInfo;
+    }
+}
+
+#[cfg(feature = "transaction")]
+service_trait! {
+    /// The transaction API.
+    pub trait TransactionApi for TransactionClient<Channel> {
+        unary batch_transaction(transaction::BatchTransactionRequest) -> transaction::TransactionInfo;
+        unary start_transaction(transaction::StartTransactionRequest) -> transaction::Transaction;
+        unary inspect_transaction(transaction::InspectTransactionRequest) -> transaction::TransactionInfo;
+        unary delete_transaction(transaction::DeleteTransactionRequest) -> ();
+        unary list_transaction(transaction::ListTransactionRequest) -> transaction::TransactionInfos;
+        unary finish_transaction(transaction::FinishTransactionRequest) -> transaction::TransactionInfo;
+        unary delete_all(transaction::DeleteAllRequest) -> ();
+    }
+}
+
+#[cfg(feature = "debug")]
+service_trait! {
+    /// The debug API.
+    pub trait DebugApi for DebugClient<Channel> {
+        server_streaming dump(debug::DumpRequest) -> Vec<u8>;
+        server_streaming profile(debug::ProfileRequest) -> Vec<u8>;
+        server_streaming binary(debug::BinaryRequest) -> Vec<u8>;
+    }
+}
+
+#[cfg(feature = "enterprise")]
+service_trait! {
+    /// The enterprise API.
+    pub trait EnterpriseApi for EnterpriseClient<Channel> {
+        unary activate(enterprise::ActivateRequest) -> enterprise::ActivateResponse;
+        unary get_state(enterprise::GetStateRequest) -> enterprise::GetStateResponse;
+        unary deactivate(enterprise::DeactivateRequest) -> enterprise::DeactivateResponse;
+    }
+}
+
+service_trait! {
+    /// The version API.
+    pub trait VersionApi for VersionClient<Channel> {
+        unary get_version() -> version::Version;
+    }
+}
+
+service_trait! {
+    /// The health API.
+    pub trait HealthApi for HealthClient<Channel> {
+        unary health() -> ();
+    }
+}
+
+/// Converts a response stream's errors.
+#[cfg_attr(not(any(feature = "pfs", feature = "debug")), allow(dead_code))]
+fn streaming<T, S>(stream: S) -> Streaming<T>
+where
+    S: Stream<Item = Result<T, Status>> + Send + 'static,
+{
+    Box::pin(stream.map(|item| item.map_err(Error::from)))
+}
+
+/// The error a trait's method returns when it isn't implemented.
+fn unimplemented(method: &str) -> Error {
+    Status::unimplemented(format!("`{}` isn't implemented", method)).into()
+}
@@ -0,0 +1,201 @@
+use std::convert::TryFrom;
+use std::io::{self, ErrorKind, Read};
+
+use prost::Message;
+use tonic::transport::Channel;
+
+use super::{iter_stream, Iter, Runtime};
+use crate::admin::api_client::ApiClient;
+use crate::admin::*;
+use crate::versioned::{finish_converted, stall_on_error};
+use crate::Error;
+
+/// A synchronous client for the admin API. Create one via `Client::admin`.
+///
+/// Each method blocks on the async client's method of the same name. Ops
+/// can also be extracted to, and restored from, the same format pachctl
+/// uses for `pachctl extract` and `pachctl restore`.
+#[derive(Clone, Debug)]
+pub struct AdminClient {
+    inner: ApiClient<Channel>,
+    runtime: Runtime,
+}
+
+impl AdminClient {
+    pub(crate) fn new(inner: ApiClient<Channel>, runtime: Runtime) -> Self {
+        AdminClient { inner, runtime }
+    }
+
+    server_streaming!(extract_ops = extract(ExtractRequest) -> Op);
+    unary!(extract_pipeline(ExtractPipelineRequest) -> Op);
+    unary!(inspect_cluster() -> ClusterInfo);
+
+    /// Extracts the cluster's state, returning a reader over it in the same
+    /// format as `pachctl extract`.
+    pub fn extract(&mut self, request: ExtractRequest) -> Result<OpReader, Error> {
+        Ok(OpReader {
+            ops: self.extract_ops(request)?,
+            buf: Vec::new(),
+            offset: 0,
+        })
+    }
+
+    /// Restores the cluster's state from ops, e.g. those from `extract_ops`.
+    pub fn restore_ops<I>(&mut self, ops: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = Op>,
+        I::IntoIter: Send + 'static,
+    {
+        let requests = ops.into_iter().map(|op| RestoreRequest {
+            op: Some(op),
+            ..Default::default()
+        });
+        self.runtime.block_on(self.inner.restore(iter_stream(requests)))?;
+        Ok(())
+    }
+
+    /// Restores the cluster's state from a reader in the same format as
+    /// `pachctl extract`. If the reader fails or contains an invalid op, the
+    /// restore is cancelled rather than restoring a partial state.
+    pub fn restore<R: Read + Send + 'static>(&mut self, reader: R) -> Result<(), Error> {
+        let mut reader = reader;
+        let requests = std::iter::from_fn(move || read_op(&mut reader).transpose()).map(|op| {
+            Ok(RestoreRequest {
+                op: Some(op?),
+                ..Default::default()
+            })
+        });
+        let (requests, failed) = stall_on_error(iter_stream(requests));
+        let mut inner = self.inner.clone();
+        self.runtime.block_on(finish_converted(inner.restore(requests), failed))
+    }
+}
+
+/// A reader over a cluster's extracted state, in the same format as
+/// `pachctl extract`, i.e. each op prefixed by its length as a 64-bit little
+/// endian integer.
+#[derive(Debug)]
+pub struct OpReader {
+    ops: Iter<Op>,
+    buf: Vec<u8>,
+    offset: usize,
+}
+
+impl Read for OpReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.offset == self.buf.len() {
+            let op = match self.ops.next() {
+                Some(op) => op?,
+                None => return Ok(0),
+            };
+            self.buf.clear();
+            self.buf.extend_from_slice(&(op.encoded_len() as i64).to_le_bytes());
+            op.encode(&mut self.buf).expect("vec should grow to fit the op");
+            self.offset = 0;
+        }
+
+        let read = buf.len().min(self.buf.len() - self.offset);
+        buf[..read].copy_from_slice(&self.buf[self.offset..self.offset + read]);
+        self.offset += read;
+        Ok(read)
+    }
+}
+
+/// Reads the next op in the format written by `OpReader`, or `None` at the
+/// end of the reader.
+fn read_op<R: Read>(reader: &mut R) -> Result<Option<Op>, Error> {
+    let mut len = [0; 8];
+    let mut filled = 0;
+    while filled < len.len() {
+        match reader.read(&mut len[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::Error::from(ErrorKind::UnexpectedEof).into()),
+            Ok(read) => filled += read,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    let len = u64::try_from(i64::from_le_bytes(len))
+        .map_err(|_| io::Error::new(ErrorKind::InvalidData, "negative op length"))?;
+    // The length isn't trusted, so the buffer only grows as the op is read,
+    // rather than being allocated up front
+    let mut buf = Vec::new();
+    reader.by_ref().take(len).read_to_end(&mut buf)?;
+    if (buf.len() as u64) < len {
+        return Err(io::Error::from(ErrorKind::UnexpectedEof).into());
+    }
+    let op = Op::decode(buf.as_slice()).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+    Ok(Some(op))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pfs::PutObjectRequest;
+
+    fn op(value: &[u8]) -> Op {
+        Op {
+            op1_11: Some(Op111 {
+                object: Some(PutObjectRequest {
+                    value: value.to_vec(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn encode(op: &Op) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        op.encode(&mut encoded).unwrap();
+        encoded
+    }
+
+    fn frame(len: i64, body: &[u8]) -> Vec<u8> {
+        let mut framed = len.to_le_bytes().to_vec();
+        framed.extend_from_slice(body);
+        framed
+    }
+
+    fn error_kind(mut reader: &[u8]) -> ErrorKind {
+        match read_op(&mut reader) {
+            Err(Error::Io(err)) => err.kind(),
+            result => panic!("expected an I/O error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn reads_ops_until_the_end() {
+        let ops = vec![op(b"first"), op(b""), op(&[7; 1000])];
+        let mut extracted = Vec::new();
+        for op in &ops {
+            extracted.extend(frame(op.encoded_len() as i64, &encode(op)));
+        }
+
+        let mut reader = extracted.as_slice();
+        for op in ops {
+            assert_eq!(read_op(&mut reader).unwrap(), Some(op));
+        }
+        assert_eq!(read_op(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_truncated_ops() {
+        assert_eq!(error_kind(&[1, 0, 0]), ErrorKind::UnexpectedEof);
+        let body = encode(&op(b"value"));
+        assert_eq!(
+            error_kind(&frame(body.len() as i64 + 1, &body)),
+            ErrorKind::UnexpectedEof
+        );
+        // The length isn't allocated up front, so a corrupt one just fails
+        assert_eq!(error_kind(&frame(i64::MAX, &body)), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_invalid_ops() {
+        assert_eq!(error_kind(&frame(-1, b"")), ErrorKind::InvalidData);
+        assert_eq!(error_kind(&frame(3, &[0xff, 0xff, 0xff])), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,48 @@
+use tonic::transport::Channel;
+
+use super::Runtime;
+use crate::auth::api_client::ApiClient;
+use crate::auth::*;
+use crate::Error;
+
+/// A synchronous client for the auth API. Create one via `Client::auth`.
+///
+/// Each method blocks on the async client's method of the same name. Note
+/// that tokens returned by e.g. `authenticate` aren't used automatically;
+/// set them via `Client::async_client().set_auth_token()`.
+#[derive(Clone, Debug)]
+pub struct AuthClient {
+    inner: ApiClient<Channel>,
+    runtime: Runtime,
+}
+
+impl AuthClient {
+    pub(crate) fn new(inner: ApiClient<Channel>, runtime: Runtime) -> Self {
+        AuthClient { inner, runtime }
+    }
+
+    unary!(activate(ActivateRequest) -> ActivateResponse);
+    unary!(deactivate(DeactivateRequest) -> DeactivateResponse);
+    unary!(get_configuration(GetConfigurationRequest) -> GetConfigurationResponse);
+    unary!(set_configuration(SetConfigurationRequest) -> SetConfigurationResponse);
+    unary!(get_admins(GetAdminsRequest) -> GetAdminsResponse);
+    unary!(modify_admins(ModifyAdminsRequest) -> ModifyAdminsResponse);
+    unary!(get_cluster_role_bindings(GetClusterRoleBindingsRequest) -> GetClusterRoleBindingsResponse);
+    unary!(modify_cluster_role_binding(ModifyClusterRoleBindingRequest) -> ModifyClusterRoleBindingResponse);
+    unary!(authenticate(AuthenticateRequest) -> AuthenticateResponse);
+    unary!(authorize(AuthorizeRequest) -> AuthorizeResponse);
+    unary!(who_am_i(WhoAmIRequest) -> WhoAmIResponse);
+    unary!(get_scope(GetScopeRequest) -> GetScopeResponse);
+    unary!(set_scope(SetScopeRequest) -> SetScopeResponse);
+    unary!(get_acl(GetAclRequest) -> GetAclResponse);
+    unary!(set_acl(SetAclRequest) -> SetAclResponse);
+    unary!(get_oidc_login(GetOidcLoginRequest) -> GetOidcLoginResponse);
+    unary!(get_auth_token(GetAuthTokenRequest) -> GetAuthTokenResponse);
+    unary!(extend_auth_token(ExtendAuthTokenRequest) -> ExtendAuthTokenResponse);
+    unary!(revoke_auth_token(RevokeAuthTokenRequest) -> RevokeAuthTokenResponse);
+    unary!(set_groups_for_user(SetGroupsForUserRequest) -> SetGroupsForUserResponse);
+    unary!(modify_members(ModifyMembersRequest) -> ModifyMembersResponse);
+    unary!(get_groups(GetGroupsRequest) -> GetGroupsResponse);
+    unary!(get_users(GetUsersRequest) -> GetUsersResponse);
+    unary!(get_one_time_password(GetOneTimePasswordRequest) -> GetOneTimePasswordResponse);
+}
@@ -0,0 +1,296 @@
+//! A synchronous client, for programs that don't otherwise use async.
+//!
+//! The clients here wrap the async service clients, and run each call on a
+//! runtime of their own, blocking the calling thread until the call
+//! completes. Responses are unwrapped, and errors are returned as `Error`s.
+//! Calls that stream back responses return iterators, or, for streams of
+//! bytes such as `GetFile`, implementations of `std::io::Read`.
+//! `PfsClient::open_file` opens a file for random access, via
+//! `std::io::Seek` as well.
+//!
+//! These clients must not be used from within an async runtime, since
+//! blocking would stall it; use the async clients there instead.
+//!
+//! ```no_run
+//! # fn run() -> Result<(), pachyderm::Error> {
+//! use std::io;
+//!
+//! use pachyderm::blocking::Client;
+//! use pachyderm::pfs::{Commit, File, GetFileRequest, Repo};
+//!
+//! let client = Client::connect("grpc://localhost:30650")?;
+//! let request = GetFileRequest {
+//!     file: Some(File {
+//!         commit: Some(Commit {
+//!             repo: Some(Repo { name: "images".into() }),
+//!             id: "master".into(),
+//!         }),
+//!         path: "/liberty.png".into(),
+//!     }),
+//!     ..Default::default()
+//! };
+//! let mut reader = client.pfs().get_file(request)?;
+//! io::copy(&mut reader, &mut io::stdout())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::io::{self, Read};
+use std::sync::{Arc, Mutex};
+
+use futures::stream::{self, Stream};
+use tokio::runtime::{Builder, Runtime as TokioRuntime};
+use tonic::codec::Streaming;
+
+use crate::Error;
+
+/// Defines a method that blocks on a unary call, returning its response.
+macro_rules! unary {
+    ($method:ident() -> $response:ty) => {
+        pub fn $method(&mut self) -> Result<$response, Error> {
+            Ok(self.runtime.block_on(self.inner.$method(()))?.into_inner())
+        }
+    };
+    ($method:ident($request:ty) -> $response:ty) => {
+        pub fn $method(&mut self, request: $request) -> Result<$response, Error> {
+            Ok(self.runtime.block_on(self.inner.$method(request))?.into_inner())
+        }
+    };
+}
+
+/// Defines a method that blocks on a call that streams back responses,
+/// returning an iterator over them. A different name can be given for the
+/// method, e.g. to leave the call's own name for a wrapper.
+macro_rules! server_streaming {
+    ($method:ident($request:ty) -> $item:ty) => {
+        server_streaming!($method = $method($request) -> $item);
+    };
+    ($name:ident = $method:ident($request:ty) -> $item:ty) => {
+        pub fn $name(&mut self, request: $request) -> Result<crate::blocking::Iter<$item>, Error> {
+            let stream = self.runtime.block_on(self.inner.$method(request))?.into_inner();
+            Ok(crate::blocking::Iter::new(self.runtime.clone(), stream))
+        }
+    };
+}
+
+/// Defines a method that blocks on a call that streams back bytes,
+/// returning a reader over them.
+macro_rules! bytes_streaming {
+    ($method:ident($request:ty)) => {
+        pub fn $method(&mut self, request: $request) -> Result<crate::blocking::BytesReader, Error> {
+            let stream = self.runtime.block_on(self.inner.$method(request))?.into_inner();
+            Ok(crate::blocking::BytesReader::new(self.runtime.clone(), stream))
+        }
+    };
+}
+
+/// Defines a method that streams requests from an iterator, and blocks
+/// until the call completes.
+macro_rules! client_streaming {
+    ($method:ident($request:ty) -> $response:ty) => {
+        pub fn $method<I>(&mut self, requests: I) -> Result<$response, Error>
+        where
+            I: IntoIterator<Item = $request>,
+            I::IntoIter: Send + 'static,
+        {
+            let requests = crate::blocking::iter_stream(requests);
+            Ok(self.runtime.block_on(self.inner.$method(requests))?.into_inner())
+        }
+    };
+}
+
+#[cfg(feature = "admin")]
+mod admin;
+#[cfg(feature = "auth")]
+mod auth;
+#[cfg(feature = "pfs")]
+mod pfs;
+#[cfg(feature = "pps")]
+mod pps;
+
+#[cfg(feature = "admin")]
+pub use self::admin::{AdminClient, OpReader};
+#[cfg(feature = "auth")]
+pub use self::auth::AuthClient;
+#[cfg(feature = "pfs")]
+pub use self::pfs::{PfsClient, PfsFileReader};
+#[cfg(feature = "pps")]
+pub use self::pps::PpsClient;
+
+/// A synchronous client for pachyderm's services, which wraps an async
+/// `pachyderm::Client`. Cloning it is cheap, and clones share the same
+/// connection and runtime.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Runtime,
+}
+
+impl Client {
+    /// Connects to pachd at the given address with default options. The
+    /// address is in the same format pachctl accepts, e.g.
+    /// `grpcs://pachd.example.com:30650`.
+    pub fn connect(address: &str) -> Result<Self, Error> {
+        Self::connect_with(|| crate::Client::connect(address))
+    }
+
+    /// Connects to pachd using the active context in pachctl's config file,
+    /// with default options.
+    pub fn from_config() -> Result<Self, Error> {
+        Self::connect_with(crate::Client::from_config)
+    }
+
+    /// Connects to pachd via `connect`, which makes an async client, e.g. so
+    /// that it can be configured with a `ClientBuilder`:
+    ///
+    /// ```no_run
+    /// # fn run() -> Result<(), pachyderm::Error> {
+    /// use std::time::Duration;
+    ///
+    /// let client = pachyderm::blocking::Client::connect_with(|| {
+    ///     pachyderm::Client::builder()
+    ///         .timeout(Duration::from_secs(30))
+    ///         .connect("grpc://localhost:30650")
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connect_with<F, Fut>(connect: F) -> Result<Self, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<crate::Client, Error>>,
+    {
+        let runtime = Runtime::new()?;
+        let inner = runtime.block_on(connect())?;
+        Ok(Client { inner, runtime })
+    }
+
+    /// Gets the async client this wraps, e.g. to set its auth token.
+    pub fn async_client(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Gets a client for the PFS API.
+    #[cfg(feature = "pfs")]
+    pub fn pfs(&self) -> PfsClient {
+        PfsClient::new(self.inner.pfs(), self.runtime.clone())
+    }
+
+    /// Gets a client for the PPS API.
+    #[cfg(feature = "pps")]
+    pub fn pps(&self) -> PpsClient {
+        PpsClient::new(self.inner.pps(), self.runtime.clone())
+    }
+
+    /// Gets a client for the auth API.
+    #[cfg(feature = "auth")]
+    pub fn auth(&self) -> AuthClient {
+        AuthClient::new(self.inner.auth(), self.runtime.clone())
+    }
+
+    /// Gets a client for the admin API.
+    #[cfg(feature = "admin")]
+    pub fn admin(&self) -> AdminClient {
+        AdminClient::new(self.inner.admin(), self.runtime.clone())
+    }
+}
+
+/// The runtime calls are run on, shared by a client and everything it hands
+/// out.
+#[derive(Clone, Debug)]
+pub(crate) struct Runtime(Arc<TokioRuntime>);
+
+impl Runtime {
+    fn new() -> Result<Self, Error> {
+        // A single worker suffices, since it only drives connections; calls
+        // themselves run on the threads that block on them
+        let runtime = Builder::new()
+            .threaded_scheduler()
+            .core_threads(1)
+            .thread_name("pachyderm-blocking")
+            .enable_all()
+            .build()?;
+        Ok(Runtime(Arc::new(runtime)))
+    }
+
+    /// Runs a future to completion on the current thread.
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.0.handle().block_on(future)
+    }
+}
+
+/// An iterator over the responses of a call that streams them back.
+#[derive(Debug)]
+pub struct Iter<T> {
+    runtime: Runtime,
+    stream: Streaming<T>,
+}
+
+impl<T> Iter<T> {
+    pub(crate) fn new(runtime: Runtime, stream: Streaming<T>) -> Self {
+        Iter { runtime, stream }
+    }
+}
+
+impl<T> Iterator for Iter<T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime
+            .block_on(self.stream.message())
+            .map_err(Error::from)
+            .transpose()
+    }
+}
+
+/// A reader over the bytes streamed back by a call such as `GetFile`.
+#[derive(Debug)]
+pub struct BytesReader {
+    chunks: Iter<Vec<u8>>,
+    chunk: Vec<u8>,
+    offset: usize,
+}
+
+impl BytesReader {
+    pub(crate) fn new(runtime: Runtime, stream: Streaming<Vec<u8>>) -> Self {
+        BytesReader {
+            chunks: Iter::new(runtime, stream),
+            chunk: Vec::new(),
+            offset: 0,
+        }
+    }
+}
+
+impl Read for BytesReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.offset == self.chunk.len() {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.chunk = chunk?;
+                    self.offset = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+
+        let read = buf.len().min(self.chunk.len() - self.offset);
+        buf[..read].copy_from_slice(&self.chunk[self.offset..self.offset + read]);
+        self.offset += read;
+        Ok(read)
+    }
+}
+
+/// Turns an iterator of requests into a stream that can be sent to pachd.
+/// The iterator is only ever advanced by one thread at a time, so it doesn't
+/// need to be `Sync`.
+pub(crate) fn iter_stream<I>(requests: I) -> impl Stream<Item = I::Item> + Send + Sync + 'static
+where
+    I: IntoIterator,
+    I::IntoIter: Send + 'static,
+{
+    let requests = Mutex::new(requests.into_iter());
+    stream::iter(std::iter::from_fn(move || {
+        requests.lock().expect("request iterator lock poisoned").next()
+    }))
+}
@@ -0,0 +1,127 @@
+use std::io::{self, Read, Seek, SeekFrom};
+use std::pin::Pin;
+
+use futures::future::poll_fn;
+use tokio::io::{AsyncRead, AsyncSeek};
+use tonic::transport::Channel;
+
+use super::Runtime;
+use crate::pfs::api_client::ApiClient;
+use crate::pfs::*;
+use crate::Error;
+
+/// A synchronous client for the PFS API. Create one via `Client::pfs`.
+///
+/// Each method blocks on the async client's method of the same name.
+/// `PutFile` and `FileOperationV2` take iterators of requests, which are
+/// streamed to pachd as they're produced.
+#[derive(Clone, Debug)]
+pub struct PfsClient {
+    inner: ApiClient<Channel>,
+    runtime: Runtime,
+}
+
+impl PfsClient {
+    pub(crate) fn new(inner: ApiClient<Channel>, runtime: Runtime) -> Self {
+        PfsClient { inner, runtime }
+    }
+
+    unary!(create_repo(CreateRepoRequest) -> ());
+    unary!(inspect_repo(InspectRepoRequest) -> RepoInfo);
+    unary!(list_repo(ListRepoRequest) -> ListRepoResponse);
+    unary!(delete_repo(DeleteRepoRequest) -> ());
+    unary!(start_commit(StartCommitRequest) -> Commit);
+    unary!(finish_commit(FinishCommitRequest) -> ());
+    unary!(inspect_commit(InspectCommitRequest) -> CommitInfo);
+    unary!(list_commit(ListCommitRequest) -> CommitInfos);
+    server_streaming!(list_commit_stream(ListCommitRequest) -> CommitInfo);
+    unary!(delete_commit(DeleteCommitRequest) -> ());
+    server_streaming!(flush_commit(FlushCommitRequest) -> CommitInfo);
+    server_streaming!(subscribe_commit(SubscribeCommitRequest) -> CommitInfo);
+    unary!(build_commit(BuildCommitRequest) -> Commit);
+    unary!(create_branch(CreateBranchRequest) -> ());
+    unary!(inspect_branch(InspectBranchRequest) -> BranchInfo);
+    unary!(list_branch(ListBranchRequest) -> BranchInfos);
+    unary!(delete_branch(DeleteBranchRequest) -> ());
+    client_streaming!(put_file(PutFileRequest) -> ());
+    unary!(copy_file(CopyFileRequest) -> ());
+    bytes_streaming!(get_file(GetFileRequest));
+    unary!(inspect_file(InspectFileRequest) -> FileInfo);
+    unary!(list_file(ListFileRequest) -> FileInfos);
+    server_streaming!(list_file_stream(ListFileRequest) -> FileInfo);
+    server_streaming!(walk_file(WalkFileRequest) -> FileInfo);
+    unary!(glob_file(GlobFileRequest) -> FileInfos);
+    server_streaming!(glob_file_stream(GlobFileRequest) -> FileInfo);
+    unary!(diff_file(DiffFileRequest) -> DiffFileResponse);
+    unary!(delete_file(DeleteFileRequest) -> ());
+    unary!(delete_all() -> ());
+    server_streaming!(fsck(FsckRequest) -> FsckResponse);
+    client_streaming!(file_operation_v2(FileOperationRequestV2) -> ());
+    bytes_streaming!(get_tar_v2(GetTarRequestV2));
+    server_streaming!(list_file_v2(ListFileRequest) -> FileInfoV2);
+    server_streaming!(glob_file_v2(GlobFileRequest) -> FileInfoV2);
+
+    /// Opens a file for reading with random access. See
+    /// `pachyderm::PfsFileReader`.
+    pub fn open_file(&mut self, file: File) -> Result<PfsFileReader, Error> {
+        let inner = self
+            .runtime
+            .block_on(crate::PfsFileReader::open(self.inner.clone(), file))?;
+        Ok(PfsFileReader {
+            inner,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+/// A reader of a file in PFS, with random access, which blocks on an async
+/// `pachyderm::PfsFileReader`. Create one via `PfsClient::open_file`.
+#[derive(Debug)]
+pub struct PfsFileReader {
+    inner: crate::PfsFileReader<ApiClient<Channel>>,
+    runtime: Runtime,
+}
+
+impl PfsFileReader {
+    /// Sets how many bytes each `GetFile` call asks for, unless a read asks
+    /// for more. Defaults to 4MiB.
+    pub fn read_ahead(self, bytes: usize) -> Self {
+        PfsFileReader {
+            inner: self.inner.read_ahead(bytes),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Gets the file being read, in the commit it's read from.
+    pub fn file(&self) -> &File {
+        self.inner.file()
+    }
+
+    /// Gets the file's length in bytes.
+    pub fn len(&self) -> u64 {
+        self.inner.len()
+    }
+
+    /// Checks whether the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl Read for PfsFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        self.runtime
+            .block_on(poll_fn(|cx| Pin::new(&mut *inner).poll_read(cx, buf)))
+    }
+}
+
+impl Seek for PfsFileReader {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let inner = &mut self.inner;
+        self.runtime.block_on(async move {
+            poll_fn(|cx| Pin::new(&mut *inner).start_seek(cx, position)).await?;
+            poll_fn(|cx| Pin::new(&mut *inner).poll_complete(cx)).await
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use tonic::transport::Channel;
+
+use super::Runtime;
+use crate::pps::api_client::ApiClient;
+use crate::pps::*;
+use crate::Error;
+
+/// A synchronous client for the PPS API. Create one via `Client::pps`.
+///
+/// Each method blocks on the async client's method of the same name.
+#[derive(Clone, Debug)]
+pub struct PpsClient {
+    inner: ApiClient<Channel>,
+    runtime: Runtime,
+}
+
+impl PpsClient {
+    pub(crate) fn new(inner: ApiClient<Channel>, runtime: Runtime) -> Self {
+        PpsClient { inner, runtime }
+    }
+
+    unary!(create_job(CreateJobRequest) -> Job);
+    unary!(inspect_job(InspectJobRequest) -> JobInfo);
+    unary!(list_job(ListJobRequest) -> JobInfos);
+    server_streaming!(list_job_stream(ListJobRequest) -> JobInfo);
+    server_streaming!(flush_job(FlushJobRequest) -> JobInfo);
+    unary!(delete_job(DeleteJobRequest) -> ());
+    unary!(stop_job(StopJobRequest) -> ());
+    unary!(inspect_datum(InspectDatumRequest) -> DatumInfo);
+    unary!(list_datum(ListDatumRequest) -> ListDatumResponse);
+    server_streaming!(list_datum_stream(ListDatumRequest) -> ListDatumStreamResponse);
+    unary!(restart_datum(RestartDatumRequest) -> ());
+    unary!(create_pipeline(CreatePipelineRequest) -> ());
+    unary!(inspect_pipeline(InspectPipelineRequest) -> PipelineInfo);
+    unary!(list_pipeline(ListPipelineRequest) -> PipelineInfos);
+    unary!(delete_pipeline(DeletePipelineRequest) -> ());
+    unary!(start_pipeline(StartPipelineRequest) -> ());
+    unary!(stop_pipeline(StopPipelineRequest) -> ());
+    unary!(run_pipeline(RunPipelineRequest) -> ());
+    unary!(run_cron(RunCronRequest) -> ());
+    unary!(create_secret(CreateSecretRequest) -> ());
+    unary!(delete_secret(DeleteSecretRequest) -> ());
+    unary!(list_secret() -> SecretInfos);
+    unary!(inspect_secret(InspectSecretRequest) -> SecretInfo);
+    unary!(delete_all() -> ());
+    server_streaming!(get_logs(GetLogsRequest) -> LogMessage);
+    unary!(garbage_collect(GarbageCollectRequest) -> GarbageCollectResponse);
+    unary!(activate_auth(ActivateAuthRequest) -> ActivateAuthResponse);
+    unary!(update_job_state(UpdateJobStateRequest) -> ());
+}
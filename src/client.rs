@@ -0,0 +1,573 @@
+use std::fmt;
+#[cfg(feature = "put-dir")]
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use log::warn;
+
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::metadata::{Ascii, MetadataKey, MetadataValue};
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Code, Interceptor, Request, Status};
+
+#[cfg(feature = "admin")]
+use crate::admin;
+#[cfg(feature = "auth")]
+use crate::auth;
+use crate::config::{Config, Context};
+#[cfg(feature = "debug")]
+use crate::debug;
+#[cfg(feature = "enterprise")]
+use crate::enterprise;
+#[cfg(feature = "pfs")]
+use crate::pfs;
+#[cfg(feature = "pps")]
+use crate::pps;
+#[cfg(feature = "put-dir")]
+use crate::put_dir::PutDir;
+#[cfg(feature = "transaction")]
+use crate::transaction;
+#[cfg(feature = "legacy-versions")]
+use crate::version::ApiVersion;
+#[cfg(feature = "legacy-versions")]
+use crate::versioned;
+use crate::{health, version, Error, PachdAddress, RetryPolicy, TlsConfig};
+
+/// The metadata key pachd reads auth tokens from.
+pub(crate) const AUTH_TOKEN_KEY: &str = "authn-token";
+
+type InterceptorFn = Arc<dyn Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static>;
+
+/// A client for every pachyderm service, multiplexed over a single channel.
+///
+/// Service clients are handed out by methods like `pfs()` and `pps()`. They
+/// are cheap to create, and each one shares the underlying connection, as
+/// well as any default metadata, deadlines and interceptors configured via
+/// `ClientBuilder`. Cloning a `Client` is similarly cheap.
+///
+/// Once auth is activated, every request needs to carry the caller's token.
+/// Clones of a client, and the service clients handed out by it, share the
+/// same token, which can be swapped at runtime via `set_auth_token`, e.g.:
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// use pachyderm::auth::AuthenticateRequest;
+///
+/// let request = AuthenticateRequest {
+///     one_time_password: "...".into(),
+///     ..Default::default()
+/// };
+/// let response = client.auth().authenticate(request).await?.into_inner();
+/// client.set_auth_token(&response.pach_token)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Client {
+    channel: Channel,
+    middleware: Arc<Middleware>,
+    auth_token: Arc<RwLock<Option<MetadataValue<Ascii>>>>,
+    server_version: Option<version::Version>,
+    retry: Option<RetryPolicy>,
+}
+
+impl Client {
+    /// Connects to pachd at the given address with default options. The
+    /// address is in the same format pachctl accepts, e.g.
+    /// `grpcs://pachd.example.com:30650`.
+    pub async fn connect(address: &str) -> Result<Self, Error> {
+        ClientBuilder::default().connect(address).await
+    }
+
+    /// Connects to pachd using the active context in pachctl's config file,
+    /// with default options.
+    pub async fn from_config() -> Result<Self, Error> {
+        ClientBuilder::default().connect_with_config().await
+    }
+
+    /// Creates a client over an existing channel with default options.
+    pub fn new(channel: Channel) -> Self {
+        ClientBuilder::default().build(channel)
+    }
+
+    /// Creates a builder for configuring a client.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Gets the underlying channel.
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+
+    /// Gets the version of pachd, as reported when the client connected.
+    /// This is `None` for clients built over an existing channel, or when
+    /// version checks were skipped.
+    pub fn server_version(&self) -> Option<&version::Version> {
+        self.server_version.as_ref()
+    }
+
+    /// Gets the auth token attached to requests, if any.
+    pub fn auth_token(&self) -> Option<String> {
+        let token = self.auth_token.read().expect("auth token lock poisoned");
+        token.as_ref().and_then(|token| token.to_str().ok()).map(String::from)
+    }
+
+    /// Sets the auth token attached to requests, e.g. the `pach_token` in an
+    /// `AuthenticateResponse` or `ActivateResponse`. This applies to all
+    /// clones of this client, as well as all of the service clients they've
+    /// handed out.
+    pub fn set_auth_token(&self, token: &str) -> Result<(), Error> {
+        let token = parse_auth_token(token)?;
+        *self.auth_token.write().expect("auth token lock poisoned") = Some(token);
+        Ok(())
+    }
+
+    /// Stops attaching an auth token to requests. Like `set_auth_token`,
+    /// this applies to all clones of this client.
+    pub fn clear_auth_token(&self) {
+        *self.auth_token.write().expect("auth token lock poisoned") = None;
+    }
+
+    /// Creates a client that shares this client's connection and options,
+    /// but uses a different auth token. Unlike with clones, setting the auth
+    /// token of either client afterwards does not affect the other.
+    pub fn with_auth_token(&self, token: &str) -> Result<Self, Error> {
+        Ok(Client {
+            channel: self.channel.clone(),
+            middleware: self.middleware.clone(),
+            auth_token: Arc::new(RwLock::new(Some(parse_auth_token(token)?))),
+            server_version: self.server_version.clone(),
+            retry: self.retry.clone(),
+        })
+    }
+
+    #[cfg(feature = "pfs")]
+    /// Gets a client for the PFS API. This speaks the latest API version; to
+    /// talk to older versions of pachd, use `versioned_pfs`.
+    pub fn pfs(&self) -> pfs::api_client::ApiClient<Channel> {
+        pfs::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "put-dir")]
+    /// Prepares to upload a local directory into a commit, with files' paths
+    /// in PFS under `pfs_prefix`. See `PutDir` for the options.
+    pub fn put_dir<L: Into<PathBuf>>(
+        &self,
+        commit: pfs::Commit,
+        local_path: L,
+        pfs_prefix: &str,
+    ) -> PutDir<pfs::api_client::ApiClient<Channel>> {
+        PutDir::new(self.pfs(), commit, local_path, pfs_prefix)
+    }
+
+    #[cfg(feature = "pfs")]
+    /// Gets a client for the PFS object API.
+    pub fn pfs_object(&self) -> pfs::object_api_client::ObjectApiClient<Channel> {
+        pfs::object_api_client::ObjectApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "pps")]
+    /// Gets a client for the PPS API. This speaks the latest API version; to
+    /// talk to older versions of pachd, use `versioned_pps`.
+    pub fn pps(&self) -> pps::api_client::ApiClient<Channel> {
+        pps::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "legacy-versions")]
+    /// Gets a client for the PFS API that speaks the same API version as
+    /// pachd. If pachd's version is unknown, e.g. because version checks
+    /// were skipped, the latest API version is used.
+    pub fn versioned_pfs(&self) -> versioned::PfsClient {
+        versioned::PfsClient::new(
+            self.channel.clone(),
+            self.interceptor(),
+            self.api_version(),
+            self.retry.clone(),
+        )
+    }
+
+    #[cfg(feature = "legacy-versions")]
+    /// Gets a client for the PPS API that speaks the same API version as
+    /// pachd. If pachd's version is unknown, e.g. because version checks
+    /// were skipped, the latest API version is used.
+    pub fn versioned_pps(&self) -> versioned::PpsClient {
+        versioned::PpsClient::new(
+            self.channel.clone(),
+            self.interceptor(),
+            self.api_version(),
+            self.retry.clone(),
+        )
+    }
+
+    #[cfg(feature = "auth")]
+    /// Gets a client for the auth API.
+    pub fn auth(&self) -> auth::api_client::ApiClient<Channel> {
+        auth::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "admin")]
+    /// Gets a client for the admin API.
+    pub fn admin(&self) -> admin::api_client::ApiClient<Channel> {
+        admin::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "transaction")]
+    /// Gets a client for the transaction API.
+    pub fn transaction(&self) -> transaction::api_client::ApiClient<Channel> {
+        transaction::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    /// Gets a client for the version API.
+    pub fn version(&self) -> version::api_client::ApiClient<Channel> {
+        version::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "debug")]
+    /// Gets a client for the debug API.
+    pub fn debug(&self) -> debug::debug_client::DebugClient<Channel> {
+        debug::debug_client::DebugClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "enterprise")]
+    /// Gets a client for the enterprise API.
+    pub fn enterprise(&self) -> enterprise::api_client::ApiClient<Channel> {
+        enterprise::api_client::ApiClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    /// Gets a client for the health API.
+    pub fn health(&self) -> health::health_client::HealthClient<Channel> {
+        health::health_client::HealthClient::with_interceptor(self.channel.clone(), self.interceptor())
+    }
+
+    #[cfg(feature = "legacy-versions")]
+    fn api_version(&self) -> ApiVersion {
+        self.server_version
+            .as_ref()
+            .and_then(version::Version::api_version)
+            .unwrap_or(ApiVersion::LATEST)
+    }
+
+    /// Ensures pachd's deployment ID matches the expected one, so that we
+    /// don't, e.g., delete everything on the wrong cluster because of a stale
+    /// port-forward.
+    async fn check_deployment_id(&self, expected: &str) -> Result<(), Error> {
+        // This is called directly rather than via the admin API's bindings,
+        // so that deployment IDs can be checked without the `admin` feature
+        let mut grpc = tonic::client::Grpc::with_interceptor(self.channel.clone(), self.interceptor());
+        grpc.ready()
+            .await
+            .map_err(|err| Status::new(Code::Unavailable, format!("Service was not ready: {}", err)))?;
+        let path = PathAndQuery::from_static("/admin.API/InspectCluster");
+        let info: ClusterInfo = grpc
+            .unary(Request::new(()), path, ProstCodec::default())
+            .await?
+            .into_inner();
+
+        if info.deployment_id != expected {
+            return Err(Error::DeploymentMismatch {
+                expected: expected.into(),
+                actual: info.deployment_id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Creates a client that shares this client's connection and options,
+    /// including its auth token, but attaches additional metadata to every
+    /// request.
+    #[cfg_attr(not(feature = "transaction"), allow(dead_code))]
+    pub(crate) fn with_metadata(&self, key: &'static str, value: MetadataValue<Ascii>) -> Self {
+        let mut middleware = (*self.middleware).clone();
+        middleware.metadata.push((MetadataKey::from_static(key), value));
+        Client {
+            middleware: Arc::new(middleware),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn interceptor(&self) -> Interceptor {
+        let middleware = self.middleware.clone();
+        let auth_token = self.auth_token.clone();
+
+        Interceptor::new(move |mut request: Request<()>| {
+            if let Some(token) = &*auth_token.read().expect("auth token lock poisoned") {
+                request.metadata_mut().insert(AUTH_TOKEN_KEY, token.clone());
+            }
+            middleware.intercept(request)
+        })
+    }
+}
+
+/// Configures and builds a `Client`.
+#[derive(Clone, Debug, Default)]
+pub struct ClientBuilder {
+    middleware: Middleware,
+    auth_token: Option<MetadataValue<Ascii>>,
+    tls: Option<TlsConfig>,
+    deployment_id: Option<String>,
+    skip_deployment_check: bool,
+    version_check: VersionCheck,
+    retry: Option<RetryPolicy>,
+}
+
+impl ClientBuilder {
+    /// Adds metadata that will be attached to every request. Panics if `key`
+    /// is not a valid ASCII metadata key.
+    pub fn metadata(mut self, key: &'static str, value: MetadataValue<Ascii>) -> Self {
+        self.middleware.metadata.push((MetadataKey::from_static(key), value));
+        self
+    }
+
+    /// Sets a deadline for every request, relative to when it is sent.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.middleware.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the initial auth token attached to every request. It can later be
+    /// swapped out via `Client::set_auth_token`.
+    pub fn auth_token(mut self, token: MetadataValue<Ascii>) -> Self {
+        self.auth_token = Some(token);
+        self
+    }
+
+    /// Adds an interceptor that will be run on every request. Interceptors
+    /// run in the order they were added, after auth tokens, default metadata
+    /// and deadlines have been attached.
+    pub fn interceptor<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.middleware.interceptors.push(Arc::new(f));
+        self
+    }
+
+    /// Connects over TLS with the given settings. This is implied by
+    /// `grpcs://` and `https://` addresses, in which case the system's root
+    /// certificates are trusted by default.
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Sets the deployment ID of the cluster this client is meant to talk
+    /// to. Once connected, pachd is asked for its deployment ID, and
+    /// connecting fails with `Error::DeploymentMismatch` if the two differ.
+    /// Contexts with `cluster_deployment_id` set do this automatically.
+    pub fn deployment_id<S: Into<String>>(mut self, deployment_id: S) -> Self {
+        self.deployment_id = Some(deployment_id.into());
+        self
+    }
+
+    /// Skips checking pachd's deployment ID when connecting, even if one is
+    /// expected. Only use this if you're certain of the cluster you're
+    /// connecting to.
+    pub fn skip_deployment_check(mut self) -> Self {
+        self.skip_deployment_check = true;
+        self
+    }
+
+    /// Retries idempotent calls made via the versioned PFS and PPS clients,
+    /// i.e. `Client::versioned_pfs` and `Client::versioned_pps` from the
+    /// `legacy-versions` feature, when they fail transiently, according to
+    /// the given policy. By default, calls aren't retried.
+    ///
+    /// This only affects the versioned clients. Calls made via any other
+    /// client, e.g. `Client::pfs` or `Client::pps`, are never retried
+    /// automatically; wrap them in `RetryPolicy::retry` to retry them.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Sets what to do if pachd's version isn't one this library supports.
+    /// By default, connecting fails with `Error::UnsupportedVersion`.
+    pub fn version_check(mut self, version_check: VersionCheck) -> Self {
+        self.version_check = version_check;
+        self
+    }
+
+    /// Connects to pachd at the given address, in the same format pachctl
+    /// accepts, e.g. `grpcs://pachd.example.com:30650`.
+    pub async fn connect(self, address: &str) -> Result<Client, Error> {
+        let address = address.parse()?;
+        self.connect_address(&address, None).await
+    }
+
+    /// Connects to pachd using the active context in pachctl's config file.
+    pub async fn connect_with_config(self) -> Result<Client, Error> {
+        let config = Config::read()?;
+        let (_, context) = config.active_context()?;
+        self.connect_with_context(context).await
+    }
+
+    /// Connects to pachd using the given pachctl context. The context's
+    /// session token, if any, is used as the initial auth token, and its
+    /// trusted root certificates, if any, are used to connect over TLS. If
+    /// the context has a cluster deployment ID, connecting fails unless pachd
+    /// reports the same one.
+    pub async fn connect_with_context(mut self, context: &Context) -> Result<Client, Error> {
+        if !context.session_token.is_empty() {
+            self.auth_token = Some(parse_auth_token(&context.session_token)?);
+        }
+        if self.deployment_id.is_none() && !context.cluster_deployment_id.is_empty() {
+            self.deployment_id = Some(context.cluster_deployment_id.clone());
+        }
+
+        let address = context.address()?;
+        let server_cas = context.server_cas_pem()?;
+        self.connect_address(&address, server_cas).await
+    }
+
+    /// Connects to pachd at the given address, trusting `server_cas` unless
+    /// root certificates were explicitly configured.
+    async fn connect_address(mut self, address: &PachdAddress, server_cas: Option<Vec<u8>>) -> Result<Client, Error> {
+        if let Some(pem) = server_cas {
+            let tls = self.tls.take().unwrap_or_default();
+            self.tls = Some(if tls.has_ca_certificates() {
+                tls
+            } else {
+                tls.ca_certificates(pem)
+            });
+        }
+
+        // As with pachctl, an explicit scheme decides whether to use TLS, and
+        // otherwise TLS is used only if it has been configured
+        let secured = match address.scheme {
+            Some(scheme) if scheme.is_secured() => true,
+            Some(scheme) if self.tls.is_some() => {
+                return Err(Error::Tls(format!(
+                    "TLS is configured, but `{}` uses the plaintext scheme `{}://`; use `grpcs://` instead",
+                    address, scheme
+                )))
+            }
+            Some(_) => false,
+            None => self.tls.is_some(),
+        };
+
+        let mut endpoint = Endpoint::from_shared(address.uri(secured))
+            .map_err(|err| Error::InvalidAddress(format!("{} in `{}`", err, address)))?;
+        if secured {
+            let tls = self.tls.take().unwrap_or_default();
+            endpoint = endpoint.tls_config(tls.to_tonic()?)?;
+        }
+
+        self.connect_endpoint(endpoint).await
+    }
+
+    async fn connect_endpoint(self, mut endpoint: Endpoint) -> Result<Client, Error> {
+        if let Some(timeout) = self.middleware.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        let channel = endpoint.connect().await?;
+
+        let version_check = self.version_check;
+        let expected = match &self.deployment_id {
+            Some(expected) if !self.skip_deployment_check => Some(expected.clone()),
+            _ => None,
+        };
+        let mut client = self.build(channel);
+
+        // Check the version first, since an unsupported pachd may not even
+        // understand the request we use to check its deployment ID
+        if version_check != VersionCheck::Skip {
+            let version = client.version().get_version(()).await?.into_inner();
+            if !version.is_supported() {
+                if version_check == VersionCheck::Strict {
+                    return Err(Error::UnsupportedVersion(version));
+                }
+                warn!("{}", Error::UnsupportedVersion(version.clone()));
+            }
+            client.server_version = Some(version);
+        }
+        if let Some(expected) = expected {
+            client.check_deployment_id(&expected).await?;
+        }
+        Ok(client)
+    }
+
+    /// Builds a client over an existing channel.
+    pub fn build(self, channel: Channel) -> Client {
+        Client {
+            channel,
+            middleware: Arc::new(self.middleware),
+            auth_token: Arc::new(RwLock::new(self.auth_token)),
+            server_version: None,
+            retry: self.retry,
+        }
+    }
+}
+
+/// What to do when connecting to a version of pachd this library doesn't
+/// support. Requests to an unsupported pachd tend to fail with confusing
+/// `Unimplemented` or decoding errors, so it's best to find out early.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VersionCheck {
+    /// Fail to connect.
+    #[default]
+    Strict,
+    /// Log a warning, but connect anyways.
+    Warn,
+    /// Don't ask pachd for its version at all.
+    Skip,
+}
+
+/// Default metadata, deadlines and interceptors applied to every request.
+#[derive(Clone, Default)]
+struct Middleware {
+    metadata: Vec<(MetadataKey<Ascii>, MetadataValue<Ascii>)>,
+    timeout: Option<Duration>,
+    interceptors: Vec<InterceptorFn>,
+}
+
+impl Middleware {
+    fn intercept(&self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        for (key, value) in &self.metadata {
+            request.metadata_mut().insert(key.clone(), value.clone());
+        }
+        if let Some(timeout) = self.timeout {
+            request.metadata_mut().insert("grpc-timeout", grpc_timeout(timeout));
+        }
+        for interceptor in &self.interceptors {
+            request = interceptor(request)?;
+        }
+        Ok(request)
+    }
+}
+
+impl fmt::Debug for Middleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Middleware")
+            .field("metadata", &self.metadata)
+            .field("timeout", &self.timeout)
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
+
+/// The part of the admin API's `ClusterInfo` needed to check deployment IDs.
+#[derive(Clone, PartialEq, prost::Message)]
+struct ClusterInfo {
+    #[prost(string, tag = "2")]
+    deployment_id: String,
+}
+
+fn parse_auth_token(token: &str) -> Result<MetadataValue<Ascii>, Error> {
+    MetadataValue::from_str(token).map_err(|_| Error::InvalidMetadata("the auth token".into()))
+}
+
+/// Encodes a duration as a `grpc-timeout` header value. The gRPC spec limits
+/// the value to 8 digits, so we fall back to coarser units for long timeouts.
+fn grpc_timeout(timeout: Duration) -> MetadataValue<Ascii> {
+    const MAX: u128 = 99_999_999;
+    let millis = timeout.as_millis();
+    let value = if millis <= MAX {
+        format!("{}m", millis)
+    } else {
+        format!("{}S", (timeout.as_secs() as u128).min(MAX))
+    };
+    MetadataValue::from_str(&value).expect("grpc-timeout values are always valid metadata")
+}
@@ -0,0 +1,573 @@
+//! pachctl's config, as well as utilities for reading and managing it, so
+//! that clients can be built from (and share) the same contexts pachctl uses.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::{Error, PachdAddress};
+
+include_proto!("config");
+
+/// Environment variable that overrides the path to the config file.
+pub const CONFIG_ENV_VAR: &str = "PACH_CONFIG";
+
+/// Environment variable that overrides the active context.
+pub const CONTEXT_ENV_VAR: &str = "PACH_CONTEXT";
+
+/// Environment variable that overrides the pachd address of the active
+/// context.
+pub const ADDRESS_ENV_VAR: &str = "PACHD_ADDRESS";
+
+/// The name of the context created when no config exists, or when a v1
+/// config is upgraded.
+const DEFAULT_CONTEXT: &str = "default";
+
+impl Config {
+    /// Gets the path to pachctl's config file. This is the value of
+    /// `PACH_CONFIG` if set, or `$HOME/.pachyderm/config.json` otherwise.
+    pub fn path() -> Result<PathBuf, Error> {
+        if let Some(path) = env::var_os(CONFIG_ENV_VAR) {
+            return Ok(path.into());
+        }
+
+        match env::var_os("HOME") {
+            Some(home) => Ok(Path::new(&home).join(".pachyderm").join("config.json")),
+            None => Err(Error::Config(format!(
+                "could not find the config file, since neither `{}` nor `HOME` are set",
+                CONFIG_ENV_VAR
+            ))),
+        }
+    }
+
+    /// Reads pachctl's config file from its default location. As with
+    /// pachctl, a missing file yields a config with a single empty context.
+    pub fn read() -> Result<Self, Error> {
+        Self::read_from(Self::path()?)
+    }
+
+    /// Reads a config file from the given path. A missing file yields a
+    /// config with a single empty context, and a v1 config is upgraded to
+    /// v2.
+    pub fn read_from<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let mut config = match fs::read(path) {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(ref err) if err.kind() == ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(err.into()),
+        };
+
+        config.upgrade();
+        Ok(config)
+    }
+
+    /// Writes the config to pachctl's config file at its default location.
+    pub fn write(&self) -> Result<(), Error> {
+        self.write_to(Self::path()?)
+    }
+
+    /// Writes the config to the given path, in the same format as pachctl.
+    /// The file is replaced atomically, and is only readable by its owner
+    /// since it may contain session tokens.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        // pachctl refuses to write v1 configs, so we do too
+        if self.v1.is_some() {
+            return Err(Error::Config(
+                "cannot write a v1 config, it must be upgraded first".into(),
+            ));
+        }
+
+        let path = path.as_ref();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        fs::create_dir_all(dir)?;
+
+        let mut contents = serde_json::to_vec_pretty(self)?;
+        contents.push(b'\n');
+
+        // Write to a temporary file in the same directory, then rename it
+        // over the original, so that readers never see a partial config. The
+        // file's name is random, and it must not already exist, so that
+        // concurrent writers never write to the same one
+        let tmp_path = dir.join(format!(
+            ".config.json.{}.{:016x}.tmp",
+            process::id(),
+            rand::random::<u64>()
+        ));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let mut file = options.open(&tmp_path)?;
+        let result = file
+            .write_all(&contents)
+            .and_then(|_| file.sync_all())
+            .and_then(|_| fs::rename(&tmp_path, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        Ok(result?)
+    }
+
+    /// Upgrades a v1 config to v2, converting the v1 settings into a context
+    /// named `default`, which is made active. This is a no-op for v2
+    /// configs. Configs are upgraded automatically when read.
+    pub fn upgrade(&mut self) {
+        if self.v2.is_some() {
+            self.v1 = None;
+            return;
+        }
+
+        let context = match self.v1.take() {
+            Some(v1) => Context {
+                source: ContextSource::ConfigV1 as i32,
+                pachd_address: v1.pachd_address,
+                server_cas: v1.server_cas,
+                session_token: v1.session_token,
+                active_transaction: v1.active_transaction,
+                ..Default::default()
+            },
+            None => Context::default(),
+        };
+
+        let mut contexts = HashMap::new();
+        contexts.insert(DEFAULT_CONTEXT.to_string(), context);
+
+        self.v2 = Some(ConfigV2 {
+            active_context: DEFAULT_CONTEXT.into(),
+            contexts,
+            metrics: true,
+            max_shell_completions: 0,
+        });
+    }
+
+    /// Lists the contexts, sorted by name.
+    pub fn contexts(&self) -> Vec<(&str, &Context)> {
+        let mut contexts: Vec<(&str, &Context)> = match &self.v2 {
+            Some(v2) => v2
+                .contexts
+                .iter()
+                .map(|(name, context)| (name.as_str(), context))
+                .collect(),
+            None => Vec::new(),
+        };
+        contexts.sort_unstable_by_key(|(name, _)| *name);
+        contexts
+    }
+
+    /// Gets a context by name.
+    pub fn context(&self, name: &str) -> Option<&Context> {
+        self.v2.as_ref().and_then(|v2| v2.contexts.get(name))
+    }
+
+    /// Gets a mutable reference to a context by name.
+    pub fn context_mut(&mut self, name: &str) -> Option<&mut Context> {
+        self.v2.as_mut().and_then(|v2| v2.contexts.get_mut(name))
+    }
+
+    /// Adds a context. Fails if a context with the same name already exists,
+    /// unless `overwrite` is set.
+    pub fn add_context<S: Into<String>>(&mut self, name: S, context: Context, overwrite: bool) -> Result<(), Error> {
+        let name = name.into();
+        let contexts = &mut self.v2_mut().contexts;
+        if !overwrite && contexts.contains_key(&name) {
+            return Err(Error::Config(format!("the context {:?} already exists", name)));
+        }
+        contexts.insert(name, context);
+        Ok(())
+    }
+
+    /// Renames a context. If it was the active context, it remains active.
+    /// Renaming a context to its own name does nothing.
+    pub fn rename_context(&mut self, old_name: &str, new_name: &str) -> Result<(), Error> {
+        let v2 = self.v2_mut();
+        if old_name == new_name && v2.contexts.contains_key(old_name) {
+            return Ok(());
+        }
+        if v2.contexts.contains_key(new_name) {
+            return Err(Error::Config(format!("the context {:?} already exists", new_name)));
+        }
+        let context = v2
+            .contexts
+            .remove(old_name)
+            .ok_or_else(|| Error::Config(format!("the context {:?} does not exist", old_name)))?;
+        v2.contexts.insert(new_name.into(), context);
+        if v2.active_context == old_name {
+            v2.active_context = new_name.into();
+        }
+        Ok(())
+    }
+
+    /// Deletes a context, returning it. As with pachctl, the active context
+    /// cannot be deleted.
+    pub fn delete_context(&mut self, name: &str) -> Result<Context, Error> {
+        let v2 = self.v2_mut();
+        if v2.active_context == name {
+            return Err(Error::Config(format!("cannot delete the active context {:?}", name)));
+        }
+        v2.contexts
+            .remove(name)
+            .ok_or_else(|| Error::Config(format!("the context {:?} does not exist", name)))
+    }
+
+    /// Switches the active context.
+    pub fn set_active_context(&mut self, name: &str) -> Result<(), Error> {
+        let v2 = self.v2_mut();
+        if !v2.contexts.contains_key(name) {
+            return Err(Error::Config(format!("the context {:?} does not exist", name)));
+        }
+        v2.active_context = name.into();
+        Ok(())
+    }
+
+    /// Sets the active transaction of the active context, or clears it if
+    /// `transaction` is `None`.
+    pub fn set_active_transaction(&mut self, transaction: Option<&str>) -> Result<(), Error> {
+        let (_, context) = self.active_context_mut()?;
+        context.active_transaction = transaction.unwrap_or_default().into();
+        Ok(())
+    }
+
+    /// Gets the name and value of the active context. `PACH_CONTEXT`, if
+    /// set, takes precedence over the config's active context.
+    pub fn active_context(&self) -> Result<(&str, &Context), Error> {
+        let name = self.active_context_name()?;
+        let v2 = self.v2.as_ref().expect("active context resolved without a v2 config");
+        let (name, context) = v2.contexts.get_key_value(&name).expect("active context does not exist");
+        Ok((name, context))
+    }
+
+    /// Gets the name of, and a mutable reference to, the active context.
+    /// `PACH_CONTEXT`, if set, takes precedence over the config's active
+    /// context.
+    pub fn active_context_mut(&mut self) -> Result<(String, &mut Context), Error> {
+        let name = self.active_context_name()?;
+        let context = self.context_mut(&name).expect("active context does not exist");
+        Ok((name, context))
+    }
+
+    /// Resolves the name of the active context, and ensures it exists.
+    fn active_context_name(&self) -> Result<String, Error> {
+        let v2 = self.v2.as_ref().ok_or_else(|| Error::Config("no v2 config".into()))?;
+
+        if let Ok(name) = env::var(CONTEXT_ENV_VAR) {
+            if !v2.contexts.contains_key(&name) {
+                return Err(Error::Config(format!(
+                    "`{}` refers to a context ({:?}) that does not exist",
+                    CONTEXT_ENV_VAR, name
+                )));
+            }
+            return Ok(name);
+        }
+
+        if v2.active_context.is_empty() {
+            return Err(Error::Config("no active context configured".into()));
+        } else if !v2.contexts.contains_key(&v2.active_context) {
+            return Err(Error::Config(format!(
+                "the active context ({:?}) does not exist",
+                v2.active_context
+            )));
+        }
+
+        Ok(v2.active_context.clone())
+    }
+
+    /// Gets the v2 config, upgrading from v1 if necessary.
+    fn v2_mut(&mut self) -> &mut ConfigV2 {
+        self.upgrade();
+        self.v2.get_or_insert_with(ConfigV2::default)
+    }
+}
+
+impl Context {
+    /// Gets the pachd address to connect to for this context. As with
+    /// pachctl, `PACHD_ADDRESS` takes precedence if set, followed by the
+    /// context's address, then a port-forwarded pachd, and finally pachd's
+    /// default port on localhost.
+    pub fn address(&self) -> Result<PachdAddress, Error> {
+        if let Ok(address) = env::var(ADDRESS_ENV_VAR) {
+            address.parse()
+        } else if !self.pachd_address.is_empty() {
+            self.pachd_address.parse()
+        } else if let Some(port) = self.port_forwarders.get("pachd") {
+            let port = u16::try_from(*port)
+                .map_err(|_| Error::Config(format!("invalid port-forwarded pachd port: {}", port)))?;
+            Ok(PachdAddress {
+                port,
+                ..PachdAddress::localhost()
+            })
+        } else {
+            Ok(PachdAddress::localhost())
+        }
+    }
+
+    /// Decodes the context's trusted root certificates, if any, into PEM.
+    pub fn server_cas_pem(&self) -> Result<Option<Vec<u8>>, Error> {
+        if self.server_cas.is_empty() {
+            return Ok(None);
+        }
+
+        base64::decode(&self.server_cas)
+            .map(Some)
+            .map_err(|err| Error::Config(format!("could not decode `server_cas`: {}", err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::*;
+
+    /// Serializes the tests that read or set environment variables, which
+    /// are shared by the whole process.
+    static ENV: Mutex<()> = Mutex::new(());
+
+    /// Takes the environment, without the variables that override the
+    /// config set.
+    fn lock_env() -> MutexGuard<'static, ()> {
+        let guard = ENV.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        env::remove_var(CONTEXT_ENV_VAR);
+        env::remove_var(ADDRESS_ENV_VAR);
+        guard
+    }
+
+    /// A local directory, deleted when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            static NEXT: AtomicUsize = AtomicUsize::new(0);
+            let root = env::temp_dir().join(format!(
+                "pachyderm-config-{}-{}",
+                process::id(),
+                NEXT.fetch_add(1, Ordering::SeqCst)
+            ));
+            fs::create_dir_all(&root).unwrap();
+            TempDir(root)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// A config with the contexts `default`, which is active, and `prod`.
+    fn config() -> Config {
+        let mut config = Config::default();
+        config.upgrade();
+        let prod = Context {
+            pachd_address: "grpcs://pachd.example.com:31400".into(),
+            ..Default::default()
+        };
+        config.add_context("prod", prod, false).unwrap();
+        config
+    }
+
+    fn names(config: &Config) -> Vec<&str> {
+        config.contexts().into_iter().map(|(name, _)| name).collect()
+    }
+
+    #[test]
+    fn upgrades_v1_configs() {
+        let dir = TempDir::new();
+        let path = dir.0.join("config.json");
+        let v1 = r#"{
+            "user_id": "abc",
+            "v1": {
+                "pachd_address": "10.0.0.1:30650",
+                "session_token": "secret",
+                "active_transaction": "tx-1"
+            }
+        }"#;
+        fs::write(&path, v1).unwrap();
+
+        let config = Config::read_from(&path).unwrap();
+        assert_eq!(config.user_id, "abc");
+        assert!(config.v1.is_none());
+        let v2 = config.v2.as_ref().unwrap();
+        assert_eq!(v2.active_context, "default");
+        assert!(v2.metrics);
+        assert_eq!(names(&config), vec!["default"]);
+        let context = config.context("default").unwrap();
+        assert_eq!(context.source, ContextSource::ConfigV1 as i32);
+        assert_eq!(context.pachd_address, "10.0.0.1:30650");
+        assert_eq!(context.session_token, "secret");
+        assert_eq!(context.active_transaction, "tx-1");
+
+        // v1 configs can't be written, as with pachctl
+        let v1: Config = serde_json::from_str(v1).unwrap();
+        assert!(matches!(v1.write_to(&path), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn reads_missing_configs_as_empty() {
+        let dir = TempDir::new();
+        let config = Config::read_from(dir.0.join("missing.json")).unwrap();
+        assert_eq!(config.v2.as_ref().unwrap().active_context, "default");
+        assert_eq!(config.context("default"), Some(&Context::default()));
+    }
+
+    #[test]
+    fn adds_contexts() {
+        let mut config = config();
+        assert_eq!(names(&config), vec!["default", "prod"]);
+
+        let staging = Context {
+            pachd_address: "staging:30650".into(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.add_context("prod", staging.clone(), false),
+            Err(Error::Config(_))
+        ));
+        config.add_context("prod", staging.clone(), true).unwrap();
+        assert_eq!(config.context("prod"), Some(&staging));
+    }
+
+    #[test]
+    fn renames_contexts() {
+        let mut config = config();
+        config.set_active_context("prod").unwrap();
+
+        // Renaming the active context keeps it active
+        config.rename_context("prod", "production").unwrap();
+        assert_eq!(names(&config), vec!["default", "production"]);
+        assert_eq!(config.v2.as_ref().unwrap().active_context, "production");
+
+        let before = config.clone();
+        config.rename_context("production", "production").unwrap();
+        assert_eq!(config, before);
+
+        assert!(matches!(
+            config.rename_context("default", "production"),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            config.rename_context("missing", "missing"),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            config.rename_context("missing", "other"),
+            Err(Error::Config(_))
+        ));
+        assert_eq!(config, before);
+    }
+
+    #[test]
+    fn deletes_contexts() {
+        let mut config = config();
+
+        assert!(matches!(config.delete_context("default"), Err(Error::Config(_))));
+        assert!(matches!(config.delete_context("missing"), Err(Error::Config(_))));
+        let prod = config.delete_context("prod").unwrap();
+        assert_eq!(prod.pachd_address, "grpcs://pachd.example.com:31400");
+        assert_eq!(names(&config), vec!["default"]);
+    }
+
+    #[test]
+    fn sets_active_context() {
+        let _env = lock_env();
+        let mut config = config();
+        assert_eq!(config.active_context().unwrap().0, "default");
+
+        config.set_active_context("prod").unwrap();
+        assert_eq!(config.active_context().unwrap().0, "prod");
+        assert!(matches!(config.set_active_context("missing"), Err(Error::Config(_))));
+        assert_eq!(config.active_context().unwrap().0, "prod");
+    }
+
+    #[test]
+    fn sets_active_transaction() {
+        let _env = lock_env();
+        let mut config = config();
+
+        config.set_active_transaction(Some("tx-1")).unwrap();
+        assert_eq!(config.active_context().unwrap().1.active_transaction, "tx-1");
+        assert_eq!(config.context("prod").unwrap().active_transaction, "");
+
+        config.set_active_transaction(None).unwrap();
+        assert_eq!(config.active_context().unwrap().1.active_transaction, "");
+    }
+
+    #[test]
+    fn overrides_active_context_from_env() {
+        let _env = lock_env();
+        let mut config = config();
+
+        env::set_var(CONTEXT_ENV_VAR, "prod");
+        assert_eq!(config.active_context().unwrap().0, "prod");
+        config.set_active_transaction(Some("tx-1")).unwrap();
+        assert_eq!(config.context("prod").unwrap().active_transaction, "tx-1");
+        assert_eq!(config.context("default").unwrap().active_transaction, "");
+
+        env::set_var(CONTEXT_ENV_VAR, "missing");
+        assert!(matches!(config.active_context(), Err(Error::Config(_))));
+        env::remove_var(CONTEXT_ENV_VAR);
+        assert_eq!(config.active_context().unwrap().0, "default");
+    }
+
+    #[test]
+    fn overrides_address_from_env() {
+        let _env = lock_env();
+        let config = config();
+        let prod = config.context("prod").unwrap();
+        let mut forwarded = Context::default();
+        forwarded.port_forwarders.insert("pachd".into(), 30651);
+
+        assert_eq!(
+            prod.address().unwrap(),
+            "grpcs://pachd.example.com:31400".parse().unwrap()
+        );
+        assert_eq!(
+            forwarded.address().unwrap(),
+            PachdAddress {
+                port: 30651,
+                ..PachdAddress::localhost()
+            }
+        );
+        assert_eq!(Context::default().address().unwrap(), PachdAddress::localhost());
+
+        env::set_var(ADDRESS_ENV_VAR, "10.0.0.1:650");
+        let address: PachdAddress = "10.0.0.1:650".parse().unwrap();
+        assert_eq!(prod.address().unwrap(), address);
+        assert_eq!(forwarded.address().unwrap(), address);
+        env::remove_var(ADDRESS_ENV_VAR);
+    }
+
+    #[test]
+    fn writes_configs_readably_by_their_owner() {
+        let dir = TempDir::new();
+        let path = dir.0.join(".pachyderm").join("config.json");
+        let mut config = config();
+        config.context_mut("prod").unwrap().session_token = "secret".into();
+
+        config.write_to(&path).unwrap();
+        assert_eq!(Config::read_from(&path).unwrap(), config);
+
+        // Overwriting it keeps it private, and leaves no temporary files
+        config.delete_context("prod").unwrap();
+        config.write_to(&path).unwrap();
+        assert_eq!(Config::read_from(&path).unwrap(), config);
+        let files: Vec<_> = fs::read_dir(path.parent().unwrap()).unwrap().collect();
+        assert_eq!(files.len(), 1);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
@@ -0,0 +1,420 @@
+use std::convert::Infallible;
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind};
+
+use serde_json::Error as JsonError;
+use tonic::transport::Error as TransportError;
+use tonic::{Code, Status};
+
+use crate::version::{ApiVersion, Version};
+
+/// Errors returned by the higher-level functionality in this library.
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O error, e.g. while reading pachctl's config file.
+    Io(IoError),
+    /// pachctl's config file could not be (de)serialized.
+    Json(JsonError),
+    /// pachctl's config is invalid, e.g. the active context does not exist.
+    Config(String),
+    /// A pachd address could not be parsed.
+    InvalidAddress(String),
+    /// A reference to a repo, branch, commit or file, such as
+    /// `images@master:/cats`, could not be parsed.
+    InvalidReference(String),
+    /// A glob, e.g. one of `PutDir`'s ignore globs, could not be compiled.
+    InvalidGlob(String),
+    /// A value could not be attached to requests as gRPC metadata, e.g. an
+    /// auth token with invalid characters. The string describes the value.
+    InvalidMetadata(String),
+    /// TLS settings are invalid, or conflict with the pachd address.
+    Tls(String),
+    /// Connecting to pachd failed.
+    Transport(TransportError),
+    /// pachd reported a different deployment ID than expected, i.e. the
+    /// client connected to the wrong cluster.
+    DeploymentMismatch {
+        /// The deployment ID the client expected, e.g. from its context.
+        expected: String,
+        /// The deployment ID pachd reported.
+        actual: String,
+    },
+    /// pachd's version is not one this library supports.
+    UnsupportedVersion(Version),
+    /// A message could not be converted between pachd API versions, e.g.
+    /// because it sets a field the other version has no equivalent of. The
+    /// string describes why.
+    Conversion(String),
+    /// A `TransactionRequest` does not set exactly one operation.
+    InvalidTransactionRequest(String),
+    /// A repo does not exist.
+    RepoNotFound(Status),
+    /// A repo already exists.
+    RepoExists(Status),
+    /// A commit does not exist.
+    CommitNotFound(Status),
+    /// A commit has already been finished, so it can't be written to.
+    CommitFinished(Status),
+    /// A branch does not exist.
+    BranchNotFound(Status),
+    /// A file does not exist.
+    FileNotFound(Status),
+    /// A pipeline does not exist.
+    PipelineNotFound(Status),
+    /// The request requires auth, which has not been activated.
+    AuthNotActivated(Status),
+    /// The caller is not authorized to make the request.
+    NotAuthorized(Status),
+    /// A transaction could not be modified because of concurrent
+    /// modifications. Retrying the transaction may succeed.
+    TransactionConflict(Status),
+    /// pachd could not be reached.
+    Unavailable(Status),
+    /// Any other error returned by pachd.
+    Other(Status),
+}
+
+impl Error {
+    /// Gets the status pachd returned, if this error came from pachd.
+    pub fn status(&self) -> Option<&Status> {
+        match self {
+            Error::RepoNotFound(status)
+            | Error::RepoExists(status)
+            | Error::CommitNotFound(status)
+            | Error::CommitFinished(status)
+            | Error::BranchNotFound(status)
+            | Error::FileNotFound(status)
+            | Error::PipelineNotFound(status)
+            | Error::AuthNotActivated(status)
+            | Error::NotAuthorized(status)
+            | Error::TransactionConflict(status)
+            | Error::Unavailable(status)
+            | Error::Other(status) => Some(status),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Json(err) => write!(f, "could not parse pachctl config: {}", err),
+            Error::Config(msg) => write!(f, "pachctl config error: {}", msg),
+            Error::InvalidAddress(msg) => write!(f, "invalid pachd address: {}", msg),
+            Error::InvalidReference(msg) => write!(f, "invalid reference: {}", msg),
+            Error::InvalidGlob(msg) => write!(f, "invalid glob: {}", msg),
+            Error::InvalidMetadata(what) => write!(f, "{} is not valid gRPC metadata", what),
+            Error::Tls(msg) => write!(f, "TLS error: {}", msg),
+            Error::Transport(err) => write!(f, "could not connect to pachd: {}", err),
+            Error::DeploymentMismatch { expected, actual } => write!(
+                f,
+                "connected to the wrong cluster (expected deployment ID {:?}, but pachd reported {:?})",
+                expected, actual
+            ),
+            Error::UnsupportedVersion(version) if ApiVersion::SUPPORTED_FROM == ApiVersion::LATEST => write!(
+                f,
+                "pachd {} is not supported; this library supports pachd {}.x, or {}.x through {}.x with the \
+                 `legacy-versions` feature",
+                version,
+                ApiVersion::LATEST,
+                ApiVersion::OLDEST,
+                ApiVersion::LATEST
+            ),
+            Error::UnsupportedVersion(version) => write!(
+                f,
+                "pachd {} is not supported; this library supports pachd {}.x through {}.x",
+                version,
+                ApiVersion::SUPPORTED_FROM,
+                ApiVersion::LATEST
+            ),
+            Error::Conversion(msg) => write!(f, "could not convert a message between pachd API versions: {}", msg),
+            Error::InvalidTransactionRequest(msg) => write!(f, "invalid transaction request: {}", msg),
+            Error::RepoNotFound(status)
+            | Error::RepoExists(status)
+            | Error::CommitNotFound(status)
+            | Error::CommitFinished(status)
+            | Error::BranchNotFound(status)
+            | Error::FileNotFound(status)
+            | Error::PipelineNotFound(status)
+            | Error::AuthNotActivated(status)
+            | Error::NotAuthorized(status)
+            | Error::TransactionConflict(status) => f.write_str(status.message()),
+            Error::Unavailable(status) => write!(f, "pachd is unavailable: {}", status.message()),
+            Error::Other(status) => write!(f, "pachd error ({:?}): {}", status.code(), status.message()),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::Transport(err) => Some(err),
+            Error::Config(_)
+            | Error::Conversion(_)
+            | Error::InvalidAddress(_)
+            | Error::InvalidReference(_)
+            | Error::InvalidGlob(_)
+            | Error::InvalidMetadata(_)
+            | Error::Tls(_)
+            | Error::DeploymentMismatch { .. }
+            | Error::UnsupportedVersion(_)
+            | Error::InvalidTransactionRequest(_) => None,
+            Error::RepoNotFound(status)
+            | Error::RepoExists(status)
+            | Error::CommitNotFound(status)
+            | Error::CommitFinished(status)
+            | Error::BranchNotFound(status)
+            | Error::FileNotFound(status)
+            | Error::PipelineNotFound(status)
+            | Error::AuthNotActivated(status)
+            | Error::NotAuthorized(status)
+            | Error::TransactionConflict(status)
+            | Error::Unavailable(status)
+            | Error::Other(status) => Some(status),
+        }
+    }
+}
+
+impl From<IoError> for Error {
+    fn from(err: IoError) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<JsonError> for Error {
+    fn from(err: JsonError) -> Self {
+        Error::Json(err)
+    }
+}
+
+impl From<TransportError> for Error {
+    fn from(err: TransportError) -> Self {
+        Error::Transport(err)
+    }
+}
+
+/// Converts an error into an I/O error, e.g. for `std::io::Read`
+/// implementations, keeping the kind of error where there's an equivalent.
+impl From<Error> for IoError {
+    fn from(err: Error) -> Self {
+        let kind = match &err {
+            Error::RepoNotFound(_)
+            | Error::CommitNotFound(_)
+            | Error::BranchNotFound(_)
+            | Error::FileNotFound(_)
+            | Error::PipelineNotFound(_) => ErrorKind::NotFound,
+            Error::RepoExists(_) => ErrorKind::AlreadyExists,
+            Error::NotAuthorized(_) => ErrorKind::PermissionDenied,
+            _ => ErrorKind::Other,
+        };
+        match err {
+            Error::Io(err) => err,
+            err => IoError::new(kind, err),
+        }
+    }
+}
+
+/// Classifies an error returned by pachd. pachd returns most errors with the
+/// `Unknown` code, so this mostly goes by the same message patterns pachctl
+/// uses to recognize them.
+impl From<Status> for Error {
+    fn from(status: Status) -> Self {
+        let message = status.message();
+
+        if status.code() == Code::Unavailable || is_unreachable(&status) {
+            Error::Unavailable(status)
+        } else if message.contains("the auth service is not activated") {
+            Error::AuthNotActivated(status)
+        } else if message.contains("not authorized to perform this operation")
+            || status.code() == Code::PermissionDenied
+            || status.code() == Code::Unauthenticated
+        {
+            Error::NotAuthorized(status)
+        } else if message.contains("due to concurrent modifications") {
+            Error::TransactionConflict(status)
+        } else if word_indices(message, "file ").any(|i| message[i..].contains(" not found")) {
+            // File paths may contain spaces, so this is checked loosely, and
+            // before the other "not found" errors, since its message also
+            // names the repo and commit
+            Error::FileNotFound(status)
+        } else if contains_pattern(message, "branch ", " not found in repo ") {
+            Error::BranchNotFound(status)
+        } else if contains_pattern(message, "commit ", " not found in repo ") {
+            Error::CommitNotFound(status)
+        } else if contains_pattern(message, "commit ", " in repo ") && message.contains(" has already finished") {
+            Error::CommitFinished(status)
+        } else if contains_pattern(message, "pipeline ", " not found") {
+            Error::PipelineNotFound(status)
+        } else if contains_pattern(message, "repo ", " already exists") {
+            Error::RepoExists(status)
+        } else if contains_pattern(message, "repo ", " not found") || contains_pattern(message, "repos ", " not found")
+        {
+            Error::RepoNotFound(status)
+        } else {
+            Error::Other(status)
+        }
+    }
+}
+
+/// Whether a status is how tonic reports failing to reach pachd, e.g. while
+/// it's restarting, rather than an error pachd returned. tonic gives these
+/// the `Unknown` code, with its transport's error as the message.
+pub(crate) fn is_unreachable(status: &Status) -> bool {
+    let message = status.message();
+    status.code() == Code::Unknown
+        && (message == "transport error"
+            || message.starts_with("Service was not ready")
+            || message.starts_with("error trying to connect"))
+}
+
+/// Whether `message` contains `prefix`, immediately followed by a single
+/// word (e.g. a repo name), immediately followed by `suffix`.
+fn contains_pattern(message: &str, prefix: &str, suffix: &str) -> bool {
+    word_indices(message, prefix).any(|i| {
+        let rest = &message[i + prefix.len()..];
+        let word_len = rest.find(' ').unwrap_or(rest.len());
+        word_len > 0 && rest[word_len..].starts_with(suffix)
+    })
+}
+
+/// Finds where `word` occurs in `message`, ignoring occurrences that are
+/// part of a larger word, e.g. `file ` in `profile `.
+fn word_indices<'a>(message: &'a str, word: &'a str) -> impl Iterator<Item = usize> + 'a {
+    message.match_indices(word).map(|(i, _)| i).filter(move |&i| {
+        message[..i]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '-' || c == '.'))
+    })
+}
+
+// Lets conversions that can't fail be used wherever fallible ones are
+impl From<Infallible> for Error {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(code: Code, message: &str) -> Error {
+        Error::from(Status::new(code, message))
+    }
+
+    fn unknown(message: &str) -> Error {
+        classify(Code::Unknown, message)
+    }
+
+    #[test]
+    fn classifies_unreachable_pachd() {
+        assert!(matches!(
+            classify(Code::Unavailable, "connection reset"),
+            Error::Unavailable(_)
+        ));
+        assert!(matches!(unknown("transport error"), Error::Unavailable(_)));
+        assert!(matches!(
+            unknown("Service was not ready: transport error"),
+            Error::Unavailable(_)
+        ));
+        assert!(matches!(
+            unknown("error trying to connect: tcp connect error: Connection refused (os error 111)"),
+            Error::Unavailable(_)
+        ));
+        // Only tonic's own statuses count, not pachd's errors that mention
+        // the transport
+        assert!(matches!(unknown("upload failed: transport error"), Error::Other(_)));
+        assert!(matches!(classify(Code::Internal, "transport error"), Error::Other(_)));
+    }
+
+    #[test]
+    fn classifies_auth_errors() {
+        assert!(matches!(
+            unknown("the auth service is not activated"),
+            Error::AuthNotActivated(_)
+        ));
+        assert!(matches!(
+            unknown("alice is not authorized to perform this operation; must have at least READER access"),
+            Error::NotAuthorized(_)
+        ));
+        assert!(matches!(
+            classify(Code::PermissionDenied, "no"),
+            Error::NotAuthorized(_)
+        ));
+        assert!(matches!(classify(Code::Unauthenticated, "no"), Error::NotAuthorized(_)));
+    }
+
+    #[test]
+    fn classifies_transaction_conflicts() {
+        assert!(matches!(
+            unknown("transaction 1234 failed due to concurrent modifications"),
+            Error::TransactionConflict(_)
+        ));
+    }
+
+    #[test]
+    fn classifies_not_found_errors() {
+        assert!(matches!(
+            unknown("file /cats/a b.png not found in repo images at commit 1234"),
+            Error::FileNotFound(_)
+        ));
+        assert!(matches!(
+            unknown("branch master not found in repo images"),
+            Error::BranchNotFound(_)
+        ));
+        assert!(matches!(
+            unknown("commit 1234 not found in repo images"),
+            Error::CommitNotFound(_)
+        ));
+        assert!(matches!(
+            unknown("pipeline edges not found"),
+            Error::PipelineNotFound(_)
+        ));
+        assert!(matches!(unknown("repo images not found"), Error::RepoNotFound(_)));
+        assert!(matches!(unknown("repos images not found"), Error::RepoNotFound(_)));
+    }
+
+    #[test]
+    fn classifies_other_repo_and_commit_errors() {
+        assert!(matches!(unknown("repo images already exists"), Error::RepoExists(_)));
+        assert!(matches!(
+            unknown("commit 1234 in repo images has already finished"),
+            Error::CommitFinished(_)
+        ));
+    }
+
+    #[test]
+    fn only_matches_whole_words() {
+        assert!(matches!(unknown("profile cats not found"), Error::Other(_)));
+        assert!(matches!(unknown("my-file cats not found"), Error::Other(_)));
+        assert!(matches!(
+            unknown("subbranch master not found in repo images"),
+            Error::Other(_)
+        ));
+        assert!(matches!(
+            unknown("recommit 1234 not found in repo images"),
+            Error::Other(_)
+        ));
+        assert!(matches!(unknown("object_repo images not found"), Error::Other(_)));
+        assert!(matches!(unknown("etc.repo images already exists"), Error::Other(_)));
+        // Names are single words, and can't be empty
+        assert!(matches!(unknown("branch  not found in repo images"), Error::Other(_)));
+        assert!(matches!(unknown("pipeline my pipeline not found"), Error::Other(_)));
+        // Whole words count wherever they are in the message
+        assert!(matches!(
+            unknown("error: branch master not found in repo images"),
+            Error::BranchNotFound(_)
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_other() {
+        assert!(matches!(unknown("something went wrong"), Error::Other(_)));
+        assert!(matches!(classify(Code::NotFound, "gone"), Error::Other(_)));
+    }
+}
@@ -0,0 +1,799 @@
+//! Files in PFS as async I/O streams.
+
+use std::fmt;
+use std::io::{self, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::future::BoxFuture;
+use futures::ready;
+use futures::stream::StreamExt;
+use tokio::io::{AsyncRead, AsyncSeek, AsyncWrite};
+
+use crate::api::{PfsApi, Streaming};
+use crate::pfs::{Delimiter, File, GetFileRequest, InspectFileRequest, OverwriteIndex, PutFileRequest};
+use crate::Error;
+
+/// How many bytes a reader asks for per `GetFile` call by default.
+const DEFAULT_READ_AHEAD: usize = 4 * 1024 * 1024;
+
+/// The most data a writer sends per `PutFileRequest`: half of the 20MB limit
+/// pachd puts on gRPC messages, which leaves plenty of room for the rest of
+/// the request.
+pub(crate) const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// A reader of a file in PFS, with random access, e.g. for formats such as
+/// Parquet and zip that are read out of order.
+///
+/// The file's length is learned via `InspectFile` when it's opened, and
+/// reads are made from the commit it was inspected in, so they stay
+/// consistent even if the file's branch moves on. Data is fetched via
+/// ranged `GetFile` calls, each of which reads ahead of what was asked for,
+/// up to `read_ahead` bytes. Seeking within the range being fetched skips
+/// ahead in it, and seeking anywhere else starts a new call at the next
+/// read.
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+/// use std::io::SeekFrom;
+///
+/// use pachyderm::PfsFileReader;
+/// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+///
+/// let file = "data@master:/table.parquet".parse()?;
+/// let mut reader = PfsFileReader::open(client.pfs(), file).await?;
+/// let mut footer = [0; 8];
+/// reader.seek(SeekFrom::End(-8)).await?;
+/// reader.read_exact(&mut footer).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PfsFileReader<P> {
+    pfs: P,
+    file: File,
+    len: u64,
+    pos: u64,
+    read_ahead: usize,
+    /// The latest chunk received, which starts at `chunk_start`
+    chunk: Vec<u8>,
+    chunk_start: u64,
+    fetch: Fetch,
+    /// Where the current fetch's next chunk starts
+    fetched: u64,
+    /// Where the current fetch ends
+    fetch_end: u64,
+}
+
+/// The state of the `GetFile` call a reader is fetching data with.
+enum Fetch {
+    Idle,
+    Opening(BoxFuture<'static, Result<Streaming<Vec<u8>>, Error>>),
+    Streaming(Streaming<Vec<u8>>),
+}
+
+impl<P: PfsApi + Clone + Unpin + 'static> PfsFileReader<P> {
+    /// Opens a file for reading, which is given by a commit or branch and a
+    /// path.
+    pub async fn open(mut pfs: P, file: File) -> Result<Self, Error> {
+        let request = InspectFileRequest {
+            file: Some(file.clone()),
+        };
+        let info = pfs.inspect_file(request).await?;
+        let file = info.file.filter(|file| file.commit.is_some()).unwrap_or(file);
+
+        Ok(PfsFileReader {
+            pfs,
+            file,
+            len: info.size_bytes,
+            pos: 0,
+            read_ahead: DEFAULT_READ_AHEAD,
+            chunk: Vec::new(),
+            chunk_start: 0,
+            fetch: Fetch::Idle,
+            fetched: 0,
+            fetch_end: 0,
+        })
+    }
+
+    /// Sets how many bytes each `GetFile` call asks for, unless a read asks
+    /// for more. Defaults to 4MiB.
+    pub fn read_ahead(mut self, bytes: usize) -> Self {
+        self.read_ahead = bytes.max(1);
+        self
+    }
+
+    /// Gets the file being read, in the commit it's read from.
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    /// Gets the file's length in bytes.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Checks whether the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Starts a `GetFile` call from the current position, for at least
+    /// `wanted` bytes.
+    fn start_fetch(&mut self, wanted: usize) {
+        let size = (self.read_ahead.max(wanted) as u64).min(self.len - self.pos);
+        let request = GetFileRequest {
+            file: Some(self.file.clone()),
+            offset_bytes: self.pos as i64,
+            size_bytes: size as i64,
+        };
+        let mut pfs = self.pfs.clone();
+        self.fetch = Fetch::Opening(Box::pin(async move { pfs.get_file(request).await }));
+        self.fetched = self.pos;
+        self.fetch_end = self.pos + size;
+    }
+
+    /// Copies what it can into `buf` from the latest chunk, if the current
+    /// position is in it.
+    fn read_chunk(&mut self, buf: &mut [u8]) -> Option<usize> {
+        let chunk_end = self.chunk_start + self.chunk.len() as u64;
+        if self.pos < self.chunk_start || self.pos >= chunk_end {
+            return None;
+        }
+        let offset = (self.pos - self.chunk_start) as usize;
+        let read = buf.len().min(self.chunk.len() - offset);
+        buf[..read].copy_from_slice(&self.chunk[offset..offset + read]);
+        self.pos += read as u64;
+        Some(read)
+    }
+}
+
+impl<P: PfsApi + Clone + Unpin + 'static> AsyncRead for PfsFileReader<P> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        loop {
+            if this.pos >= this.len || buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+            if let Some(read) = this.read_chunk(buf) {
+                return Poll::Ready(Ok(read));
+            }
+
+            match &mut this.fetch {
+                Fetch::Opening(opening) => match ready!(opening.as_mut().poll(cx)) {
+                    Ok(chunks) => this.fetch = Fetch::Streaming(chunks),
+                    Err(err) => {
+                        this.fetch = Fetch::Idle;
+                        return Poll::Ready(Err(err.into()));
+                    }
+                },
+                Fetch::Streaming(chunks) if this.fetched <= this.pos => match ready!(chunks.poll_next_unpin(cx)) {
+                    Some(Ok(chunk)) => {
+                        this.chunk_start = this.fetched;
+                        this.fetched += chunk.len() as u64;
+                        this.chunk = chunk;
+                    }
+                    Some(Err(err)) => {
+                        this.fetch = Fetch::Idle;
+                        return Poll::Ready(Err(err.into()));
+                    }
+                    None if this.fetched < this.fetch_end => {
+                        this.fetch = Fetch::Idle;
+                        return Poll::Ready(Err(io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            format!("{} ended before its length of {} bytes", this.file, this.len),
+                        )));
+                    }
+                    None => this.fetch = Fetch::Idle,
+                },
+                _ => this.start_fetch(buf.len()),
+            }
+        }
+    }
+}
+
+/// Seeking past the end of the file is allowed, after which reads return
+/// nothing, but seeking before its start fails.
+impl<P: PfsApi + Clone + Unpin + 'static> AsyncSeek for PfsFileReader<P> {
+    fn start_seek(self: Pin<&mut Self>, _: &mut Context<'_>, position: SeekFrom) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let pos = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => add_offset(this.len, offset),
+            SeekFrom::Current(offset) => add_offset(this.pos, offset),
+        };
+        let pos = match pos {
+            Some(pos) => pos,
+            None => {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )))
+            }
+        };
+
+        // A fetch is only worth keeping if it has yet to reach the new
+        // position
+        if pos < this.fetched || pos >= this.fetch_end {
+            this.fetch = Fetch::Idle;
+        }
+        this.pos = pos;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        Poll::Ready(Ok(self.pos))
+    }
+}
+
+impl<P> fmt::Debug for PfsFileReader<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PfsFileReader")
+            .field("file", &self.file)
+            .field("len", &self.len)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A writer of a file in PFS, which streams what's written to pachd via
+/// `PutFile`, split into requests that fit under gRPC's limit on message
+/// sizes.
+///
+/// As with `PutFile`, the data is appended to the file, unless the writer
+/// is configured otherwise via `PfsFileWriter::builder`. Nothing is certain
+/// to be written until `shutdown` completes, which waits for pachd to have
+/// written the file; dropping the writer before then cancels the call.
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+/// use pachyderm::pfs::Delimiter;
+/// use pachyderm::PfsFileWriter;
+/// use tokio::io::AsyncWriteExt;
+///
+/// let file = "logs@master:/2020-08-01".parse()?;
+/// let mut writer = PfsFileWriter::builder()
+///     .delimiter(Delimiter::Line)
+///     .target_file_datums(1000)
+///     .open(client.pfs(), file);
+/// writer.write_all(b"first line\nsecond line\n").await?;
+/// writer.shutdown().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PfsFileWriter {
+    /// The first request, which carries the file and options, until it's
+    /// sent
+    first: Option<PutFileRequest>,
+    /// Data written but not yet sent
+    buffer: Vec<u8>,
+    requests: mpsc::Sender<PutFileRequest>,
+    /// The `PutFile` call, until it ends
+    call: Option<BoxFuture<'static, Result<(), Error>>>,
+    finished: bool,
+}
+
+impl PfsFileWriter {
+    /// Opens a file for writing with default options, i.e. appending to it.
+    pub fn open<P: PfsApi + 'static>(pfs: P, file: File) -> Self {
+        PfsFileWriterBuilder::default().open(pfs, file)
+    }
+
+    /// Creates a builder for configuring a writer, e.g. to split the data
+    /// into several files.
+    pub fn builder() -> PfsFileWriterBuilder {
+        PfsFileWriterBuilder::default()
+    }
+
+    /// Checks that the call is still running, since it ending before the
+    /// writer is shut down means it failed.
+    fn poll_running(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        let call = self.call.as_mut().ok_or_else(ended)?;
+        if let Poll::Ready(result) = call.as_mut().poll(cx) {
+            self.call = None;
+            result?;
+            return Err(ended());
+        }
+        Ok(())
+    }
+
+    /// Sends what's buffered, along with the file and options if they
+    /// haven't been sent yet.
+    fn poll_send(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.requests.poll_ready(cx)).map_err(|_| ended())?;
+        let value = std::mem::take(&mut self.buffer);
+        let request = match self.first.take() {
+            Some(first) => PutFileRequest { value, ..first },
+            None => PutFileRequest {
+                value,
+                ..Default::default()
+            },
+        };
+        self.requests.start_send(request).map_err(|_| ended())?;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for PfsFileWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.poll_running(cx)?;
+        if this.buffer.len() >= CHUNK_SIZE {
+            ready!(this.poll_send(cx))?;
+        }
+        let written = buf.len().min(CHUNK_SIZE - this.buffer.len());
+        this.buffer.extend_from_slice(&buf[..written]);
+        Poll::Ready(Ok(written))
+    }
+
+    /// Sends what's buffered to pachd, without waiting for it to be
+    /// written.
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.poll_running(cx)?;
+        if !this.buffer.is_empty() {
+            ready!(this.poll_send(cx))?;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Sends what's buffered, and waits for pachd to finish writing the
+    /// file.
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.finished {
+            return Poll::Ready(Ok(()));
+        }
+        if this.first.is_some() || !this.buffer.is_empty() {
+            this.poll_running(cx)?;
+            ready!(this.poll_send(cx))?;
+        }
+        this.requests.close_channel();
+
+        let call = this.call.as_mut().ok_or_else(ended)?;
+        let result = ready!(call.as_mut().poll(cx));
+        this.call = None;
+        result?;
+        this.finished = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl fmt::Debug for PfsFileWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PfsFileWriter")
+            .field("buffered", &self.buffer.len())
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Configures and opens a `PfsFileWriter`. The options are those of
+/// `PutFileRequest`.
+#[derive(Clone, Debug, Default)]
+pub struct PfsFileWriterBuilder {
+    request: PutFileRequest,
+}
+
+impl PfsFileWriterBuilder {
+    /// Splits the data into separate files at the given delimiter, e.g. by
+    /// line, which are written under the file's path.
+    pub fn delimiter(mut self, delimiter: Delimiter) -> Self {
+        self.request.delimiter = delimiter as i32;
+        self
+    }
+
+    /// Sets the most datums, e.g. lines, in each file the data is split
+    /// into. By default, each datum gets its own file.
+    pub fn target_file_datums(mut self, datums: i64) -> Self {
+        self.request.target_file_datums = datums;
+        self
+    }
+
+    /// Sets roughly how many bytes each file the data is split into has.
+    pub fn target_file_bytes(mut self, bytes: i64) -> Self {
+        self.request.target_file_bytes = bytes;
+        self
+    }
+
+    /// Sets how many records at the start of the data are a header, which
+    /// is applied to each file it's split into, e.g. 1 for a CSV file with
+    /// column names. This doesn't apply to SQL, whose headers are found
+    /// automatically.
+    pub fn header_records(mut self, records: i64) -> Self {
+        self.request.header_records = records;
+        self
+    }
+
+    /// Overwrites the file from the given object index on, rather than
+    /// appending to it. An index of 0 overwrites the whole file, and for
+    /// data that's split, the index is that of the file to start from.
+    pub fn overwrite_index(mut self, index: i64) -> Self {
+        self.request.overwrite_index = Some(OverwriteIndex { index });
+        self
+    }
+
+    /// Opens a file for writing. The `PutFile` call starts when the writer
+    /// is first written to or shut down.
+    pub fn open<P: PfsApi + 'static>(self, mut pfs: P, file: File) -> PfsFileWriter {
+        let (requests, stream) = mpsc::channel(1);
+        let call = Box::pin(async move { pfs.put_file(Box::pin(stream)).await });
+        PfsFileWriter {
+            first: Some(PutFileRequest {
+                file: Some(file),
+                ..self.request
+            }),
+            buffer: Vec::new(),
+            requests,
+            call: Some(call),
+            finished: false,
+        }
+    }
+}
+
+/// The error writes fail with once the `PutFile` call has ended.
+fn ended() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "the PutFile call has ended")
+}
+
+fn add_offset(pos: u64, offset: i64) -> Option<u64> {
+    if offset >= 0 {
+        pos.checked_add(offset as u64)
+    } else {
+        pos.checked_sub(offset.unsigned_abs())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::stream::{self, TryStreamExt};
+    use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+    use tonic::transport::Channel;
+
+    use super::*;
+    use crate::api::RequestStream;
+    use crate::pfs::api_client::ApiClient;
+    use crate::pfs::{
+        Commit, CreateRepoRequest, FileInfo, FileType, FinishCommitRequest, GlobFileRequest, Repo, StartCommitRequest,
+    };
+    use crate::testing::FakePachd;
+
+    /// Forwards PFS calls to the fake pachd, recording the offset and size
+    /// of each `GetFile` call, and the size of each `PutFileRequest`'s data.
+    #[derive(Clone)]
+    struct Recording {
+        pfs: ApiClient<Channel>,
+        gets: Arc<Mutex<Vec<(i64, i64)>>>,
+        puts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Recording {
+        fn new(pfs: ApiClient<Channel>) -> Self {
+            Recording {
+                pfs,
+                gets: Arc::default(),
+                puts: Arc::default(),
+            }
+        }
+
+        fn gets(&self) -> Vec<(i64, i64)> {
+            self.gets.lock().unwrap().clone()
+        }
+
+        fn puts(&self) -> Vec<usize> {
+            self.puts.lock().unwrap().clone()
+        }
+    }
+
+    #[tonic::async_trait]
+    impl PfsApi for Recording {
+        async fn inspect_file(&mut self, request: InspectFileRequest) -> Result<FileInfo, Error> {
+            PfsApi::inspect_file(&mut self.pfs, request).await
+        }
+
+        async fn get_file(&mut self, request: GetFileRequest) -> Result<Streaming<Vec<u8>>, Error> {
+            let range = (request.offset_bytes, request.size_bytes);
+            self.gets.lock().unwrap().push(range);
+            PfsApi::get_file(&mut self.pfs, request).await
+        }
+
+        async fn put_file(&mut self, requests: RequestStream<PutFileRequest>) -> Result<(), Error> {
+            let puts = self.puts.clone();
+            let requests = requests.inspect(move |request| puts.lock().unwrap().push(request.value.len()));
+            PfsApi::put_file(&mut self.pfs, Box::pin(requests)).await
+        }
+    }
+
+    /// Creates the repo `images`, and starts a commit on its master branch.
+    async fn start_commit(pachd: &FakePachd) -> (ApiClient<Channel>, Commit) {
+        let mut pfs = pachd.client().await.unwrap().pfs();
+        let repo = Repo { name: "images".into() };
+        let request = CreateRepoRequest {
+            repo: Some(repo.clone()),
+            ..Default::default()
+        };
+        PfsApi::create_repo(&mut pfs, request).await.unwrap();
+        let request = StartCommitRequest {
+            parent: Some(Commit {
+                repo: Some(repo),
+                id: String::new(),
+            }),
+            branch: "master".into(),
+            ..Default::default()
+        };
+        let commit = PfsApi::start_commit(&mut pfs, request).await.unwrap();
+        (pfs, commit)
+    }
+
+    fn file(commit: &Commit, path: &str) -> File {
+        File {
+            commit: Some(commit.clone()),
+            path: path.into(),
+        }
+    }
+
+    /// Data that differs at every offset of the files these tests read.
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Writes `/data` in a finished commit, and opens a reader of it via
+    /// the master branch.
+    async fn open_reader(pachd: &FakePachd, data: &[u8]) -> (PfsFileReader<Recording>, Recording) {
+        let (mut pfs, commit) = start_commit(pachd).await;
+        let request = PutFileRequest {
+            file: Some(file(&commit, "/data")),
+            value: data.to_vec(),
+            ..Default::default()
+        };
+        PfsApi::put_file(&mut pfs, Box::pin(stream::iter(vec![request])))
+            .await
+            .unwrap();
+        let request = FinishCommitRequest {
+            commit: Some(commit.clone()),
+            ..Default::default()
+        };
+        PfsApi::finish_commit(&mut pfs, request).await.unwrap();
+
+        let recording = Recording::new(pfs);
+        let master = Commit {
+            id: "master".into(),
+            ..commit
+        };
+        let reader = PfsFileReader::open(recording.clone(), file(&master, "/data"))
+            .await
+            .unwrap();
+        (reader, recording)
+    }
+
+    #[tokio::test]
+    async fn reads_across_chunks() {
+        let pachd = FakePachd::start().await.unwrap();
+        // The fake sends 1MiB chunks
+        let data = data((5 << 20) / 2);
+        let (mut reader, recording) = open_reader(&pachd, &data).await;
+        assert_eq!(reader.len(), data.len() as u64);
+        // Reads are from the commit the branch was at when it was opened
+        assert_ne!(reader.file().commit.as_ref().unwrap().id, "master");
+
+        let mut read = Vec::new();
+        reader.read_to_end(&mut read).await.unwrap();
+        assert!(read == data, "read {} bytes, expected {}", read.len(), data.len());
+        assert_eq!(recording.gets(), vec![(0, data.len() as i64)]);
+
+        // A read that starts before a chunk boundary and ends after it
+        reader.seek(SeekFrom::Start((1 << 20) - 50)).await.unwrap();
+        let mut buf = [0; 100];
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[(1 << 20) - 50..(1 << 20) + 50]);
+    }
+
+    #[tokio::test]
+    async fn seeks() {
+        let pachd = FakePachd::start().await.unwrap();
+        let data = data(1000);
+        let (mut reader, _) = open_reader(&pachd, &data).await;
+        let mut buf = [0; 10];
+
+        assert_eq!(reader.seek(SeekFrom::Start(100)).await.unwrap(), 100);
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[100..110]);
+
+        assert_eq!(reader.seek(SeekFrom::Current(15)).await.unwrap(), 125);
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[125..135]);
+
+        assert_eq!(reader.seek(SeekFrom::Current(-30)).await.unwrap(), 105);
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[105..115]);
+
+        assert_eq!(reader.seek(SeekFrom::End(-10)).await.unwrap(), 990);
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[990..]);
+
+        // Back to the start, after having read to the end
+        assert_eq!(reader.seek(SeekFrom::Start(0)).await.unwrap(), 0);
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[..10]);
+    }
+
+    #[tokio::test]
+    async fn seeks_past_the_end() {
+        let pachd = FakePachd::start().await.unwrap();
+        let data = data(1000);
+        let (mut reader, recording) = open_reader(&pachd, &data).await;
+        let mut buf = [0; 10];
+
+        assert_eq!(reader.seek(SeekFrom::End(10)).await.unwrap(), 1010);
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
+        assert_eq!(reader.seek(SeekFrom::Start(5000)).await.unwrap(), 5000);
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
+        assert!(recording.gets().is_empty());
+
+        let err = reader.seek(SeekFrom::Current(-5001)).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = reader.seek(SeekFrom::End(-1001)).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        // A failed seek leaves the position as it was
+        assert_eq!(reader.seek(SeekFrom::Current(0)).await.unwrap(), 5000);
+    }
+
+    #[tokio::test]
+    async fn reads_ahead() {
+        let pachd = FakePachd::start().await.unwrap();
+        let data = data(10_000);
+        let (reader, recording) = open_reader(&pachd, &data).await;
+        let mut reader = reader.read_ahead(1000);
+        let mut buf = [0; 10];
+
+        // Reads within what was read ahead don't make another call, even
+        // after seeking back
+        reader.read_exact(&mut buf).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        reader.seek(SeekFrom::Start(500)).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        reader.seek(SeekFrom::Start(5)).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[5..15]);
+        assert_eq!(recording.gets(), vec![(0, 1000)]);
+
+        // Reading past it makes a new call from where the read starts
+        reader.seek(SeekFrom::Start(995)).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf[..], &data[995..1005]);
+        assert_eq!(recording.gets(), vec![(0, 1000), (1000, 1000)]);
+
+        // Reads larger than the read-ahead ask for all they want
+        reader.seek(SeekFrom::Start(5000)).await.unwrap();
+        let mut big = vec![0; 3000];
+        reader.read_exact(&mut big).await.unwrap();
+        assert!(big[..] == data[5000..8000]);
+        assert_eq!(recording.gets()[2], (5000, 3000));
+
+        // Calls stop at the end of the file
+        reader.seek(SeekFrom::End(-10)).await.unwrap();
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(&rest[..], &data[9990..]);
+        assert_eq!(recording.gets()[3], (9990, 10));
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
+        assert_eq!(recording.gets().len(), 4);
+    }
+
+    /// Reads a file, or the concatenation of the files in a directory.
+    async fn read(pfs: &mut ApiClient<Channel>, file: File) -> Result<Vec<u8>, Error> {
+        let request = GetFileRequest {
+            file: Some(file),
+            ..Default::default()
+        };
+        let chunks: Vec<Vec<u8>> = PfsApi::get_file(pfs, request).await?.try_collect().await?;
+        Ok(chunks.concat())
+    }
+
+    #[tokio::test]
+    async fn splits_writes_into_chunks() {
+        let pachd = FakePachd::start().await.unwrap();
+        let (mut pfs, commit) = start_commit(&pachd).await;
+        let recording = Recording::new(pfs.clone());
+        let data = data(CHUNK_SIZE * 5 / 2);
+
+        let mut writer = PfsFileWriter::open(recording.clone(), file(&commit, "/data"));
+        writer.write_all(&data).await.unwrap();
+        writer.shutdown().await.unwrap();
+
+        assert_eq!(recording.puts(), vec![CHUNK_SIZE, CHUNK_SIZE, CHUNK_SIZE / 2]);
+        let written = read(&mut pfs, file(&commit, "/data")).await.unwrap();
+        assert!(
+            written == data,
+            "wrote {} bytes, expected {}",
+            written.len(),
+            data.len()
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_on_shutdown() {
+        let pachd = FakePachd::start().await.unwrap();
+        let (mut pfs, commit) = start_commit(&pachd).await;
+
+        let mut writer = PfsFileWriter::open(pfs.clone(), file(&commit, "/cat.txt"));
+        writer.write_all(b"meow").await.unwrap();
+        writer.flush().await.unwrap();
+        // Nothing's certain to be written until the call's finished
+        let err = read(&mut pfs, file(&commit, "/cat.txt")).await.unwrap_err();
+        assert!(matches!(err, Error::FileNotFound(_)), "{:?}", err);
+
+        writer.shutdown().await.unwrap();
+        assert_eq!(read(&mut pfs, file(&commit, "/cat.txt")).await.unwrap(), b"meow");
+        // Shutting down again does nothing, and writing fails
+        writer.shutdown().await.unwrap();
+        let err = writer.write_all(b"purr").await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        // Shutting down a writer that wasn't written to creates an empty file
+        let mut writer = PfsFileWriter::open(pfs.clone(), file(&commit, "/empty.txt"));
+        writer.shutdown().await.unwrap();
+        assert_eq!(read(&mut pfs, file(&commit, "/empty.txt")).await.unwrap(), b"");
+    }
+
+    #[tokio::test]
+    async fn writes_nothing_when_dropped() {
+        let pachd = FakePachd::start().await.unwrap();
+        let (mut pfs, commit) = start_commit(&pachd).await;
+
+        let mut writer = PfsFileWriter::open(pfs.clone(), file(&commit, "/cat.txt"));
+        writer.write_all(b"meow").await.unwrap();
+        writer.flush().await.unwrap();
+        drop(writer);
+        // Give the fake time to see the call cancelled
+        tokio::time::delay_for(std::time::Duration::from_millis(100)).await;
+
+        let err = read(&mut pfs, file(&commit, "/cat.txt")).await.unwrap_err();
+        assert!(matches!(err, Error::FileNotFound(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn appends_or_overwrites() {
+        let pachd = FakePachd::start().await.unwrap();
+        let (mut pfs, commit) = start_commit(&pachd).await;
+
+        for (builder, data) in [
+            (PfsFileWriter::builder(), "meow"),
+            (PfsFileWriter::builder(), " purr"),
+            (PfsFileWriter::builder().overwrite_index(0), "hiss"),
+            (PfsFileWriter::builder(), " growl"),
+        ] {
+            let mut writer = builder.open(pfs.clone(), file(&commit, "/cat.txt"));
+            writer.write_all(data.as_bytes()).await.unwrap();
+            writer.shutdown().await.unwrap();
+        }
+        assert_eq!(read(&mut pfs, file(&commit, "/cat.txt")).await.unwrap(), b"hiss growl");
+    }
+
+    #[tokio::test]
+    async fn splits_files_by_delimiter() {
+        let pachd = FakePachd::start().await.unwrap();
+        let (mut pfs, commit) = start_commit(&pachd).await;
+
+        let mut writer = PfsFileWriter::builder()
+            .delimiter(Delimiter::Line)
+            .target_file_datums(2)
+            .open(pfs.clone(), file(&commit, "/lines"));
+        writer.write_all(b"one\ntwo\nthree\n").await.unwrap();
+        writer.shutdown().await.unwrap();
+
+        let request = GlobFileRequest {
+            commit: Some(commit.clone()),
+            pattern: "/lines/*".into(),
+        };
+        let infos = PfsApi::glob_file(&mut pfs, request).await.unwrap().file_info;
+        assert!(infos.iter().all(|info| info.file_type == FileType::File as i32));
+        let mut files = Vec::new();
+        for info in infos {
+            files.push(read(&mut pfs, info.file.unwrap()).await.unwrap());
+        }
+        assert_eq!(files, vec![b"one\ntwo\n".to_vec(), b"three\n".to_vec()]);
+    }
+}
@@ -0,0 +1,440 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Op17 {
+    #[prost(message, optional, tag = "2")]
+    pub object: ::std::option::Option<super::pfs_1_7::PutObjectRequest>,
+    #[prost(message, optional, tag = "3")]
+    pub tag: ::std::option::Option<super::pfs_1_7::TagObjectRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub repo: ::std::option::Option<super::pfs_1_7::CreateRepoRequest>,
+    #[prost(message, optional, tag = "5")]
+    pub commit: ::std::option::Option<super::pfs_1_7::BuildCommitRequest>,
+    #[prost(message, optional, tag = "6")]
+    pub branch: ::std::option::Option<super::pfs_1_7::CreateBranchRequest>,
+    #[prost(message, optional, tag = "7")]
+    pub pipeline: ::std::option::Option<super::pps_1_7::CreatePipelineRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Op18 {
+    #[prost(message, optional, tag = "2")]
+    pub object: ::std::option::Option<super::pfs_1_8::PutObjectRequest>,
+    #[prost(message, optional, tag = "3")]
+    pub tag: ::std::option::Option<super::pfs_1_8::TagObjectRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub repo: ::std::option::Option<super::pfs_1_8::CreateRepoRequest>,
+    #[prost(message, optional, tag = "5")]
+    pub commit: ::std::option::Option<super::pfs_1_8::BuildCommitRequest>,
+    #[prost(message, optional, tag = "6")]
+    pub branch: ::std::option::Option<super::pfs_1_8::CreateBranchRequest>,
+    #[prost(message, optional, tag = "7")]
+    pub pipeline: ::std::option::Option<super::pps_1_8::CreatePipelineRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Op19 {
+    #[prost(message, optional, tag = "2")]
+    pub object: ::std::option::Option<super::pfs_1_9::PutObjectRequest>,
+    #[prost(message, optional, tag = "9")]
+    pub create_object: ::std::option::Option<super::pfs_1_9::CreateObjectRequest>,
+    #[prost(message, optional, tag = "3")]
+    pub tag: ::std::option::Option<super::pfs_1_9::TagObjectRequest>,
+    #[prost(message, optional, tag = "10")]
+    pub block: ::std::option::Option<super::pfs_1_9::PutBlockRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub repo: ::std::option::Option<super::pfs_1_9::CreateRepoRequest>,
+    #[prost(message, optional, tag = "5")]
+    pub commit: ::std::option::Option<super::pfs_1_9::BuildCommitRequest>,
+    #[prost(message, optional, tag = "6")]
+    pub branch: ::std::option::Option<super::pfs_1_9::CreateBranchRequest>,
+    #[prost(message, optional, tag = "7")]
+    pub pipeline: ::std::option::Option<super::pps_1_9::CreatePipelineRequest>,
+    #[prost(message, optional, tag = "8")]
+    pub job: ::std::option::Option<super::pps_1_9::CreateJobRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Op110 {
+    #[prost(message, optional, tag = "2")]
+    pub object: ::std::option::Option<super::pfs_1_10::PutObjectRequest>,
+    #[prost(message, optional, tag = "9")]
+    pub create_object: ::std::option::Option<super::pfs_1_10::CreateObjectRequest>,
+    #[prost(message, optional, tag = "3")]
+    pub tag: ::std::option::Option<super::pfs_1_10::TagObjectRequest>,
+    #[prost(message, optional, tag = "10")]
+    pub block: ::std::option::Option<super::pfs_1_10::PutBlockRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub repo: ::std::option::Option<super::pfs_1_10::CreateRepoRequest>,
+    #[prost(message, optional, tag = "5")]
+    pub commit: ::std::option::Option<super::pfs_1_10::BuildCommitRequest>,
+    #[prost(message, optional, tag = "6")]
+    pub branch: ::std::option::Option<super::pfs_1_10::CreateBranchRequest>,
+    #[prost(message, optional, tag = "7")]
+    pub pipeline: ::std::option::Option<super::pps_1_10::CreatePipelineRequest>,
+    #[prost(message, optional, tag = "8")]
+    pub job: ::std::option::Option<super::pps_1_10::CreateJobRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Op111 {
+    #[prost(message, optional, tag = "2")]
+    pub object: ::std::option::Option<super::pfs::PutObjectRequest>,
+    #[prost(message, optional, tag = "9")]
+    pub create_object: ::std::option::Option<super::pfs::CreateObjectRequest>,
+    #[prost(message, optional, tag = "3")]
+    pub tag: ::std::option::Option<super::pfs::TagObjectRequest>,
+    #[prost(message, optional, tag = "10")]
+    pub block: ::std::option::Option<super::pfs::PutBlockRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub repo: ::std::option::Option<super::pfs::CreateRepoRequest>,
+    #[prost(message, optional, tag = "5")]
+    pub commit: ::std::option::Option<super::pfs::BuildCommitRequest>,
+    #[prost(message, optional, tag = "6")]
+    pub branch: ::std::option::Option<super::pfs::CreateBranchRequest>,
+    #[prost(message, optional, tag = "7")]
+    pub pipeline: ::std::option::Option<super::pps::CreatePipelineRequest>,
+    #[prost(message, optional, tag = "8")]
+    pub job: ::std::option::Option<super::pps::CreateJobRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Op {
+    #[prost(message, optional, tag = "1")]
+    pub op1_7: ::std::option::Option<Op17>,
+    #[prost(message, optional, tag = "2")]
+    pub op1_8: ::std::option::Option<Op18>,
+    #[prost(message, optional, tag = "3")]
+    pub op1_9: ::std::option::Option<Op19>,
+    #[prost(message, optional, tag = "4")]
+    pub op1_10: ::std::option::Option<Op110>,
+    #[prost(message, optional, tag = "5")]
+    pub op1_11: ::std::option::Option<Op111>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtractRequest {
+    /// URL is an object storage URL, if it's not "" data will be extracted to
+    /// this URL rather than returned.
+    #[prost(string, tag = "1")]
+    pub url: std::string::String,
+    /// NoObjects, if true, will cause extract to omit objects (and tags)
+    #[prost(bool, tag = "2")]
+    pub no_objects: bool,
+    /// NoRepos, if true, will cause extract to omit repos, commits and branches.
+    #[prost(bool, tag = "3")]
+    pub no_repos: bool,
+    /// NoPipelines, if true, will cause extract to omit pipelines.
+    #[prost(bool, tag = "4")]
+    pub no_pipelines: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtractPipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<super::pps::Pipeline>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreRequest {
+    #[prost(message, optional, tag = "1")]
+    pub op: ::std::option::Option<Op>,
+    /// URL is an object storage URL, if it's not "" data will be restored from
+    /// this URL.
+    #[prost(string, tag = "2")]
+    pub url: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusterInfo {
+    #[prost(string, tag = "1")]
+    pub id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub deployment_id: std::string::String,
+}
+#[doc = r" Generated client implementations."]
+pub mod api_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct ApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ApiClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ApiClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        pub async fn extract(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExtractRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::Op>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/admin.API/Extract");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn extract_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExtractPipelineRequest>,
+        ) -> Result<tonic::Response<super::Op>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/admin.API/ExtractPipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn restore(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RestoreRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/admin.API/Restore");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        pub async fn inspect_cluster(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> Result<tonic::Response<super::ClusterInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/admin.API/InspectCluster");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for ApiClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ApiClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ApiClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod api_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ApiServer."]
+    #[async_trait]
+    pub trait Api: Send + Sync + 'static {
+        #[doc = "Server streaming response type for the Extract method."]
+        type ExtractStream: Stream<Item = Result<super::Op, tonic::Status>> + Send + Sync + 'static;
+        async fn extract(
+            &self,
+            request: tonic::Request<super::ExtractRequest>,
+        ) -> Result<tonic::Response<Self::ExtractStream>, tonic::Status>;
+        async fn extract_pipeline(
+            &self,
+            request: tonic::Request<super::ExtractPipelineRequest>,
+        ) -> Result<tonic::Response<super::Op>, tonic::Status>;
+        async fn restore(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RestoreRequest>>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn inspect_cluster(
+            &self,
+            request: tonic::Request<()>,
+        ) -> Result<tonic::Response<super::ClusterInfo>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ApiServer<T: Api> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: Api> ApiServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ApiServer<T>
+    where
+        T: Api,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/admin.API/Extract" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExtractSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::ExtractRequest> for ExtractSvc<T> {
+                        type Response = super::Op;
+                        type ResponseStream = T::ExtractStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ExtractRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).extract(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ExtractSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin.API/ExtractPipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExtractPipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ExtractPipelineRequest> for ExtractPipelineSvc<T> {
+                        type Response = super::Op;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ExtractPipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).extract_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ExtractPipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin.API/Restore" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ClientStreamingService<super::RestoreRequest> for RestoreSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::RestoreRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).restore(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = RestoreSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/admin.API/InspectCluster" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectClusterSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<()> for InspectClusterSvc<T> {
+                        type Response = super::ClusterInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_cluster(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectClusterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Api> Clone for ApiServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: Api> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Api> tonic::transport::NamedService for ApiServer<T> {
+        const NAME: &'static str = "admin.API";
+    }
+}
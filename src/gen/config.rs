@@ -0,0 +1,111 @@
+/// Config specifies the pachyderm config that is read and interpreted by the
+/// pachctl command-line tool. Right now, this is stored at
+/// $HOME/.pachyderm/config.
+///
+/// Different versions of the pachyderm config are specified as subfields of this
+/// message (this allows us to make significant changes to the config structure
+/// without breaking existing users by defining a new config version).
+///
+/// DO NOT change or remove field numbers from this proto, otherwise ALL
+/// pachyderm user configs will fail to parse.
+#[derive(Clone, PartialEq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Config {
+    #[prost(string, tag = "1")]
+    pub user_id: std::string::String,
+    /// Configuration options. Exactly one of these fields should be set
+    /// (depending on which version of the config is being used)
+    #[prost(message, optional, tag = "2")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v1: ::std::option::Option<ConfigV1>,
+    #[prost(message, optional, tag = "3")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v2: ::std::option::Option<ConfigV2>,
+}
+/// ConfigV1 specifies v1 of the pachyderm config (June 30 2017 - June 2019)
+/// DO NOT change or remove field numbers from this proto, as if you do, v1 user
+/// configs will become unparseable.
+#[derive(Clone, PartialEq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ConfigV1 {
+    /// A host:port pointing pachd at a pachyderm cluster.
+    #[prost(string, tag = "2")]
+    pub pachd_address: std::string::String,
+    /// Trusted root certificates (overrides installed certificates), formatted
+    /// as base64-encoded PEM
+    #[prost(string, tag = "3")]
+    pub server_cas: std::string::String,
+    /// A secret token identifying the current pachctl user within their
+    /// pachyderm cluster. This is included in all RPCs sent by pachctl, and used
+    /// to determine if pachctl actions are authorized.
+    #[prost(string, tag = "1")]
+    pub session_token: std::string::String,
+    /// The currently active transaction for batching together pachctl commands.
+    /// This can be set or cleared via many of the `pachctl * transaction` commands.
+    /// This is the ID of the transaction object stored in the pachyderm etcd.
+    #[prost(string, tag = "4")]
+    pub active_transaction: std::string::String,
+}
+/// ConfigV2 specifies v2 of the pachyderm config (June 2019 - present)
+#[derive(Clone, PartialEq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ConfigV2 {
+    #[prost(string, tag = "1")]
+    pub active_context: std::string::String,
+    #[prost(map = "string, message", tag = "2")]
+    pub contexts: ::std::collections::HashMap<std::string::String, Context>,
+    #[prost(bool, tag = "3")]
+    pub metrics: bool,
+    #[prost(int64, tag = "4")]
+    pub max_shell_completions: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Context {
+    /// Where this context came from
+    #[prost(enumeration = "ContextSource", tag = "1")]
+    pub source: i32,
+    /// The hostname or IP address pointing pachd at a pachyderm cluster.
+    #[prost(string, tag = "2")]
+    pub pachd_address: std::string::String,
+    /// Trusted root certificates (overrides installed certificates), formatted
+    /// as base64-encoded PEM.
+    #[prost(string, tag = "3")]
+    pub server_cas: std::string::String,
+    /// A secret token identifying the current pachctl user within their
+    /// pachyderm cluster. This is included in all RPCs sent by pachctl, and used
+    /// to determine if pachctl actions are authorized.
+    #[prost(string, tag = "4")]
+    pub session_token: std::string::String,
+    /// The currently active transaction for batching together pachctl commands.
+    /// This can be set or cleared via many of the `pachctl * transaction` commands.
+    /// This is the ID of the transaction object stored in the pachyderm etcd.
+    #[prost(string, tag = "5")]
+    pub active_transaction: std::string::String,
+    /// The k8s cluster name - used to construct a k8s context.
+    #[prost(string, tag = "6")]
+    pub cluster_name: std::string::String,
+    /// The k8s auth info - used to construct a k8s context.
+    #[prost(string, tag = "7")]
+    pub auth_info: std::string::String,
+    /// The k8s namespace - used to construct a k8s context.
+    #[prost(string, tag = "8")]
+    pub namespace: std::string::String,
+    /// A mapping of service -> port number, when port forwarding is
+    /// running for this context.
+    #[prost(map = "string, uint32", tag = "10")]
+    pub port_forwarders: ::std::collections::HashMap<std::string::String, u32>,
+    /// A unique ID for the cluster deployment. At client initialization time,
+    /// we ensure this is the same as what the cluster reports back, to prevent
+    /// us from connecting to the wrong cluster.
+    #[prost(string, tag = "11")]
+    pub cluster_deployment_id: std::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ContextSource {
+    None = 0,
+    ConfigV1 = 1,
+    Hub = 2,
+    Imported = 3,
+}
@@ -0,0 +1,273 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DumpRequest {
+    /// Recursed is true if this request is a recursive call from another request.
+    /// Callers should leave it unset, it's used to prevent infinite loops of
+    /// recursive calls.
+    #[prost(bool, tag = "1")]
+    pub recursed: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProfileRequest {
+    #[prost(string, tag = "1")]
+    pub profile: std::string::String,
+    /// only meaningful if profile == "cpu"
+    #[prost(message, optional, tag = "2")]
+    pub duration: ::std::option::Option<::prost_types::Duration>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BinaryRequest {}
+#[doc = r" Generated client implementations."]
+pub mod debug_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct DebugClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DebugClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DebugClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        pub async fn dump(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DumpRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/debug.Debug/Dump");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn profile(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProfileRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/debug.Debug/Profile");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn binary(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BinaryRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/debug.Debug/Binary");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for DebugClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for DebugClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "DebugClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod debug_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with DebugServer."]
+    #[async_trait]
+    pub trait Debug: Send + Sync + 'static {
+        #[doc = "Server streaming response type for the Dump method."]
+        type DumpStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn dump(
+            &self,
+            request: tonic::Request<super::DumpRequest>,
+        ) -> Result<tonic::Response<Self::DumpStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the Profile method."]
+        type ProfileStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn profile(
+            &self,
+            request: tonic::Request<super::ProfileRequest>,
+        ) -> Result<tonic::Response<Self::ProfileStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the Binary method."]
+        type BinaryStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn binary(
+            &self,
+            request: tonic::Request<super::BinaryRequest>,
+        ) -> Result<tonic::Response<Self::BinaryStream>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct DebugServer<T: Debug> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: Debug> DebugServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for DebugServer<T>
+    where
+        T: Debug,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/debug.Debug/Dump" => {
+                    #[allow(non_camel_case_types)]
+                    struct DumpSvc<T: Debug>(pub Arc<T>);
+                    impl<T: Debug> tonic::server::ServerStreamingService<super::DumpRequest> for DumpSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::DumpStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DumpRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).dump(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = DumpSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/debug.Debug/Profile" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProfileSvc<T: Debug>(pub Arc<T>);
+                    impl<T: Debug> tonic::server::ServerStreamingService<super::ProfileRequest> for ProfileSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::ProfileStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ProfileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).profile(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/debug.Debug/Binary" => {
+                    #[allow(non_camel_case_types)]
+                    struct BinarySvc<T: Debug>(pub Arc<T>);
+                    impl<T: Debug> tonic::server::ServerStreamingService<super::BinaryRequest> for BinarySvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::BinaryStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::BinaryRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).binary(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = BinarySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Debug> Clone for DebugServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: Debug> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Debug> tonic::transport::NamedService for DebugServer<T> {
+        const NAME: &'static str = "debug.Debug";
+    }
+}
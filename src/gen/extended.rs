@@ -0,0 +1,15 @@
+/// TraceProto contains information identifying a Jaeger trace. It's used to
+/// propagate traces that follow the lifetime of a long operation (e.g. creating
+/// a pipeline or running a job), and which live longer than any single RPC.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TraceProto {
+    /// serialized_trace contains the info identifying a trace in Jaeger (a
+    /// (trace ID, span ID, sampled) tuple, basically)
+    #[prost(map = "string, string", tag = "1")]
+    pub serialized_trace: ::std::collections::HashMap<std::string::String, std::string::String>,
+    /// pipeline specifies the target pipeline of this trace; this would be set for
+    /// a trace created by 'pachctl create-pipeline' or 'pachctl update-pipeline'
+    /// and would include the kubernetes RPCs involved in creating a pipeline
+    #[prost(string, tag = "2")]
+    pub pipeline: std::string::String,
+}
@@ -0,0 +1,65 @@
+/// FileNodeProto is a node corresponding to a file (which is also a leaf node).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileNodeProto {
+    /// Object references an object in the object store which contains the content
+    /// of the data.
+    #[prost(message, repeated, tag = "4")]
+    pub objects: ::std::vec::Vec<super::pfs_1_7::Object>,
+}
+/// DirectoryNodeProto is a node corresponding to a directory.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DirectoryNodeProto {
+    /// Children of this directory. Note that paths are relative, so if "/foo/bar"
+    /// has a child "baz", that means that there is a file at "/foo/bar/baz".
+    ///
+    /// 'Children' is ordered alphabetically, to quickly check if a new file is
+    /// overwriting an existing one.
+    #[prost(string, repeated, tag = "3")]
+    pub children: ::std::vec::Vec<std::string::String>,
+    #[prost(message, optional, tag = "4")]
+    pub header: ::std::option::Option<super::pfs_1_7::Object>,
+    #[prost(message, optional, tag = "5")]
+    pub footer: ::std::option::Option<super::pfs_1_7::Object>,
+}
+/// NodeProto is a node in the file tree (either a file or a directory)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeProto {
+    /// Name is the name (not path) of the file/directory (e.g. /lib).
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+    /// Hash is a hash of the node's name and contents (which includes the
+    /// BlockRefs of a file and the Children of a directory). This can be used to
+    /// detect if the name or contents have changed between versions.
+    #[prost(bytes, tag = "2")]
+    pub hash: std::vec::Vec<u8>,
+    /// subtree_size is the of the subtree under node; i.e. if this is a directory,
+    /// subtree_size includes all children.
+    #[prost(int64, tag = "3")]
+    pub subtree_size: i64,
+    /// Exactly one of the following fields must be set. The type of this node will
+    /// be determined by which field is set.
+    #[prost(message, optional, tag = "4")]
+    pub file_node: ::std::option::Option<FileNodeProto>,
+    #[prost(message, optional, tag = "5")]
+    pub dir_node: ::std::option::Option<DirectoryNodeProto>,
+}
+/// HashTreeProto is a tree corresponding to the complete file contents of a
+/// pachyderm repo at a given commit (based on a Merkle Tree). We store one
+/// HashTree for every PFS commit.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HashTreeProto {
+    /// Version is an arbitrary version number, set by the corresponding library
+    /// in hashtree.go.  This ensures that if the hash function used to create
+    /// these trees is changed, we won't run into errors when deserializing old
+    /// trees. The current version is 1.
+    #[prost(int32, tag = "1")]
+    pub version: i32,
+    /// Fs maps each node's path to the NodeProto with that node's details.
+    /// See "Potential Optimizations" at the end for a compression scheme that
+    /// could be useful if this map gets too large.
+    ///
+    /// Note that the key must end in "/" if an only if the value has .dir_node set
+    /// (i.e. iff the path points to a directory).
+    #[prost(map = "string, message", tag = "2")]
+    pub fs: ::std::collections::HashMap<std::string::String, NodeProto>,
+}
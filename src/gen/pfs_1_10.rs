@@ -0,0 +1,3512 @@
+////  PFS Data structures (stored in etcd)
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Repo {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Branch {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(string, tag = "2")]
+    pub name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BranchInfo {
+    #[prost(message, optional, tag = "4")]
+    pub branch: ::std::option::Option<Branch>,
+    #[prost(message, optional, tag = "2")]
+    pub head: ::std::option::Option<Commit>,
+    #[prost(message, repeated, tag = "3")]
+    pub provenance: ::std::vec::Vec<Branch>,
+    #[prost(message, repeated, tag = "5")]
+    pub subvenance: ::std::vec::Vec<Branch>,
+    #[prost(message, repeated, tag = "6")]
+    pub direct_provenance: ::std::vec::Vec<Branch>,
+    /// Deprecated field left for backward compatibility.
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BranchInfos {
+    #[prost(message, repeated, tag = "1")]
+    pub branch_info: ::std::vec::Vec<BranchInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct File {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    #[prost(string, tag = "2")]
+    pub path: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    #[prost(string, tag = "1")]
+    pub hash: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Object {
+    #[prost(string, tag = "1")]
+    pub hash: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Tag {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+}
+/// RepoInfo is the main data structure representing a Repo in etcd
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RepoInfo {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(message, optional, tag = "2")]
+    pub created: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag = "3")]
+    pub size_bytes: u64,
+    #[prost(string, tag = "5")]
+    pub description: std::string::String,
+    #[prost(message, repeated, tag = "7")]
+    pub branches: ::std::vec::Vec<Branch>,
+    /// Set by ListRepo and InspectRepo if Pachyderm's auth system is active, but
+    /// not stored in etcd. To set a user's auth scope for a repo, use the
+    /// Pachyderm Auth API (in src/client/auth/auth.proto)
+    #[prost(message, optional, tag = "6")]
+    pub auth_info: ::std::option::Option<RepoAuthInfo>,
+}
+/// RepoAuthInfo includes the caller's access scope for a repo, and is returned
+/// by ListRepo and InspectRepo but not persisted in etcd. It's used by the
+/// Pachyderm dashboard to render repo access appropriately. To set a user's auth
+/// scope for a repo, use the Pachyderm Auth API (in src/client/auth/auth.proto)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RepoAuthInfo {
+    /// The callers access level to the relevant repo (e.g. may be OWNER even if
+    /// the user isn't an OWNER of the repo, if they're an admin for the cluster)
+    #[prost(enumeration = "super::auth_1_10::Scope", tag = "1")]
+    pub access_level: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitOrigin {
+    #[prost(enumeration = "OriginKind", tag = "1")]
+    pub kind: i32,
+}
+/// Commit is a reference to a commit (e.g. the collection of branches and the
+/// collection of currently-open commits in etcd are collections of Commit
+/// protos)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Commit {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(string, tag = "2")]
+    pub id: std::string::String,
+}
+/// CommitRange represents chain of commits with Lower being an ancestor of
+/// Upper or, in the case of a range of size 1, the same commit.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitRange {
+    #[prost(message, optional, tag = "1")]
+    pub lower: ::std::option::Option<Commit>,
+    #[prost(message, optional, tag = "2")]
+    pub upper: ::std::option::Option<Commit>,
+}
+/// CommitProvenance keeps track of where (i.e. which branch) a certain commit
+/// originated from. A commit's provenance consists of the commits of
+/// the commits which are in its causal history.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitProvenance {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    #[prost(message, optional, tag = "2")]
+    pub branch: ::std::option::Option<Branch>,
+}
+/// CommitInfo is the main data structure representing a commit in etcd
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitInfo {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    #[prost(message, optional, tag = "15")]
+    pub branch: ::std::option::Option<Branch>,
+    #[prost(message, optional, tag = "17")]
+    pub origin: ::std::option::Option<CommitOrigin>,
+    /// description is a user-provided script describing this commit
+    #[prost(string, tag = "8")]
+    pub description: std::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub parent_commit: ::std::option::Option<Commit>,
+    #[prost(message, repeated, tag = "11")]
+    pub child_commits: ::std::vec::Vec<Commit>,
+    #[prost(message, optional, tag = "3")]
+    pub started: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "4")]
+    pub finished: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag = "5")]
+    pub size_bytes: u64,
+    /// the commits and their original branches on which this commit is provenant
+    #[prost(message, repeated, tag = "16")]
+    pub provenance: ::std::vec::Vec<CommitProvenance>,
+    /// ReadyProvenance is the number of provenant commits which have been
+    /// finished, if ReadyProvenance == len(Provenance) then the commit is ready
+    /// to be processed by pps.
+    #[prost(int64, tag = "12")]
+    pub ready_provenance: i64,
+    #[prost(message, repeated, tag = "9")]
+    pub subvenance: ::std::vec::Vec<CommitRange>,
+    /// this is the block that stores the serialized form of a tree that
+    /// represents the entire file system hierarchy of the repo at this commit
+    /// If this is nil, then the commit is either open (in which case 'finished'
+    /// will also be nil) or is the output commit of a failed job (in which case
+    /// 'finished' will have a value -- the end time of the job)
+    #[prost(message, optional, tag = "7")]
+    pub tree: ::std::option::Option<Object>,
+    #[prost(message, repeated, tag = "13")]
+    pub trees: ::std::vec::Vec<Object>,
+    #[prost(message, optional, tag = "14")]
+    pub datums: ::std::option::Option<Object>,
+    #[prost(int64, tag = "18")]
+    pub subvenant_commits_success: i64,
+    #[prost(int64, tag = "19")]
+    pub subvenant_commits_failure: i64,
+    #[prost(int64, tag = "20")]
+    pub subvenant_commits_total: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileInfo {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+    #[prost(enumeration = "FileType", tag = "2")]
+    pub file_type: i32,
+    #[prost(uint64, tag = "3")]
+    pub size_bytes: u64,
+    #[prost(message, optional, tag = "10")]
+    pub committed: ::std::option::Option<::prost_types::Timestamp>,
+    /// the base names (i.e. just the filenames, not the full paths) of
+    /// the children
+    #[prost(string, repeated, tag = "6")]
+    pub children: ::std::vec::Vec<std::string::String>,
+    #[prost(message, repeated, tag = "8")]
+    pub objects: ::std::vec::Vec<Object>,
+    #[prost(message, repeated, tag = "9")]
+    pub block_refs: ::std::vec::Vec<BlockRef>,
+    #[prost(bytes, tag = "7")]
+    pub hash: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ByteRange {
+    #[prost(uint64, tag = "1")]
+    pub lower: u64,
+    #[prost(uint64, tag = "2")]
+    pub upper: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockRef {
+    #[prost(message, optional, tag = "1")]
+    pub block: ::std::option::Option<Block>,
+    #[prost(message, optional, tag = "2")]
+    pub range: ::std::option::Option<ByteRange>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectInfo {
+    #[prost(message, optional, tag = "1")]
+    pub object: ::std::option::Option<Object>,
+    #[prost(message, optional, tag = "2")]
+    pub block_ref: ::std::option::Option<BlockRef>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Compaction {
+    #[prost(string, repeated, tag = "1")]
+    pub prefixes: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Shard {
+    #[prost(message, optional, tag = "1")]
+    pub range: ::std::option::Option<PathRange>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PathRange {
+    #[prost(string, tag = "1")]
+    pub lower: std::string::String,
+    #[prost(string, tag = "2")]
+    pub upper: std::string::String,
+}
+// PFS API
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateRepoRequest {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(string, tag = "3")]
+    pub description: std::string::String,
+    #[prost(bool, tag = "4")]
+    pub update: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectRepoRequest {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRepoRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRepoResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub repo_info: ::std::vec::Vec<RepoInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteRepoRequest {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(bool, tag = "2")]
+    pub force: bool,
+    #[prost(bool, tag = "3")]
+    pub all: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartCommitRequest {
+    /// Parent.ID may be empty in which case the commit that Branch points to will be used as the parent.
+    /// If branch is empty, or if branch does not exist, the commit will have no parent.
+    #[prost(message, optional, tag = "1")]
+    pub parent: ::std::option::Option<Commit>,
+    /// description is a user-provided string describing this commit
+    #[prost(string, tag = "4")]
+    pub description: std::string::String,
+    #[prost(string, tag = "3")]
+    pub branch: std::string::String,
+    #[prost(message, repeated, tag = "5")]
+    pub provenance: ::std::vec::Vec<CommitProvenance>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuildCommitRequest {
+    #[prost(message, optional, tag = "1")]
+    pub parent: ::std::option::Option<Commit>,
+    #[prost(string, tag = "4")]
+    pub branch: std::string::String,
+    #[prost(message, repeated, tag = "6")]
+    pub provenance: ::std::vec::Vec<CommitProvenance>,
+    #[prost(message, optional, tag = "3")]
+    pub tree: ::std::option::Option<Object>,
+    #[prost(message, repeated, tag = "7")]
+    pub trees: ::std::vec::Vec<Object>,
+    #[prost(message, optional, tag = "8")]
+    pub datums: ::std::option::Option<Object>,
+    /// ID sets the ID of the created commit.
+    #[prost(string, tag = "5")]
+    pub id: std::string::String,
+    #[prost(uint64, tag = "9")]
+    pub size_bytes: u64,
+    /// 'started' and 'finished' are set by Restore() when repopulating old
+    /// commits. If 'finished' is set, the commit being built is always marked
+    /// finished.
+    #[prost(message, optional, tag = "10")]
+    pub started: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "11")]
+    pub finished: ::std::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishCommitRequest {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    /// description is a user-provided string describing this commit. Setting this
+    /// will overwrite the description set in StartCommit
+    #[prost(string, tag = "2")]
+    pub description: std::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub tree: ::std::option::Option<Object>,
+    #[prost(message, repeated, tag = "5")]
+    pub trees: ::std::vec::Vec<Object>,
+    #[prost(message, optional, tag = "7")]
+    pub datums: ::std::option::Option<Object>,
+    #[prost(uint64, tag = "6")]
+    pub size_bytes: u64,
+    /// If set, 'commit' will be closed (its 'finished' field will be set to the
+    /// current time) but its 'tree' will be left nil.
+    #[prost(bool, tag = "4")]
+    pub empty: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectCommitRequest {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    /// BlockState causes inspect commit to block until the commit is in the desired state.
+    #[prost(enumeration = "CommitState", tag = "2")]
+    pub block_state: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCommitRequest {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(message, optional, tag = "2")]
+    pub from: ::std::option::Option<Commit>,
+    #[prost(message, optional, tag = "3")]
+    pub to: ::std::option::Option<Commit>,
+    #[prost(uint64, tag = "4")]
+    pub number: u64,
+    /// Return commits oldest to newest
+    #[prost(bool, tag = "5")]
+    pub reverse: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitInfos {
+    #[prost(message, repeated, tag = "1")]
+    pub commit_info: ::std::vec::Vec<CommitInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateBranchRequest {
+    #[prost(message, optional, tag = "1")]
+    pub head: ::std::option::Option<Commit>,
+    /// s_branch matches the field number and type of SetBranchRequest.Branch in
+    /// Pachyderm 1.6--so that operations (generated by pachyderm 1.6's
+    /// Admin.Export) can be deserialized by pachyderm 1.7 correctly
+    #[prost(string, tag = "2")]
+    pub s_branch: std::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub branch: ::std::option::Option<Branch>,
+    #[prost(message, repeated, tag = "4")]
+    pub provenance: ::std::vec::Vec<Branch>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectBranchRequest {
+    #[prost(message, optional, tag = "1")]
+    pub branch: ::std::option::Option<Branch>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBranchRequest {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    /// Returns branches oldest to newest
+    #[prost(bool, tag = "2")]
+    pub reverse: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteBranchRequest {
+    #[prost(message, optional, tag = "1")]
+    pub branch: ::std::option::Option<Branch>,
+    #[prost(bool, tag = "2")]
+    pub force: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCommitRequest {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlushCommitRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub commits: ::std::vec::Vec<Commit>,
+    #[prost(message, repeated, tag = "2")]
+    pub to_repos: ::std::vec::Vec<Repo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeCommitRequest {
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<Repo>,
+    #[prost(string, tag = "2")]
+    pub branch: std::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub prov: ::std::option::Option<CommitProvenance>,
+    /// only commits created since this commit are returned
+    #[prost(message, optional, tag = "3")]
+    pub from: ::std::option::Option<Commit>,
+    /// Don't return commits until they're in (at least) the desired state.
+    #[prost(enumeration = "CommitState", tag = "4")]
+    pub state: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+    #[prost(int64, tag = "2")]
+    pub offset_bytes: i64,
+    #[prost(int64, tag = "3")]
+    pub size_bytes: i64,
+}
+/// An OverwriteIndex specifies the index of objects from which new writes
+/// are applied to.  Existing objects starting from the index are deleted.
+/// We want a separate message for ObjectIndex because we want to be able to
+/// distinguish between a zero index and a non-existent index.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OverwriteIndex {
+    #[prost(int64, tag = "1")]
+    pub index: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+    #[prost(bytes, tag = "3")]
+    pub value: std::vec::Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub url: std::string::String,
+    /// applies only to URLs that can be recursively walked, for example s3:// URLs
+    #[prost(bool, tag = "6")]
+    pub recursive: bool,
+    /// Delimiter causes data to be broken up into separate files with File.Path
+    /// as a prefix.
+    #[prost(enumeration = "Delimiter", tag = "7")]
+    pub delimiter: i32,
+    /// TargetFileDatums specifies the target number of datums in each written
+    /// file it may be lower if data does not split evenly, but will never be
+    /// higher, unless the value is 0.
+    #[prost(int64, tag = "8")]
+    pub target_file_datums: i64,
+    /// TargetFileBytes specifies the target number of bytes in each written
+    /// file, files may have more or fewer bytes than the target.
+    #[prost(int64, tag = "9")]
+    pub target_file_bytes: i64,
+    /// header_records is an option for splitting data when 'delimiter' is not NONE
+    /// (or SQL). It specifies the number of records that are converted to a
+    /// header and applied to all file shards.
+    ///
+    /// This is particularly useful for CSV files, where the first row often
+    /// contains column titles; if 'header_records' is set to one in that case,
+    /// the first row will be associated with the directory that contains the rest
+    /// of the split-up csv rows as files, and if any data is retrieved from that
+    /// directory by GetFile, it will appear to begin with that first row of
+    /// column labels (including in pipeline workers).
+    ///
+    /// Note that SQL files have their own logic for determining headers (their
+    /// header is not a number of records, but a collection of SQL commands that
+    /// create the relevant tables and such). This way, SQL files retrieved by
+    /// GetFile can be passed to psql, and they will set up the appropriate tables
+    /// before inserting the records in the files that were retrieved.
+    #[prost(int64, tag = "11")]
+    pub header_records: i64,
+    /// overwrite_index is the object index where the write starts from.  All
+    /// existing objects starting from the index are deleted.
+    #[prost(message, optional, tag = "10")]
+    pub overwrite_index: ::std::option::Option<OverwriteIndex>,
+}
+/// PutFileRecord is used to record PutFile requests in etcd temporarily.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutFileRecord {
+    #[prost(int64, tag = "1")]
+    pub size_bytes: i64,
+    #[prost(string, tag = "2")]
+    pub object_hash: std::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub overwrite_index: ::std::option::Option<OverwriteIndex>,
+    #[prost(message, optional, tag = "4")]
+    pub block_ref: ::std::option::Option<BlockRef>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutFileRecords {
+    #[prost(bool, tag = "1")]
+    pub split: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub records: ::std::vec::Vec<PutFileRecord>,
+    #[prost(bool, tag = "3")]
+    pub tombstone: bool,
+    #[prost(message, optional, tag = "4")]
+    pub header: ::std::option::Option<PutFileRecord>,
+    #[prost(message, optional, tag = "5")]
+    pub footer: ::std::option::Option<PutFileRecord>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CopyFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub src: ::std::option::Option<File>,
+    #[prost(message, optional, tag = "2")]
+    pub dst: ::std::option::Option<File>,
+    #[prost(bool, tag = "3")]
+    pub overwrite: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFileRequest {
+    /// File is the parent directory of the files we want to list. This sets the
+    /// repo, the commit/branch, and path prefix of files we're interested in
+    /// If the "path" field is omitted, a list of files at the top level of the repo
+    /// is returned
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+    /// Full indicates whether the result should include file contents, which may
+    /// be large (i.e. the list of children for directories, and the list of object
+    /// references for regular files)
+    #[prost(bool, tag = "2")]
+    pub full: bool,
+    /// History indicates how many historical versions you want returned. Its
+    /// semantics are:
+    /// 0: Return the files as they are at the commit in `file`. FileInfo.File
+    ///    will equal File in this request.
+    /// 1: Return the files as they are in the last commit they were modified in.
+    ///    (This will have the same hash as if you'd passed 0, but
+    ///    FileInfo.File.Commit will be different.
+    /// 2: Return the above and the files as they are in the next-last commit they
+    ///    were modified in.
+    /// 3: etc.
+    ///-1: Return all historical versions.
+    #[prost(int64, tag = "3")]
+    pub history: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalkFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GlobFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    #[prost(string, tag = "2")]
+    pub pattern: std::string::String,
+}
+/// FileInfos is the result of both ListFile and GlobFile
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileInfos {
+    #[prost(message, repeated, tag = "1")]
+    pub file_info: ::std::vec::Vec<FileInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiffFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub new_file: ::std::option::Option<File>,
+    /// OldFile may be left nil in which case the same path in the parent of
+    /// NewFile's commit will be used.
+    #[prost(message, optional, tag = "2")]
+    pub old_file: ::std::option::Option<File>,
+    #[prost(bool, tag = "3")]
+    pub shallow: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiffFileResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub new_files: ::std::vec::Vec<FileInfo>,
+    #[prost(message, repeated, tag = "2")]
+    pub old_files: ::std::vec::Vec<FileInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteFileRequest {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FsckRequest {
+    #[prost(bool, tag = "1")]
+    pub fix: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FsckResponse {
+    #[prost(string, tag = "1")]
+    pub fix: std::string::String,
+    #[prost(string, tag = "2")]
+    pub error: std::string::String,
+}
+// Messages specific to the new storage layer.
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutTarRequest {
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<Commit>,
+    #[prost(bytes, tag = "2")]
+    pub data: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTarRequest {
+    #[prost(message, optional, tag = "1")]
+    pub file: ::std::option::Option<File>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutObjectRequest {
+    #[prost(bytes, tag = "1")]
+    pub value: std::vec::Vec<u8>,
+    #[prost(message, repeated, tag = "2")]
+    pub tags: ::std::vec::Vec<Tag>,
+    #[prost(message, optional, tag = "3")]
+    pub block: ::std::option::Option<Block>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateObjectRequest {
+    #[prost(message, optional, tag = "1")]
+    pub object: ::std::option::Option<Object>,
+    #[prost(message, optional, tag = "2")]
+    pub block_ref: ::std::option::Option<BlockRef>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetObjectsRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub objects: ::std::vec::Vec<Object>,
+    #[prost(uint64, tag = "2")]
+    pub offset_bytes: u64,
+    /// The number of bytes requested.
+    #[prost(uint64, tag = "3")]
+    pub size_bytes: u64,
+    /// The total amount of bytes in these objects.  It's OK if it's not
+    /// entirely accurate or if it's unknown (in which case it'd be set to 0).
+    /// It's used primarily as a hint for cache eviction.
+    #[prost(uint64, tag = "4")]
+    pub total_size: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutBlockRequest {
+    #[prost(message, optional, tag = "1")]
+    pub block: ::std::option::Option<Block>,
+    #[prost(bytes, tag = "2")]
+    pub value: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockRequest {
+    #[prost(message, optional, tag = "1")]
+    pub block: ::std::option::Option<Block>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub block_refs: ::std::vec::Vec<BlockRef>,
+    #[prost(uint64, tag = "2")]
+    pub offset_bytes: u64,
+    /// The number of bytes requested.
+    #[prost(uint64, tag = "3")]
+    pub size_bytes: u64,
+    /// The total amount of bytes in these blocks.  It's OK if it's not
+    /// entirely accurate or if it's unknown (in which case it'd be set to 0).
+    /// It's used primarily as a hint for cache eviction.
+    #[prost(uint64, tag = "4")]
+    pub total_size: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBlockRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TagObjectRequest {
+    #[prost(message, optional, tag = "1")]
+    pub object: ::std::option::Option<Object>,
+    #[prost(message, repeated, tag = "2")]
+    pub tags: ::std::vec::Vec<Tag>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListObjectsRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagsRequest {
+    #[prost(string, tag = "1")]
+    pub prefix: std::string::String,
+    #[prost(bool, tag = "2")]
+    pub include_object: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub tag: ::std::option::Option<Tag>,
+    #[prost(message, optional, tag = "2")]
+    pub object: ::std::option::Option<Object>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteObjectsRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub objects: ::std::vec::Vec<Object>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteObjectsResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteTagsRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub tags: ::std::vec::Vec<Tag>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteTagsResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckObjectRequest {
+    #[prost(message, optional, tag = "1")]
+    pub object: ::std::option::Option<Object>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckObjectResponse {
+    #[prost(bool, tag = "1")]
+    pub exists: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Objects {
+    #[prost(message, repeated, tag = "1")]
+    pub objects: ::std::vec::Vec<Object>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutObjDirectRequest {
+    #[prost(string, tag = "1")]
+    pub obj: std::string::String,
+    #[prost(bytes, tag = "2")]
+    pub value: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetObjDirectRequest {
+    #[prost(string, tag = "1")]
+    pub obj: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectIndex {
+    #[prost(map = "string, message", tag = "1")]
+    pub objects: ::std::collections::HashMap<std::string::String, BlockRef>,
+    #[prost(map = "string, message", tag = "2")]
+    pub tags: ::std::collections::HashMap<std::string::String, Object>,
+}
+/// These are the different places where a commit may be originated from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OriginKind {
+    User = 0,
+    Auto = 1,
+    Fsck = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FileType {
+    Reserved = 0,
+    File = 1,
+    Dir = 2,
+}
+/// CommitState describes the states a commit can be in.
+/// The states are increasingly specific, i.e. a commit that is FINISHED also counts as STARTED.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CommitState {
+    /// The commit has been started, all commits satisfy this state.
+    Started = 0,
+    /// The commit has been started, and all of its provenant commits have been finished.
+    Ready = 1,
+    /// The commit has been finished.
+    Finished = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Delimiter {
+    None = 0,
+    Json = 1,
+    Line = 2,
+    Sql = 3,
+    Csv = 4,
+}
+#[doc = r" Generated client implementations."]
+pub mod api_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct ApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ApiClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ApiClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        #[doc = " Repo rpcs"]
+        #[doc = " CreateRepo creates a new repo."]
+        #[doc = " An error is returned if the repo already exists."]
+        pub async fn create_repo(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateRepoRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/CreateRepo");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " InspectRepo returns info about a repo."]
+        pub async fn inspect_repo(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectRepoRequest>,
+        ) -> Result<tonic::Response<super::RepoInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/InspectRepo");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListRepo returns info about all repos."]
+        pub async fn list_repo(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRepoRequest>,
+        ) -> Result<tonic::Response<super::ListRepoResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/ListRepo");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " DeleteRepo deletes a repo."]
+        pub async fn delete_repo(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteRepoRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/DeleteRepo");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Commit rpcs"]
+        #[doc = " StartCommit creates a new write commit from a parent commit."]
+        pub async fn start_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartCommitRequest>,
+        ) -> Result<tonic::Response<super::Commit>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/StartCommit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " FinishCommit turns a write commit into a read commit."]
+        pub async fn finish_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FinishCommitRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/FinishCommit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " InspectCommit returns the info about a commit."]
+        pub async fn inspect_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectCommitRequest>,
+        ) -> Result<tonic::Response<super::CommitInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/InspectCommit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListCommit returns info about all commits. This is deprecated in favor of"]
+        #[doc = " ListCommitStream."]
+        pub async fn list_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCommitRequest>,
+        ) -> Result<tonic::Response<super::CommitInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/ListCommit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListCommitStream is like ListCommit, but returns its results in a GRPC stream"]
+        pub async fn list_commit_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCommitRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::CommitInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/ListCommitStream");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " DeleteCommit deletes a commit."]
+        pub async fn delete_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteCommitRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/DeleteCommit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " FlushCommit waits for downstream commits to finish"]
+        pub async fn flush_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FlushCommitRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::CommitInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/FlushCommit");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " SubscribeCommit subscribes for new commits on a given branch"]
+        pub async fn subscribe_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeCommitRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::CommitInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/SubscribeCommit");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " BuildCommit builds a commit that's backed by the given tree"]
+        pub async fn build_commit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BuildCommitRequest>,
+        ) -> Result<tonic::Response<super::Commit>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/BuildCommit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " CreateBranch creates a new branch"]
+        pub async fn create_branch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateBranchRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/CreateBranch");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " InspectBranch returns info about a branch."]
+        pub async fn inspect_branch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectBranchRequest>,
+        ) -> Result<tonic::Response<super::BranchInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/InspectBranch");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListBranch returns info about the heads of branches."]
+        pub async fn list_branch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBranchRequest>,
+        ) -> Result<tonic::Response<super::BranchInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/ListBranch");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " DeleteBranch deletes a branch; note that the commits still exist."]
+        pub async fn delete_branch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteBranchRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/DeleteBranch");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " File rpcs"]
+        #[doc = " PutFile writes the specified file to pfs."]
+        pub async fn put_file(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutFileRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/PutFile");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " CopyFile copies the contents of one file to another."]
+        pub async fn copy_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CopyFileRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/CopyFile");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " GetFile returns a byte stream of the contents of the file."]
+        pub async fn get_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetFileRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/GetFile");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " InspectFile returns info about a file."]
+        pub async fn inspect_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectFileRequest>,
+        ) -> Result<tonic::Response<super::FileInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/InspectFile");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListFile returns info about all files. This is deprecated in favor of"]
+        #[doc = " ListFileStream"]
+        pub async fn list_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFileRequest>,
+        ) -> Result<tonic::Response<super::FileInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/ListFile");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListFileStream is a streaming version of ListFile"]
+        #[doc = " TODO(msteffen): When the dash has been updated to use ListFileStream,"]
+        #[doc = " replace ListFile with this RPC (https://github.com/pachyderm/dash/issues/201)"]
+        pub async fn list_file_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFileRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::FileInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/ListFileStream");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " WalkFile walks over all the files under a directory, including children of children."]
+        pub async fn walk_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WalkFileRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::FileInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/WalkFile");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " GlobFile returns info about all files. This is deprecated in favor of"]
+        #[doc = " GlobFileStream"]
+        pub async fn glob_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GlobFileRequest>,
+        ) -> Result<tonic::Response<super::FileInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/GlobFile");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " GlobFileStream is a streaming version of GlobFile"]
+        #[doc = " TODO(msteffen): When the dash has been updated to use GlobFileStream,"]
+        #[doc = " replace GlobFile with this RPC (https://github.com/pachyderm/dash/issues/201)"]
+        pub async fn glob_file_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GlobFileRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::FileInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/GlobFileStream");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " DiffFile returns the differences between 2 paths at 2 commits."]
+        pub async fn diff_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DiffFileRequest>,
+        ) -> Result<tonic::Response<super::DiffFileResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/DiffFile");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " DeleteFile deletes a file."]
+        pub async fn delete_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteFileRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/DeleteFile");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " DeleteAll deletes everything"]
+        pub async fn delete_all(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/DeleteAll");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Fsck does a file system consistency check for pfs"]
+        pub async fn fsck(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FsckRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::FsckResponse>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/Fsck");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " RPCs specific to the new storage layer."]
+        pub async fn put_tar(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutTarRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/PutTar");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        pub async fn get_tar(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTarRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.API/GetTar");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for ApiClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ApiClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ApiClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod object_api_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct ObjectApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ObjectApiClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ObjectApiClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        pub async fn put_object(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutObjectRequest>,
+        ) -> Result<tonic::Response<super::Object>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/PutObject");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        pub async fn put_object_split(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutObjectRequest>,
+        ) -> Result<tonic::Response<super::Objects>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/PutObjectSplit");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        pub async fn put_objects(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutObjectRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/PutObjects");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        pub async fn create_object(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateObjectRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/CreateObject");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn get_object(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Object>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/GetObject");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn get_objects(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetObjectsRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/GetObjects");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn put_block(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutBlockRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/PutBlock");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        pub async fn get_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/GetBlock");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn get_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlocksRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/GetBlocks");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn list_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBlockRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::Block>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/ListBlock");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn tag_object(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TagObjectRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/TagObject");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn inspect_object(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Object>,
+        ) -> Result<tonic::Response<super::ObjectInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/InspectObject");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " CheckObject checks if an object exists in the blob store without"]
+        #[doc = " actually reading the object."]
+        pub async fn check_object(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckObjectRequest>,
+        ) -> Result<tonic::Response<super::CheckObjectResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/CheckObject");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_objects(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListObjectsRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::ObjectInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/ListObjects");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn delete_objects(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteObjectsRequest>,
+        ) -> Result<tonic::Response<super::DeleteObjectsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/DeleteObjects");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn get_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Tag>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/GetTag");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn inspect_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Tag>,
+        ) -> Result<tonic::Response<super::ObjectInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/InspectTag");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_tags(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTagsRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::ListTagsResponse>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/ListTags");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn delete_tags(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteTagsRequest>,
+        ) -> Result<tonic::Response<super::DeleteTagsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/DeleteTags");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn compact(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/Compact");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " PutObjDirect puts an obj directly into object store, bypassing the content"]
+        #[doc = " addressing layer."]
+        pub async fn put_obj_direct(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::PutObjDirectRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/PutObjDirect");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " GetObjDirect gets an obj directly out of object store, bypassing the"]
+        #[doc = " content addressing layer."]
+        pub async fn get_obj_direct(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetObjDirectRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<::std::vec::Vec<u8>>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pfs_1_10.ObjectAPI/GetObjDirect");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for ObjectApiClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ObjectApiClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ObjectApiClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod api_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ApiServer."]
+    #[async_trait]
+    pub trait Api: Send + Sync + 'static {
+        #[doc = " Repo rpcs"]
+        #[doc = " CreateRepo creates a new repo."]
+        #[doc = " An error is returned if the repo already exists."]
+        async fn create_repo(
+            &self,
+            request: tonic::Request<super::CreateRepoRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " InspectRepo returns info about a repo."]
+        async fn inspect_repo(
+            &self,
+            request: tonic::Request<super::InspectRepoRequest>,
+        ) -> Result<tonic::Response<super::RepoInfo>, tonic::Status>;
+        #[doc = " ListRepo returns info about all repos."]
+        async fn list_repo(
+            &self,
+            request: tonic::Request<super::ListRepoRequest>,
+        ) -> Result<tonic::Response<super::ListRepoResponse>, tonic::Status>;
+        #[doc = " DeleteRepo deletes a repo."]
+        async fn delete_repo(
+            &self,
+            request: tonic::Request<super::DeleteRepoRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " Commit rpcs"]
+        #[doc = " StartCommit creates a new write commit from a parent commit."]
+        async fn start_commit(
+            &self,
+            request: tonic::Request<super::StartCommitRequest>,
+        ) -> Result<tonic::Response<super::Commit>, tonic::Status>;
+        #[doc = " FinishCommit turns a write commit into a read commit."]
+        async fn finish_commit(
+            &self,
+            request: tonic::Request<super::FinishCommitRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " InspectCommit returns the info about a commit."]
+        async fn inspect_commit(
+            &self,
+            request: tonic::Request<super::InspectCommitRequest>,
+        ) -> Result<tonic::Response<super::CommitInfo>, tonic::Status>;
+        #[doc = " ListCommit returns info about all commits. This is deprecated in favor of"]
+        #[doc = " ListCommitStream."]
+        async fn list_commit(
+            &self,
+            request: tonic::Request<super::ListCommitRequest>,
+        ) -> Result<tonic::Response<super::CommitInfos>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListCommitStream method."]
+        type ListCommitStreamStream: Stream<Item = Result<super::CommitInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " ListCommitStream is like ListCommit, but returns its results in a GRPC stream"]
+        async fn list_commit_stream(
+            &self,
+            request: tonic::Request<super::ListCommitRequest>,
+        ) -> Result<tonic::Response<Self::ListCommitStreamStream>, tonic::Status>;
+        #[doc = " DeleteCommit deletes a commit."]
+        async fn delete_commit(
+            &self,
+            request: tonic::Request<super::DeleteCommitRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the FlushCommit method."]
+        type FlushCommitStream: Stream<Item = Result<super::CommitInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " FlushCommit waits for downstream commits to finish"]
+        async fn flush_commit(
+            &self,
+            request: tonic::Request<super::FlushCommitRequest>,
+        ) -> Result<tonic::Response<Self::FlushCommitStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the SubscribeCommit method."]
+        type SubscribeCommitStream: Stream<Item = Result<super::CommitInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " SubscribeCommit subscribes for new commits on a given branch"]
+        async fn subscribe_commit(
+            &self,
+            request: tonic::Request<super::SubscribeCommitRequest>,
+        ) -> Result<tonic::Response<Self::SubscribeCommitStream>, tonic::Status>;
+        #[doc = " BuildCommit builds a commit that's backed by the given tree"]
+        async fn build_commit(
+            &self,
+            request: tonic::Request<super::BuildCommitRequest>,
+        ) -> Result<tonic::Response<super::Commit>, tonic::Status>;
+        #[doc = " CreateBranch creates a new branch"]
+        async fn create_branch(
+            &self,
+            request: tonic::Request<super::CreateBranchRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " InspectBranch returns info about a branch."]
+        async fn inspect_branch(
+            &self,
+            request: tonic::Request<super::InspectBranchRequest>,
+        ) -> Result<tonic::Response<super::BranchInfo>, tonic::Status>;
+        #[doc = " ListBranch returns info about the heads of branches."]
+        async fn list_branch(
+            &self,
+            request: tonic::Request<super::ListBranchRequest>,
+        ) -> Result<tonic::Response<super::BranchInfos>, tonic::Status>;
+        #[doc = " DeleteBranch deletes a branch; note that the commits still exist."]
+        async fn delete_branch(
+            &self,
+            request: tonic::Request<super::DeleteBranchRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " File rpcs"]
+        #[doc = " PutFile writes the specified file to pfs."]
+        async fn put_file(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutFileRequest>>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " CopyFile copies the contents of one file to another."]
+        async fn copy_file(
+            &self,
+            request: tonic::Request<super::CopyFileRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetFile method."]
+        type GetFileStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " GetFile returns a byte stream of the contents of the file."]
+        async fn get_file(
+            &self,
+            request: tonic::Request<super::GetFileRequest>,
+        ) -> Result<tonic::Response<Self::GetFileStream>, tonic::Status>;
+        #[doc = " InspectFile returns info about a file."]
+        async fn inspect_file(
+            &self,
+            request: tonic::Request<super::InspectFileRequest>,
+        ) -> Result<tonic::Response<super::FileInfo>, tonic::Status>;
+        #[doc = " ListFile returns info about all files. This is deprecated in favor of"]
+        #[doc = " ListFileStream"]
+        async fn list_file(
+            &self,
+            request: tonic::Request<super::ListFileRequest>,
+        ) -> Result<tonic::Response<super::FileInfos>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListFileStream method."]
+        type ListFileStreamStream: Stream<Item = Result<super::FileInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " ListFileStream is a streaming version of ListFile"]
+        #[doc = " TODO(msteffen): When the dash has been updated to use ListFileStream,"]
+        #[doc = " replace ListFile with this RPC (https://github.com/pachyderm/dash/issues/201)"]
+        async fn list_file_stream(
+            &self,
+            request: tonic::Request<super::ListFileRequest>,
+        ) -> Result<tonic::Response<Self::ListFileStreamStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the WalkFile method."]
+        type WalkFileStream: Stream<Item = Result<super::FileInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " WalkFile walks over all the files under a directory, including children of children."]
+        async fn walk_file(
+            &self,
+            request: tonic::Request<super::WalkFileRequest>,
+        ) -> Result<tonic::Response<Self::WalkFileStream>, tonic::Status>;
+        #[doc = " GlobFile returns info about all files. This is deprecated in favor of"]
+        #[doc = " GlobFileStream"]
+        async fn glob_file(
+            &self,
+            request: tonic::Request<super::GlobFileRequest>,
+        ) -> Result<tonic::Response<super::FileInfos>, tonic::Status>;
+        #[doc = "Server streaming response type for the GlobFileStream method."]
+        type GlobFileStreamStream: Stream<Item = Result<super::FileInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " GlobFileStream is a streaming version of GlobFile"]
+        #[doc = " TODO(msteffen): When the dash has been updated to use GlobFileStream,"]
+        #[doc = " replace GlobFile with this RPC (https://github.com/pachyderm/dash/issues/201)"]
+        async fn glob_file_stream(
+            &self,
+            request: tonic::Request<super::GlobFileRequest>,
+        ) -> Result<tonic::Response<Self::GlobFileStreamStream>, tonic::Status>;
+        #[doc = " DiffFile returns the differences between 2 paths at 2 commits."]
+        async fn diff_file(
+            &self,
+            request: tonic::Request<super::DiffFileRequest>,
+        ) -> Result<tonic::Response<super::DiffFileResponse>, tonic::Status>;
+        #[doc = " DeleteFile deletes a file."]
+        async fn delete_file(
+            &self,
+            request: tonic::Request<super::DeleteFileRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " DeleteAll deletes everything"]
+        async fn delete_all(&self, request: tonic::Request<()>) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the Fsck method."]
+        type FsckStream: Stream<Item = Result<super::FsckResponse, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " Fsck does a file system consistency check for pfs"]
+        async fn fsck(
+            &self,
+            request: tonic::Request<super::FsckRequest>,
+        ) -> Result<tonic::Response<Self::FsckStream>, tonic::Status>;
+        #[doc = " RPCs specific to the new storage layer."]
+        async fn put_tar(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutTarRequest>>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetTar method."]
+        type GetTarStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn get_tar(
+            &self,
+            request: tonic::Request<super::GetTarRequest>,
+        ) -> Result<tonic::Response<Self::GetTarStream>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ApiServer<T: Api> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: Api> ApiServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ApiServer<T>
+    where
+        T: Api,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/pfs_1_10.API/CreateRepo" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateRepoSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::CreateRepoRequest> for CreateRepoSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CreateRepoRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_repo(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CreateRepoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/InspectRepo" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectRepoSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectRepoRequest> for InspectRepoSvc<T> {
+                        type Response = super::RepoInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectRepoRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_repo(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectRepoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/ListRepo" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListRepoSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListRepoRequest> for ListRepoSvc<T> {
+                        type Response = super::ListRepoResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListRepoRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_repo(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListRepoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/DeleteRepo" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteRepoSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteRepoRequest> for DeleteRepoSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteRepoRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_repo(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteRepoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/StartCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct StartCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::StartCommitRequest> for StartCommitSvc<T> {
+                        type Response = super::Commit;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::StartCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).start_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = StartCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/FinishCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct FinishCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::FinishCommitRequest> for FinishCommitSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::FinishCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).finish_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = FinishCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/InspectCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectCommitRequest> for InspectCommitSvc<T> {
+                        type Response = super::CommitInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/ListCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListCommitRequest> for ListCommitSvc<T> {
+                        type Response = super::CommitInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/ListCommitStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCommitStreamSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::ListCommitRequest> for ListCommitStreamSvc<T> {
+                        type Response = super::CommitInfo;
+                        type ResponseStream = T::ListCommitStreamStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_commit_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListCommitStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/DeleteCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteCommitRequest> for DeleteCommitSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/FlushCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct FlushCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::FlushCommitRequest> for FlushCommitSvc<T> {
+                        type Response = super::CommitInfo;
+                        type ResponseStream = T::FlushCommitStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::FlushCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).flush_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = FlushCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/SubscribeCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::SubscribeCommitRequest> for SubscribeCommitSvc<T> {
+                        type Response = super::CommitInfo;
+                        type ResponseStream = T::SubscribeCommitStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::SubscribeCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).subscribe_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = SubscribeCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/BuildCommit" => {
+                    #[allow(non_camel_case_types)]
+                    struct BuildCommitSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::BuildCommitRequest> for BuildCommitSvc<T> {
+                        type Response = super::Commit;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::BuildCommitRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).build_commit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = BuildCommitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/CreateBranch" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateBranchSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::CreateBranchRequest> for CreateBranchSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CreateBranchRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_branch(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CreateBranchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/InspectBranch" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectBranchSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectBranchRequest> for InspectBranchSvc<T> {
+                        type Response = super::BranchInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectBranchRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_branch(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectBranchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/ListBranch" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBranchSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListBranchRequest> for ListBranchSvc<T> {
+                        type Response = super::BranchInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListBranchRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_branch(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListBranchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/DeleteBranch" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteBranchSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteBranchRequest> for DeleteBranchSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteBranchRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_branch(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteBranchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/PutFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ClientStreamingService<super::PutFileRequest> for PutFileSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutFileRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/CopyFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct CopyFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::CopyFileRequest> for CopyFileSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CopyFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).copy_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CopyFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/GetFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::GetFileRequest> for GetFileSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetFileStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/InspectFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectFileRequest> for InspectFileSvc<T> {
+                        type Response = super::FileInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/ListFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListFileRequest> for ListFileSvc<T> {
+                        type Response = super::FileInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/ListFileStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFileStreamSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::ListFileRequest> for ListFileStreamSvc<T> {
+                        type Response = super::FileInfo;
+                        type ResponseStream = T::ListFileStreamStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_file_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListFileStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/WalkFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct WalkFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::WalkFileRequest> for WalkFileSvc<T> {
+                        type Response = super::FileInfo;
+                        type ResponseStream = T::WalkFileStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::WalkFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).walk_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = WalkFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/GlobFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct GlobFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::GlobFileRequest> for GlobFileSvc<T> {
+                        type Response = super::FileInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GlobFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).glob_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GlobFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/GlobFileStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct GlobFileStreamSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::GlobFileRequest> for GlobFileStreamSvc<T> {
+                        type Response = super::FileInfo;
+                        type ResponseStream = T::GlobFileStreamStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GlobFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).glob_file_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GlobFileStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/DiffFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct DiffFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DiffFileRequest> for DiffFileSvc<T> {
+                        type Response = super::DiffFileResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DiffFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).diff_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DiffFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/DeleteFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteFileSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteFileRequest> for DeleteFileSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteFileRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_file(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/DeleteAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteAllSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<()> for DeleteAllSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_all(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/Fsck" => {
+                    #[allow(non_camel_case_types)]
+                    struct FsckSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::FsckRequest> for FsckSvc<T> {
+                        type Response = super::FsckResponse;
+                        type ResponseStream = T::FsckStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::FsckRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).fsck(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = FsckSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/PutTar" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutTarSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ClientStreamingService<super::PutTarRequest> for PutTarSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutTarRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_tar(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutTarSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.API/GetTar" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTarSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::GetTarRequest> for GetTarSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetTarStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetTarRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_tar(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetTarSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Api> Clone for ApiServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: Api> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Api> tonic::transport::NamedService for ApiServer<T> {
+        const NAME: &'static str = "pfs_1_10.API";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod object_api_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ObjectApiServer."]
+    #[async_trait]
+    pub trait ObjectApi: Send + Sync + 'static {
+        async fn put_object(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutObjectRequest>>,
+        ) -> Result<tonic::Response<super::Object>, tonic::Status>;
+        async fn put_object_split(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutObjectRequest>>,
+        ) -> Result<tonic::Response<super::Objects>, tonic::Status>;
+        async fn put_objects(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutObjectRequest>>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn create_object(
+            &self,
+            request: tonic::Request<super::CreateObjectRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetObject method."]
+        type GetObjectStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn get_object(
+            &self,
+            request: tonic::Request<super::Object>,
+        ) -> Result<tonic::Response<Self::GetObjectStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetObjects method."]
+        type GetObjectsStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn get_objects(
+            &self,
+            request: tonic::Request<super::GetObjectsRequest>,
+        ) -> Result<tonic::Response<Self::GetObjectsStream>, tonic::Status>;
+        async fn put_block(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutBlockRequest>>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetBlock method."]
+        type GetBlockStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn get_block(
+            &self,
+            request: tonic::Request<super::GetBlockRequest>,
+        ) -> Result<tonic::Response<Self::GetBlockStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetBlocks method."]
+        type GetBlocksStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn get_blocks(
+            &self,
+            request: tonic::Request<super::GetBlocksRequest>,
+        ) -> Result<tonic::Response<Self::GetBlocksStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListBlock method."]
+        type ListBlockStream: Stream<Item = Result<super::Block, tonic::Status>> + Send + Sync + 'static;
+        async fn list_block(
+            &self,
+            request: tonic::Request<super::ListBlockRequest>,
+        ) -> Result<tonic::Response<Self::ListBlockStream>, tonic::Status>;
+        async fn tag_object(
+            &self,
+            request: tonic::Request<super::TagObjectRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn inspect_object(
+            &self,
+            request: tonic::Request<super::Object>,
+        ) -> Result<tonic::Response<super::ObjectInfo>, tonic::Status>;
+        #[doc = " CheckObject checks if an object exists in the blob store without"]
+        #[doc = " actually reading the object."]
+        async fn check_object(
+            &self,
+            request: tonic::Request<super::CheckObjectRequest>,
+        ) -> Result<tonic::Response<super::CheckObjectResponse>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListObjects method."]
+        type ListObjectsStream: Stream<Item = Result<super::ObjectInfo, tonic::Status>> + Send + Sync + 'static;
+        async fn list_objects(
+            &self,
+            request: tonic::Request<super::ListObjectsRequest>,
+        ) -> Result<tonic::Response<Self::ListObjectsStream>, tonic::Status>;
+        async fn delete_objects(
+            &self,
+            request: tonic::Request<super::DeleteObjectsRequest>,
+        ) -> Result<tonic::Response<super::DeleteObjectsResponse>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetTag method."]
+        type GetTagStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        async fn get_tag(
+            &self,
+            request: tonic::Request<super::Tag>,
+        ) -> Result<tonic::Response<Self::GetTagStream>, tonic::Status>;
+        async fn inspect_tag(
+            &self,
+            request: tonic::Request<super::Tag>,
+        ) -> Result<tonic::Response<super::ObjectInfo>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListTags method."]
+        type ListTagsStream: Stream<Item = Result<super::ListTagsResponse, tonic::Status>> + Send + Sync + 'static;
+        async fn list_tags(
+            &self,
+            request: tonic::Request<super::ListTagsRequest>,
+        ) -> Result<tonic::Response<Self::ListTagsStream>, tonic::Status>;
+        async fn delete_tags(
+            &self,
+            request: tonic::Request<super::DeleteTagsRequest>,
+        ) -> Result<tonic::Response<super::DeleteTagsResponse>, tonic::Status>;
+        async fn compact(&self, request: tonic::Request<()>) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " PutObjDirect puts an obj directly into object store, bypassing the content"]
+        #[doc = " addressing layer."]
+        async fn put_obj_direct(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::PutObjDirectRequest>>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetObjDirect method."]
+        type GetObjDirectStream: Stream<Item = Result<::std::vec::Vec<u8>, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " GetObjDirect gets an obj directly out of object store, bypassing the"]
+        #[doc = " content addressing layer."]
+        async fn get_obj_direct(
+            &self,
+            request: tonic::Request<super::GetObjDirectRequest>,
+        ) -> Result<tonic::Response<Self::GetObjDirectStream>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ObjectApiServer<T: ObjectApi> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: ObjectApi> ObjectApiServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ObjectApiServer<T>
+    where
+        T: ObjectApi,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/pfs_1_10.ObjectAPI/PutObject" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutObjectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ClientStreamingService<super::PutObjectRequest> for PutObjectSvc<T> {
+                        type Response = super::Object;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutObjectRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_object(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutObjectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/PutObjectSplit" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutObjectSplitSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ClientStreamingService<super::PutObjectRequest> for PutObjectSplitSvc<T> {
+                        type Response = super::Objects;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutObjectRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_object_split(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutObjectSplitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/PutObjects" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutObjectsSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ClientStreamingService<super::PutObjectRequest> for PutObjectsSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutObjectRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_objects(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutObjectsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/CreateObject" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateObjectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::CreateObjectRequest> for CreateObjectSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CreateObjectRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_object(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CreateObjectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/GetObject" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetObjectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::Object> for GetObjectSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetObjectStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Object>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_object(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetObjectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/GetObjects" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetObjectsSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::GetObjectsRequest> for GetObjectsSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetObjectsStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetObjectsRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_objects(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetObjectsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/PutBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutBlockSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ClientStreamingService<super::PutBlockRequest> for PutBlockSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutBlockRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_block(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/GetBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::GetBlockRequest> for GetBlockSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetBlockStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetBlockRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_block(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/GetBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::GetBlocksRequest> for GetBlocksSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetBlocksStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetBlocksRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_blocks(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/ListBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBlockSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::ListBlockRequest> for ListBlockSvc<T> {
+                        type Response = super::Block;
+                        type ResponseStream = T::ListBlockStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListBlockRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_block(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/TagObject" => {
+                    #[allow(non_camel_case_types)]
+                    struct TagObjectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::TagObjectRequest> for TagObjectSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::TagObjectRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).tag_object(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = TagObjectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/InspectObject" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectObjectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::Object> for InspectObjectSvc<T> {
+                        type Response = super::ObjectInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Object>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_object(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectObjectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/CheckObject" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckObjectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::CheckObjectRequest> for CheckObjectSvc<T> {
+                        type Response = super::CheckObjectResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CheckObjectRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).check_object(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CheckObjectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/ListObjects" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListObjectsSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::ListObjectsRequest> for ListObjectsSvc<T> {
+                        type Response = super::ObjectInfo;
+                        type ResponseStream = T::ListObjectsStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListObjectsRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_objects(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListObjectsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/DeleteObjects" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteObjectsSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::DeleteObjectsRequest> for DeleteObjectsSvc<T> {
+                        type Response = super::DeleteObjectsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteObjectsRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_objects(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteObjectsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/GetTag" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTagSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::Tag> for GetTagSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetTagStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Tag>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_tag(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetTagSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/InspectTag" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectTagSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::Tag> for InspectTagSvc<T> {
+                        type Response = super::ObjectInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Tag>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_tag(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectTagSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/ListTags" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTagsSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::ListTagsRequest> for ListTagsSvc<T> {
+                        type Response = super::ListTagsResponse;
+                        type ResponseStream = T::ListTagsStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListTagsRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_tags(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListTagsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/DeleteTags" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteTagsSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<super::DeleteTagsRequest> for DeleteTagsSvc<T> {
+                        type Response = super::DeleteTagsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteTagsRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_tags(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteTagsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/Compact" => {
+                    #[allow(non_camel_case_types)]
+                    struct CompactSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::UnaryService<()> for CompactSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).compact(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CompactSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/PutObjDirect" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutObjDirectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ClientStreamingService<super::PutObjDirectRequest> for PutObjDirectSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::PutObjDirectRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_obj_direct(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = PutObjDirectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pfs_1_10.ObjectAPI/GetObjDirect" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetObjDirectSvc<T: ObjectApi>(pub Arc<T>);
+                    impl<T: ObjectApi> tonic::server::ServerStreamingService<super::GetObjDirectRequest> for GetObjDirectSvc<T> {
+                        type Response = ::std::vec::Vec<u8>;
+                        type ResponseStream = T::GetObjDirectStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetObjDirectRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_obj_direct(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetObjDirectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: ObjectApi> Clone for ObjectApiServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: ObjectApi> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ObjectApi> tonic::transport::NamedService for ObjectApiServer<T> {
+        const NAME: &'static str = "pfs_1_10.ObjectAPI";
+    }
+}
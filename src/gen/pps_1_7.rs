@@ -0,0 +1,1922 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Secret {
+    /// Name must be the name of the secret in kubernetes.
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+    /// Key of the secret to load into env_var, this field only has meaning if EnvVar != "".
+    #[prost(string, tag = "4")]
+    pub key: std::string::String,
+    #[prost(string, tag = "2")]
+    pub mount_path: std::string::String,
+    #[prost(string, tag = "3")]
+    pub env_var: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transform {
+    #[prost(string, tag = "1")]
+    pub image: std::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub cmd: ::std::vec::Vec<std::string::String>,
+    #[prost(map = "string, string", tag = "3")]
+    pub env: ::std::collections::HashMap<std::string::String, std::string::String>,
+    #[prost(message, repeated, tag = "4")]
+    pub secrets: ::std::vec::Vec<Secret>,
+    #[prost(string, repeated, tag = "9")]
+    pub image_pull_secrets: ::std::vec::Vec<std::string::String>,
+    #[prost(string, repeated, tag = "5")]
+    pub stdin: ::std::vec::Vec<std::string::String>,
+    #[prost(int64, repeated, tag = "6")]
+    pub accept_return_code: ::std::vec::Vec<i64>,
+    #[prost(bool, tag = "7")]
+    pub debug: bool,
+    #[prost(string, tag = "10")]
+    pub user: std::string::String,
+    #[prost(string, tag = "11")]
+    pub working_dir: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Egress {
+    #[prost(string, tag = "1")]
+    pub url: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Job {
+    #[prost(string, tag = "1")]
+    pub id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Service {
+    #[prost(int32, tag = "1")]
+    pub internal_port: i32,
+    #[prost(int32, tag = "2")]
+    pub external_port: i32,
+    #[prost(string, tag = "3")]
+    pub ip: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AtomInput {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+    #[prost(string, tag = "2")]
+    pub repo: std::string::String,
+    #[prost(string, tag = "3")]
+    pub branch: std::string::String,
+    #[prost(string, tag = "4")]
+    pub commit: std::string::String,
+    #[prost(string, tag = "5")]
+    pub glob: std::string::String,
+    #[prost(bool, tag = "6")]
+    pub lazy: bool,
+    /// EmptyFiles, if true, will cause files from this atom to be presented as
+    /// empty files. This is useful in shuffle pipelines where you want to read
+    /// the names of files and reorganize them using symlinks.
+    #[prost(bool, tag = "8")]
+    pub empty_files: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CronInput {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+    #[prost(string, tag = "2")]
+    pub repo: std::string::String,
+    #[prost(string, tag = "3")]
+    pub commit: std::string::String,
+    #[prost(string, tag = "4")]
+    pub spec: std::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub start: ::std::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GitInput {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+    #[prost(string, tag = "2")]
+    pub url: std::string::String,
+    #[prost(string, tag = "3")]
+    pub branch: std::string::String,
+    #[prost(string, tag = "4")]
+    pub commit: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Input {
+    #[prost(message, optional, tag = "1")]
+    pub atom: ::std::option::Option<AtomInput>,
+    #[prost(message, repeated, tag = "2")]
+    pub cross: ::std::vec::Vec<Input>,
+    #[prost(message, repeated, tag = "3")]
+    pub union: ::std::vec::Vec<Input>,
+    #[prost(message, optional, tag = "4")]
+    pub cron: ::std::option::Option<CronInput>,
+    #[prost(message, optional, tag = "5")]
+    pub git: ::std::option::Option<GitInput>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobInput {
+    #[prost(string, tag = "4")]
+    pub name: std::string::String,
+    #[prost(message, optional, tag = "1")]
+    pub commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    #[prost(string, tag = "2")]
+    pub glob: std::string::String,
+    #[prost(bool, tag = "3")]
+    pub lazy: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParallelismSpec {
+    /// Starts the pipeline/job with a 'constant' workers, unless 'constant' is
+    /// zero. If 'constant' is zero (which is the zero value of ParallelismSpec),
+    /// then Pachyderm will choose the number of workers that is started,
+    /// (currently it chooses the number of workers in the cluster)
+    #[prost(uint64, tag = "2")]
+    pub constant: u64,
+    /// Starts the pipeline/job with number of workers equal to 'coefficient' * N,
+    /// where N is the number of nodes in the kubernetes cluster.
+    ///
+    /// For example, if each Kubernetes node has four CPUs, you might set
+    /// 'coefficient' to four, so that there are four Pachyderm workers per
+    /// Kubernetes node, and each Pachyderm worker gets one CPU. If you want to
+    /// reserve half the nodes in your cluster for other tasks, you might set
+    /// 'coefficient' to 0.5.
+    #[prost(double, tag = "3")]
+    pub coefficient: f64,
+}
+/// HashTreeSpec sets the number of shards into which pps splits a pipeline's
+/// output commits (sharded commits are implemented in Pachyderm 1.8+ only)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HashtreeSpec {
+    #[prost(uint64, tag = "1")]
+    pub constant: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InputFile {
+    /// This file's absolute path within its pfs repo.
+    #[prost(string, tag = "4")]
+    pub path: std::string::String,
+    /// This file's hash
+    #[prost(bytes, tag = "5")]
+    pub hash: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Datum {
+    /// ID is the hash computed from all the files
+    #[prost(string, tag = "1")]
+    pub id: std::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub job: ::std::option::Option<Job>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DatumInfo {
+    #[prost(message, optional, tag = "1")]
+    pub datum: ::std::option::Option<Datum>,
+    #[prost(enumeration = "DatumState", tag = "2")]
+    pub state: i32,
+    #[prost(message, optional, tag = "3")]
+    pub stats: ::std::option::Option<ProcessStats>,
+    #[prost(message, optional, tag = "4")]
+    pub pfs_state: ::std::option::Option<super::pfs_1_7::File>,
+    #[prost(message, repeated, tag = "5")]
+    pub data: ::std::vec::Vec<super::pfs_1_7::FileInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Aggregate {
+    #[prost(int64, tag = "1")]
+    pub count: i64,
+    #[prost(double, tag = "2")]
+    pub mean: f64,
+    #[prost(double, tag = "3")]
+    pub stddev: f64,
+    #[prost(double, tag = "4")]
+    pub fifth_percentile: f64,
+    #[prost(double, tag = "5")]
+    pub ninety_fifth_percentile: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProcessStats {
+    #[prost(message, optional, tag = "1")]
+    pub download_time: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "2")]
+    pub process_time: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "3")]
+    pub upload_time: ::std::option::Option<::prost_types::Duration>,
+    #[prost(uint64, tag = "4")]
+    pub download_bytes: u64,
+    #[prost(uint64, tag = "5")]
+    pub upload_bytes: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateProcessStats {
+    #[prost(message, optional, tag = "1")]
+    pub download_time: ::std::option::Option<Aggregate>,
+    #[prost(message, optional, tag = "2")]
+    pub process_time: ::std::option::Option<Aggregate>,
+    #[prost(message, optional, tag = "3")]
+    pub upload_time: ::std::option::Option<Aggregate>,
+    #[prost(message, optional, tag = "4")]
+    pub download_bytes: ::std::option::Option<Aggregate>,
+    #[prost(message, optional, tag = "5")]
+    pub upload_bytes: ::std::option::Option<Aggregate>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WorkerStatus {
+    #[prost(string, tag = "1")]
+    pub worker_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub job_id: std::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub data: ::std::vec::Vec<InputFile>,
+    /// Started is the time processing on the current datum began.
+    #[prost(message, optional, tag = "4")]
+    pub started: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "5")]
+    pub stats: ::std::option::Option<ProcessStats>,
+    #[prost(int64, tag = "6")]
+    pub queue_size: i64,
+}
+/// ResourceSpec describes the amount of resources that pipeline pods should
+/// request from kubernetes, for scheduling.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResourceSpec {
+    /// The number of CPUs each worker needs (partial values are allowed, and
+    /// encouraged)
+    #[prost(float, tag = "1")]
+    pub cpu: f32,
+    /// The amount of memory each worker needs (in bytes, with allowed
+    /// SI suffixes (M, K, G, Mi, Ki, Gi, etc).
+    #[prost(string, tag = "2")]
+    pub memory: std::string::String,
+    /// The number of GPUs each worker needs.
+    #[prost(int64, tag = "3")]
+    pub gpu: i64,
+    /// The amount of ephemeral storage each worker needs (in bytes, with allowed
+    /// SI suffixes (M, K, G, Mi, Ki, Gi, etc).
+    #[prost(string, tag = "4")]
+    pub disk: std::string::String,
+}
+/// EtcdJobInfo is the portion of the JobInfo that gets stored in etcd during
+/// job execution. It contains fields which change over the lifetime of the job
+/// but aren't used in the execution of the job.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EtcdJobInfo {
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+    #[prost(message, optional, tag = "2")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(message, optional, tag = "3")]
+    pub output_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    /// Job restart count (e.g. due to datum failure)
+    #[prost(uint64, tag = "4")]
+    pub restart: u64,
+    /// Counts of how many times we processed or skipped a datum
+    #[prost(int64, tag = "5")]
+    pub data_processed: i64,
+    #[prost(int64, tag = "6")]
+    pub data_skipped: i64,
+    #[prost(int64, tag = "7")]
+    pub data_total: i64,
+    #[prost(int64, tag = "8")]
+    pub data_failed: i64,
+    /// Download/process/upload time and download/upload bytes
+    #[prost(message, optional, tag = "9")]
+    pub stats: ::std::option::Option<ProcessStats>,
+    #[prost(message, optional, tag = "10")]
+    pub stats_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    #[prost(enumeration = "JobState", tag = "11")]
+    pub state: i32,
+    #[prost(string, tag = "12")]
+    pub reason: std::string::String,
+    #[prost(message, optional, tag = "13")]
+    pub started: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "14")]
+    pub finished: ::std::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobInfo {
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+    #[prost(message, optional, tag = "2")]
+    pub transform: ::std::option::Option<Transform>,
+    #[prost(message, optional, tag = "3")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(uint64, tag = "13")]
+    pub pipeline_version: u64,
+    #[prost(message, optional, tag = "12")]
+    pub parallelism_spec: ::std::option::Option<ParallelismSpec>,
+    #[prost(message, optional, tag = "15")]
+    pub egress: ::std::option::Option<Egress>,
+    #[prost(message, optional, tag = "6")]
+    pub parent_job: ::std::option::Option<Job>,
+    #[prost(message, optional, tag = "7")]
+    pub started: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "8")]
+    pub finished: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "9")]
+    pub output_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    #[prost(enumeration = "JobState", tag = "10")]
+    pub state: i32,
+    /// reason explains why the job is in the current state
+    #[prost(string, tag = "35")]
+    pub reason: std::string::String,
+    #[prost(message, optional, tag = "14")]
+    pub service: ::std::option::Option<Service>,
+    #[prost(message, optional, tag = "18")]
+    pub output_repo: ::std::option::Option<super::pfs_1_7::Repo>,
+    #[prost(string, tag = "17")]
+    pub output_branch: std::string::String,
+    #[prost(uint64, tag = "20")]
+    pub restart: u64,
+    #[prost(int64, tag = "22")]
+    pub data_processed: i64,
+    #[prost(int64, tag = "30")]
+    pub data_skipped: i64,
+    #[prost(int64, tag = "40")]
+    pub data_failed: i64,
+    #[prost(int64, tag = "23")]
+    pub data_total: i64,
+    #[prost(message, optional, tag = "31")]
+    pub stats: ::std::option::Option<ProcessStats>,
+    #[prost(message, repeated, tag = "24")]
+    pub worker_status: ::std::vec::Vec<WorkerStatus>,
+    #[prost(message, optional, tag = "25")]
+    pub resource_requests: ::std::option::Option<ResourceSpec>,
+    #[prost(message, optional, tag = "36")]
+    pub resource_limits: ::std::option::Option<ResourceSpec>,
+    #[prost(message, optional, tag = "26")]
+    pub input: ::std::option::Option<Input>,
+    #[prost(message, optional, tag = "27")]
+    pub new_branch: ::std::option::Option<super::pfs_1_7::BranchInfo>,
+    #[prost(bool, tag = "28")]
+    pub incremental: bool,
+    #[prost(message, optional, tag = "29")]
+    pub stats_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    #[prost(bool, tag = "32")]
+    pub enable_stats: bool,
+    #[prost(string, tag = "33")]
+    pub salt: std::string::String,
+    #[prost(bool, tag = "34")]
+    pub batch: bool,
+    #[prost(message, optional, tag = "37")]
+    pub chunk_spec: ::std::option::Option<ChunkSpec>,
+    #[prost(message, optional, tag = "38")]
+    pub datum_timeout: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "39")]
+    pub job_timeout: ::std::option::Option<::prost_types::Duration>,
+    #[prost(int64, tag = "41")]
+    pub datum_tries: i64,
+    #[prost(message, optional, tag = "42")]
+    pub scheduling_spec: ::std::option::Option<SchedulingSpec>,
+    #[prost(string, tag = "43")]
+    pub pod_spec: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Worker {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+    #[prost(enumeration = "WorkerState", tag = "2")]
+    pub state: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobInfos {
+    #[prost(message, repeated, tag = "1")]
+    pub job_info: ::std::vec::Vec<JobInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pipeline {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PipelineInput {
+    #[prost(string, tag = "5")]
+    pub name: std::string::String,
+    #[prost(message, optional, tag = "1")]
+    pub repo: ::std::option::Option<super::pfs_1_7::Repo>,
+    #[prost(string, tag = "2")]
+    pub branch: std::string::String,
+    #[prost(string, tag = "3")]
+    pub glob: std::string::String,
+    #[prost(bool, tag = "4")]
+    pub lazy: bool,
+    #[prost(message, optional, tag = "6")]
+    pub from: ::std::option::Option<super::pfs_1_7::Commit>,
+}
+/// EtcdPipelineInfo is proto that Pachd stores in etcd for each pipeline. It
+/// tracks the state of the pipeline, and points to its metadata in PFS (and,
+/// by pointing to a PFS commit, de facto tracks the pipeline's version)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EtcdPipelineInfo {
+    #[prost(enumeration = "PipelineState", tag = "1")]
+    pub state: i32,
+    #[prost(string, tag = "4")]
+    pub reason: std::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub spec_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    #[prost(map = "int32, int32", tag = "3")]
+    pub job_counts: ::std::collections::HashMap<i32, i32>,
+    #[prost(string, tag = "5")]
+    pub auth_token: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PipelineInfo {
+    #[prost(string, tag = "17")]
+    pub id: std::string::String,
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(uint64, tag = "11")]
+    pub version: u64,
+    #[prost(message, optional, tag = "2")]
+    pub transform: ::std::option::Option<Transform>,
+    #[prost(message, optional, tag = "10")]
+    pub parallelism_spec: ::std::option::Option<ParallelismSpec>,
+    #[prost(message, optional, tag = "42")]
+    pub hashtree_spec: ::std::option::Option<HashtreeSpec>,
+    #[prost(message, optional, tag = "15")]
+    pub egress: ::std::option::Option<Egress>,
+    #[prost(message, optional, tag = "6")]
+    pub created_at: ::std::option::Option<::prost_types::Timestamp>,
+    /// state indicates the current state of the pipeline. This is not stored in
+    /// PFS along with the rest of this data structure--PPS.InspectPipeline fills
+    /// it in
+    #[prost(enumeration = "PipelineState", tag = "7")]
+    pub state: i32,
+    /// same for stopped field
+    #[prost(bool, tag = "38")]
+    pub stopped: bool,
+    #[prost(string, tag = "8")]
+    pub recent_error: std::string::String,
+    /// job_counts indicates the number of jobs within this pipeline in a given
+    /// state. This is not stored in PFS along with the rest of this data
+    /// structure--PPS.InspectPipeline fills it in
+    #[prost(map = "int32, int32", tag = "9")]
+    pub job_counts: ::std::collections::HashMap<i32, i32>,
+    #[prost(string, tag = "16")]
+    pub output_branch: std::string::String,
+    #[prost(message, optional, tag = "18")]
+    pub scale_down_threshold: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "19")]
+    pub resource_requests: ::std::option::Option<ResourceSpec>,
+    #[prost(message, optional, tag = "31")]
+    pub resource_limits: ::std::option::Option<ResourceSpec>,
+    #[prost(message, optional, tag = "20")]
+    pub input: ::std::option::Option<Input>,
+    #[prost(string, tag = "21")]
+    pub description: std::string::String,
+    #[prost(bool, tag = "22")]
+    pub incremental: bool,
+    #[prost(string, tag = "23")]
+    pub cache_size: std::string::String,
+    #[prost(bool, tag = "24")]
+    pub enable_stats: bool,
+    #[prost(string, tag = "25")]
+    pub salt: std::string::String,
+    #[prost(bool, tag = "27")]
+    pub batch: bool,
+    /// reason includes any error messages associated with a failed pipeline
+    #[prost(string, tag = "28")]
+    pub reason: std::string::String,
+    #[prost(int64, tag = "29")]
+    pub max_queue_size: i64,
+    #[prost(message, optional, tag = "30")]
+    pub service: ::std::option::Option<Service>,
+    #[prost(message, optional, tag = "32")]
+    pub chunk_spec: ::std::option::Option<ChunkSpec>,
+    #[prost(message, optional, tag = "33")]
+    pub datum_timeout: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "34")]
+    pub job_timeout: ::std::option::Option<::prost_types::Duration>,
+    #[prost(string, tag = "35")]
+    pub githook_url: std::string::String,
+    #[prost(message, optional, tag = "36")]
+    pub spec_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    #[prost(bool, tag = "37")]
+    pub standby: bool,
+    #[prost(int64, tag = "39")]
+    pub datum_tries: i64,
+    #[prost(message, optional, tag = "40")]
+    pub scheduling_spec: ::std::option::Option<SchedulingSpec>,
+    #[prost(string, tag = "41")]
+    pub pod_spec: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PipelineInfos {
+    #[prost(message, repeated, tag = "1")]
+    pub pipeline_info: ::std::vec::Vec<PipelineInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateJobRequest {
+    #[prost(message, optional, tag = "2")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(message, optional, tag = "25")]
+    pub output_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectJobRequest {
+    /// Callers should set either Job or OutputCommit, not both.
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+    #[prost(message, optional, tag = "3")]
+    pub output_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+    /// block until state is either JOB_STATE_FAILURE or JOB_STATE_SUCCESS
+    #[prost(bool, tag = "2")]
+    pub block_state: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListJobRequest {
+    /// nil means all pipelines
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    /// nil means all inputs
+    #[prost(message, repeated, tag = "2")]
+    pub input_commit: ::std::vec::Vec<super::pfs_1_7::Commit>,
+    #[prost(message, optional, tag = "3")]
+    pub output_commit: ::std::option::Option<super::pfs_1_7::Commit>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlushJobRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub commits: ::std::vec::Vec<super::pfs_1_7::Commit>,
+    #[prost(message, repeated, tag = "2")]
+    pub to_pipelines: ::std::vec::Vec<Pipeline>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteJobRequest {
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopJobRequest {
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLogsRequest {
+    /// The pipeline from which we want to get logs (required if the job in 'job'
+    /// was created as part of a pipeline. To get logs from a non-orphan job
+    /// without the pipeline that created it, you need to use ElasticSearch).
+    #[prost(message, optional, tag = "2")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    /// The job from which we want to get logs.
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+    /// Names of input files from which we want processing logs. This may contain
+    /// multiple files, to query pipelines that contain multiple inputs. Each
+    /// filter may be an absolute path of a file within a pps repo, or it may be
+    /// a hash for that file (to search for files at specific versions)
+    #[prost(string, repeated, tag = "3")]
+    pub data_filters: ::std::vec::Vec<std::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub datum: ::std::option::Option<Datum>,
+    /// If true get logs from the master process
+    #[prost(bool, tag = "5")]
+    pub master: bool,
+    /// Continue to follow new logs as they become available.
+    #[prost(bool, tag = "7")]
+    pub follow: bool,
+    /// If nonzero, the number of lines from the end of the logs to return.  Note:
+    /// tail applies per container, so you will get tail * <number of pods> total
+    /// lines back.
+    #[prost(int64, tag = "8")]
+    pub tail: i64,
+}
+/// LogMessage is a log line from a PPS worker, annotated with metadata
+/// indicating when and why the line was logged.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogMessage {
+    /// The job and pipeline for which a PFS file is being processed (if the job
+    /// is an orphan job, pipeline name and ID will be unset)
+    #[prost(string, tag = "1")]
+    pub pipeline_name: std::string::String,
+    #[prost(string, tag = "3")]
+    pub job_id: std::string::String,
+    #[prost(string, tag = "7")]
+    pub worker_id: std::string::String,
+    #[prost(string, tag = "9")]
+    pub datum_id: std::string::String,
+    #[prost(bool, tag = "10")]
+    pub master: bool,
+    /// The PFS files being processed (one per pipeline/job input)
+    #[prost(message, repeated, tag = "4")]
+    pub data: ::std::vec::Vec<InputFile>,
+    /// User is true if log message comes from the users code.
+    #[prost(bool, tag = "8")]
+    pub user: bool,
+    /// The message logged, and the time at which it was logged
+    #[prost(message, optional, tag = "5")]
+    pub ts: ::std::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "6")]
+    pub message: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestartDatumRequest {
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+    #[prost(string, repeated, tag = "2")]
+    pub data_filters: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectDatumRequest {
+    #[prost(message, optional, tag = "1")]
+    pub datum: ::std::option::Option<Datum>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDatumRequest {
+    #[prost(message, optional, tag = "1")]
+    pub job: ::std::option::Option<Job>,
+    #[prost(int64, tag = "2")]
+    pub page_size: i64,
+    #[prost(int64, tag = "3")]
+    pub page: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDatumResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub datum_infos: ::std::vec::Vec<DatumInfo>,
+    #[prost(int64, tag = "2")]
+    pub total_pages: i64,
+    #[prost(int64, tag = "3")]
+    pub page: i64,
+}
+/// ListDatumStreamResponse is identical to ListDatumResponse, except that only
+/// one DatumInfo is present (as these responses are streamed)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDatumStreamResponse {
+    #[prost(message, optional, tag = "1")]
+    pub datum_info: ::std::option::Option<DatumInfo>,
+    /// total_pages is only set in the first response (and set to 0 in all other
+    /// responses)
+    #[prost(int64, tag = "2")]
+    pub total_pages: i64,
+    /// page is only set in the first response (and set to 0 in all other
+    /// responses)
+    #[prost(int64, tag = "3")]
+    pub page: i64,
+}
+/// ChunkSpec specifies how a pipeline should chunk its datums.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChunkSpec {
+    /// number, if nonzero, specifies that each chunk should contain `number`
+    /// datums. Chunks may contain fewer if the total number of datums don't
+    /// divide evenly.
+    #[prost(int64, tag = "1")]
+    pub number: i64,
+    /// size_bytes, if nonzero, specifies a target size for each chunk of datums.
+    /// Chunks may be larger or smaller than size_bytes, but will usually be
+    /// pretty close to size_bytes in size.
+    #[prost(int64, tag = "2")]
+    pub size_bytes: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SchedulingSpec {
+    #[prost(map = "string, string", tag = "1")]
+    pub node_selector: ::std::collections::HashMap<std::string::String, std::string::String>,
+    #[prost(string, tag = "2")]
+    pub priority_class_name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatePipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(message, optional, tag = "2")]
+    pub transform: ::std::option::Option<Transform>,
+    #[prost(message, optional, tag = "7")]
+    pub parallelism_spec: ::std::option::Option<ParallelismSpec>,
+    #[prost(message, optional, tag = "31")]
+    pub hashtree_spec: ::std::option::Option<HashtreeSpec>,
+    #[prost(message, optional, tag = "9")]
+    pub egress: ::std::option::Option<Egress>,
+    #[prost(bool, tag = "5")]
+    pub update: bool,
+    #[prost(string, tag = "10")]
+    pub output_branch: std::string::String,
+    #[prost(message, optional, tag = "11")]
+    pub scale_down_threshold: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "12")]
+    pub resource_requests: ::std::option::Option<ResourceSpec>,
+    #[prost(message, optional, tag = "22")]
+    pub resource_limits: ::std::option::Option<ResourceSpec>,
+    #[prost(message, optional, tag = "13")]
+    pub input: ::std::option::Option<Input>,
+    #[prost(string, tag = "14")]
+    pub description: std::string::String,
+    #[prost(bool, tag = "15")]
+    pub incremental: bool,
+    #[prost(string, tag = "16")]
+    pub cache_size: std::string::String,
+    #[prost(bool, tag = "17")]
+    pub enable_stats: bool,
+    /// Reprocess forces the pipeline to reprocess all datums.
+    /// It only has meaning if Update is true
+    #[prost(bool, tag = "18")]
+    pub reprocess: bool,
+    #[prost(bool, tag = "19")]
+    pub batch: bool,
+    #[prost(int64, tag = "20")]
+    pub max_queue_size: i64,
+    #[prost(message, optional, tag = "21")]
+    pub service: ::std::option::Option<Service>,
+    #[prost(message, optional, tag = "23")]
+    pub chunk_spec: ::std::option::Option<ChunkSpec>,
+    #[prost(message, optional, tag = "24")]
+    pub datum_timeout: ::std::option::Option<::prost_types::Duration>,
+    #[prost(message, optional, tag = "25")]
+    pub job_timeout: ::std::option::Option<::prost_types::Duration>,
+    #[prost(string, tag = "26")]
+    pub salt: std::string::String,
+    #[prost(bool, tag = "27")]
+    pub standby: bool,
+    #[prost(int64, tag = "28")]
+    pub datum_tries: i64,
+    #[prost(message, optional, tag = "29")]
+    pub scheduling_spec: ::std::option::Option<SchedulingSpec>,
+    #[prost(string, tag = "30")]
+    pub pod_spec: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectPipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPipelineRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeletePipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(bool, tag = "4")]
+    pub all: bool,
+    #[prost(bool, tag = "5")]
+    pub force: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartPipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopPipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RerunPipelineRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pipeline: ::std::option::Option<Pipeline>,
+    #[prost(message, repeated, tag = "2")]
+    pub exclude: ::std::vec::Vec<super::pfs_1_7::Commit>,
+    #[prost(message, repeated, tag = "3")]
+    pub include: ::std::vec::Vec<super::pfs_1_7::Commit>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GarbageCollectRequest {
+    /// Memory is how much memory to use in computing which objects are alive. A
+    /// larger number will result in more precise garbage collection (at the
+    /// cost of more memory usage).
+    #[prost(int64, tag = "1")]
+    pub memory_bytes: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GarbageCollectResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActivateAuthRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActivateAuthResponse {}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum JobState {
+    JobStarting = 0,
+    JobRunning = 1,
+    JobFailure = 2,
+    JobSuccess = 3,
+    JobKilled = 4,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DatumState {
+    Failed = 0,
+    Success = 1,
+    Skipped = 2,
+    Starting = 3,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum WorkerState {
+    PodRunning = 0,
+    PodSuccess = 1,
+    PodFailed = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PipelineState {
+    /// When the pipeline is not ready to be triggered by commits.
+    /// This happens when either 1) a pipeline has been created but not
+    /// yet picked up by a PPS server, or 2) the pipeline does not have
+    /// any inputs and is meant to be triggered manually
+    PipelineStarting = 0,
+    /// After this pipeline is picked up by a pachd node.  This is the normal
+    /// state of a pipeline.
+    PipelineRunning = 1,
+    /// After some error caused runPipeline to exit, but before the
+    /// pipeline is re-run.  This is when the exponential backoff is
+    /// in effect.
+    PipelineRestarting = 2,
+    /// We have retried too many times and we have given up on this pipeline.
+    PipelineFailure = 3,
+    /// The pipeline has been explicitly paused by the user.
+    PipelinePaused = 4,
+    /// The pipeline is fully functional, but there are no commits to process.
+    PipelineStandby = 5,
+}
+#[doc = r" Generated client implementations."]
+pub mod api_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct ApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ApiClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ApiClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        pub async fn create_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateJobRequest>,
+        ) -> Result<tonic::Response<super::Job>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/CreateJob");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn inspect_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectJobRequest>,
+        ) -> Result<tonic::Response<super::JobInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/InspectJob");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListJob returns information about current and past Pachyderm jobs. This is"]
+        #[doc = " deprecated in favor of ListJobStream"]
+        pub async fn list_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListJobRequest>,
+        ) -> Result<tonic::Response<super::JobInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/ListJob");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListJobStream returns information about current and past Pachyderm jobs."]
+        pub async fn list_job_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListJobRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::JobInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/ListJobStream");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn flush_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FlushJobRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::JobInfo>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/FlushJob");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn delete_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteJobRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/DeleteJob");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn stop_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StopJobRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/StopJob");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn inspect_datum(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectDatumRequest>,
+        ) -> Result<tonic::Response<super::DatumInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/InspectDatum");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListDatum returns information about each datum fed to a Pachyderm job. This"]
+        #[doc = " is deprecated in favor of ListDatumStream"]
+        pub async fn list_datum(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDatumRequest>,
+        ) -> Result<tonic::Response<super::ListDatumResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/ListDatum");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " ListDatumStream returns information about each datum fed to a Pachyderm job"]
+        pub async fn list_datum_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDatumRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::ListDatumStreamResponse>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/ListDatumStream");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn restart_datum(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestartDatumRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/RestartDatum");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn create_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreatePipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/CreatePipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn inspect_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectPipelineRequest>,
+        ) -> Result<tonic::Response<super::PipelineInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/InspectPipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPipelineRequest>,
+        ) -> Result<tonic::Response<super::PipelineInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/ListPipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeletePipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/DeletePipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn start_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartPipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/StartPipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn stop_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StopPipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/StopPipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn rerun_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RerunPipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/RerunPipeline");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " DeleteAll deletes everything"]
+        pub async fn delete_all(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/DeleteAll");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn get_logs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLogsRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::LogMessage>>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/GetLogs");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        #[doc = " Garbage collection"]
+        pub async fn garbage_collect(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GarbageCollectRequest>,
+        ) -> Result<tonic::Response<super::GarbageCollectResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/GarbageCollect");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " An internal call that causes PPS to put itself into an auth-enabled state"]
+        #[doc = " (all pipeline have tokens, correct permissions, etcd)"]
+        pub async fn activate_auth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ActivateAuthRequest>,
+        ) -> Result<tonic::Response<super::ActivateAuthResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pps_1_7.API/ActivateAuth");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for ApiClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ApiClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ApiClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod api_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ApiServer."]
+    #[async_trait]
+    pub trait Api: Send + Sync + 'static {
+        async fn create_job(
+            &self,
+            request: tonic::Request<super::CreateJobRequest>,
+        ) -> Result<tonic::Response<super::Job>, tonic::Status>;
+        async fn inspect_job(
+            &self,
+            request: tonic::Request<super::InspectJobRequest>,
+        ) -> Result<tonic::Response<super::JobInfo>, tonic::Status>;
+        #[doc = " ListJob returns information about current and past Pachyderm jobs. This is"]
+        #[doc = " deprecated in favor of ListJobStream"]
+        async fn list_job(
+            &self,
+            request: tonic::Request<super::ListJobRequest>,
+        ) -> Result<tonic::Response<super::JobInfos>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListJobStream method."]
+        type ListJobStreamStream: Stream<Item = Result<super::JobInfo, tonic::Status>> + Send + Sync + 'static;
+        #[doc = " ListJobStream returns information about current and past Pachyderm jobs."]
+        async fn list_job_stream(
+            &self,
+            request: tonic::Request<super::ListJobRequest>,
+        ) -> Result<tonic::Response<Self::ListJobStreamStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the FlushJob method."]
+        type FlushJobStream: Stream<Item = Result<super::JobInfo, tonic::Status>> + Send + Sync + 'static;
+        async fn flush_job(
+            &self,
+            request: tonic::Request<super::FlushJobRequest>,
+        ) -> Result<tonic::Response<Self::FlushJobStream>, tonic::Status>;
+        async fn delete_job(
+            &self,
+            request: tonic::Request<super::DeleteJobRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn stop_job(
+            &self,
+            request: tonic::Request<super::StopJobRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn inspect_datum(
+            &self,
+            request: tonic::Request<super::InspectDatumRequest>,
+        ) -> Result<tonic::Response<super::DatumInfo>, tonic::Status>;
+        #[doc = " ListDatum returns information about each datum fed to a Pachyderm job. This"]
+        #[doc = " is deprecated in favor of ListDatumStream"]
+        async fn list_datum(
+            &self,
+            request: tonic::Request<super::ListDatumRequest>,
+        ) -> Result<tonic::Response<super::ListDatumResponse>, tonic::Status>;
+        #[doc = "Server streaming response type for the ListDatumStream method."]
+        type ListDatumStreamStream: Stream<Item = Result<super::ListDatumStreamResponse, tonic::Status>>
+            + Send
+            + Sync
+            + 'static;
+        #[doc = " ListDatumStream returns information about each datum fed to a Pachyderm job"]
+        async fn list_datum_stream(
+            &self,
+            request: tonic::Request<super::ListDatumRequest>,
+        ) -> Result<tonic::Response<Self::ListDatumStreamStream>, tonic::Status>;
+        async fn restart_datum(
+            &self,
+            request: tonic::Request<super::RestartDatumRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn create_pipeline(
+            &self,
+            request: tonic::Request<super::CreatePipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn inspect_pipeline(
+            &self,
+            request: tonic::Request<super::InspectPipelineRequest>,
+        ) -> Result<tonic::Response<super::PipelineInfo>, tonic::Status>;
+        async fn list_pipeline(
+            &self,
+            request: tonic::Request<super::ListPipelineRequest>,
+        ) -> Result<tonic::Response<super::PipelineInfos>, tonic::Status>;
+        async fn delete_pipeline(
+            &self,
+            request: tonic::Request<super::DeletePipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn start_pipeline(
+            &self,
+            request: tonic::Request<super::StartPipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn stop_pipeline(
+            &self,
+            request: tonic::Request<super::StopPipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn rerun_pipeline(
+            &self,
+            request: tonic::Request<super::RerunPipelineRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = " DeleteAll deletes everything"]
+        async fn delete_all(&self, request: tonic::Request<()>) -> Result<tonic::Response<()>, tonic::Status>;
+        #[doc = "Server streaming response type for the GetLogs method."]
+        type GetLogsStream: Stream<Item = Result<super::LogMessage, tonic::Status>> + Send + Sync + 'static;
+        async fn get_logs(
+            &self,
+            request: tonic::Request<super::GetLogsRequest>,
+        ) -> Result<tonic::Response<Self::GetLogsStream>, tonic::Status>;
+        #[doc = " Garbage collection"]
+        async fn garbage_collect(
+            &self,
+            request: tonic::Request<super::GarbageCollectRequest>,
+        ) -> Result<tonic::Response<super::GarbageCollectResponse>, tonic::Status>;
+        #[doc = " An internal call that causes PPS to put itself into an auth-enabled state"]
+        #[doc = " (all pipeline have tokens, correct permissions, etcd)"]
+        async fn activate_auth(
+            &self,
+            request: tonic::Request<super::ActivateAuthRequest>,
+        ) -> Result<tonic::Response<super::ActivateAuthResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ApiServer<T: Api> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: Api> ApiServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ApiServer<T>
+    where
+        T: Api,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/pps_1_7.API/CreateJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateJobSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::CreateJobRequest> for CreateJobSvc<T> {
+                        type Response = super::Job;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CreateJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_job(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CreateJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/InspectJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectJobSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectJobRequest> for InspectJobSvc<T> {
+                        type Response = super::JobInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_job(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/ListJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListJobSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListJobRequest> for ListJobSvc<T> {
+                        type Response = super::JobInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_job(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/ListJobStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListJobStreamSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::ListJobRequest> for ListJobStreamSvc<T> {
+                        type Response = super::JobInfo;
+                        type ResponseStream = T::ListJobStreamStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_job_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListJobStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/FlushJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct FlushJobSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::FlushJobRequest> for FlushJobSvc<T> {
+                        type Response = super::JobInfo;
+                        type ResponseStream = T::FlushJobStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::FlushJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).flush_job(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = FlushJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/DeleteJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteJobSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteJobRequest> for DeleteJobSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_job(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/StopJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct StopJobSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::StopJobRequest> for StopJobSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::StopJobRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).stop_job(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = StopJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/InspectDatum" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectDatumSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectDatumRequest> for InspectDatumSvc<T> {
+                        type Response = super::DatumInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectDatumRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_datum(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectDatumSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/ListDatum" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDatumSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListDatumRequest> for ListDatumSvc<T> {
+                        type Response = super::ListDatumResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListDatumRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_datum(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListDatumSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/ListDatumStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDatumStreamSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::ListDatumRequest> for ListDatumStreamSvc<T> {
+                        type Response = super::ListDatumStreamResponse;
+                        type ResponseStream = T::ListDatumStreamStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListDatumRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_datum_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = ListDatumStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/RestartDatum" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestartDatumSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::RestartDatumRequest> for RestartDatumSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::RestartDatumRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).restart_datum(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RestartDatumSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/CreatePipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreatePipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::CreatePipelineRequest> for CreatePipelineSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::CreatePipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = CreatePipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/InspectPipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectPipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectPipelineRequest> for InspectPipelineSvc<T> {
+                        type Response = super::PipelineInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectPipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectPipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/ListPipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListPipelineRequest> for ListPipelineSvc<T> {
+                        type Response = super::PipelineInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListPipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListPipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/DeletePipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeletePipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeletePipelineRequest> for DeletePipelineSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeletePipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeletePipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/StartPipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct StartPipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::StartPipelineRequest> for StartPipelineSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::StartPipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).start_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = StartPipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/StopPipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct StopPipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::StopPipelineRequest> for StopPipelineSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::StopPipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).stop_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = StopPipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/RerunPipeline" => {
+                    #[allow(non_camel_case_types)]
+                    struct RerunPipelineSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::RerunPipelineRequest> for RerunPipelineSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::RerunPipelineRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).rerun_pipeline(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RerunPipelineSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/DeleteAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteAllSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<()> for DeleteAllSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_all(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/GetLogs" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLogsSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::ServerStreamingService<super::GetLogsRequest> for GetLogsSvc<T> {
+                        type Response = super::LogMessage;
+                        type ResponseStream = T::GetLogsStream;
+                        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GetLogsRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_logs(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = GetLogsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/GarbageCollect" => {
+                    #[allow(non_camel_case_types)]
+                    struct GarbageCollectSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::GarbageCollectRequest> for GarbageCollectSvc<T> {
+                        type Response = super::GarbageCollectResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::GarbageCollectRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).garbage_collect(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GarbageCollectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pps_1_7.API/ActivateAuth" => {
+                    #[allow(non_camel_case_types)]
+                    struct ActivateAuthSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ActivateAuthRequest> for ActivateAuthSvc<T> {
+                        type Response = super::ActivateAuthResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ActivateAuthRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).activate_auth(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ActivateAuthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Api> Clone for ApiServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: Api> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Api> tonic::transport::NamedService for ApiServer<T> {
+        const NAME: &'static str = "pps_1_7.API";
+    }
+}
@@ -0,0 +1,126 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerState {
+    #[prost(string, tag = "1")]
+    pub address: std::string::String,
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FrontendState {
+    #[prost(string, tag = "1")]
+    pub address: std::string::String,
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerRole {
+    #[prost(string, tag = "1")]
+    pub address: std::string::String,
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+    #[prost(map = "uint64, bool", tag = "3")]
+    pub shards: ::std::collections::HashMap<u64, bool>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Addresses {
+    #[prost(int64, tag = "1")]
+    pub version: i64,
+    #[prost(map = "uint64, string", tag = "2")]
+    pub addresses: ::std::collections::HashMap<u64, std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartRegister {
+    #[prost(string, tag = "1")]
+    pub address: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishRegister {
+    #[prost(string, tag = "1")]
+    pub address: std::string::String,
+    #[prost(string, tag = "2")]
+    pub error: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Version {
+    #[prost(int64, tag = "1")]
+    pub result: i64,
+    #[prost(string, tag = "2")]
+    pub error: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartAssignRoles {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishAssignRoles {
+    #[prost(string, tag = "1")]
+    pub error: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedToAssignRoles {
+    #[prost(map = "string, message", tag = "1")]
+    pub server_states: ::std::collections::HashMap<std::string::String, ServerState>,
+    #[prost(uint64, tag = "2")]
+    pub num_shards: u64,
+    #[prost(uint64, tag = "3")]
+    pub num_replicas: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetServerState {
+    #[prost(message, optional, tag = "1")]
+    pub server_state: ::std::option::Option<ServerState>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetFrontendState {
+    #[prost(message, optional, tag = "1")]
+    pub frontend_state: ::std::option::Option<FrontendState>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddServerRole {
+    #[prost(message, optional, tag = "1")]
+    pub server_role: ::std::option::Option<ServerRole>,
+    #[prost(string, tag = "2")]
+    pub error: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveServerRole {
+    #[prost(message, optional, tag = "1")]
+    pub server_role: ::std::option::Option<ServerRole>,
+    #[prost(string, tag = "2")]
+    pub error: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetServerRole {
+    #[prost(message, optional, tag = "2")]
+    pub server_role: ::std::option::Option<ServerRole>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteServerRole {
+    #[prost(message, optional, tag = "2")]
+    pub server_role: ::std::option::Option<ServerRole>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAddresses {
+    #[prost(message, optional, tag = "1")]
+    pub addresses: ::std::option::Option<Addresses>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAddress {
+    #[prost(uint64, tag = "1")]
+    pub shard: u64,
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+    #[prost(string, tag = "3")]
+    pub result: std::string::String,
+    #[prost(bool, tag = "4")]
+    pub ok: bool,
+    #[prost(string, tag = "5")]
+    pub error: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetShardToAddress {
+    #[prost(int64, tag = "1")]
+    pub version: i64,
+    #[prost(map = "uint64, string", tag = "2")]
+    pub result: ::std::collections::HashMap<u64, std::string::String>,
+    #[prost(string, tag = "3")]
+    pub error: std::string::String,
+}
@@ -0,0 +1,497 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteAllRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionRequest {
+    /// Exactly one of these fields should be set
+    #[prost(message, optional, tag = "1")]
+    pub create_repo: ::std::option::Option<super::pfs::CreateRepoRequest>,
+    #[prost(message, optional, tag = "2")]
+    pub delete_repo: ::std::option::Option<super::pfs::DeleteRepoRequest>,
+    #[prost(message, optional, tag = "3")]
+    pub start_commit: ::std::option::Option<super::pfs::StartCommitRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub finish_commit: ::std::option::Option<super::pfs::FinishCommitRequest>,
+    #[prost(message, optional, tag = "5")]
+    pub delete_commit: ::std::option::Option<super::pfs::DeleteCommitRequest>,
+    #[prost(message, optional, tag = "6")]
+    pub create_branch: ::std::option::Option<super::pfs::CreateBranchRequest>,
+    #[prost(message, optional, tag = "7")]
+    pub delete_branch: ::std::option::Option<super::pfs::DeleteBranchRequest>,
+    #[prost(message, optional, tag = "11")]
+    pub update_job_state: ::std::option::Option<super::pps::UpdateJobStateRequest>,
+    #[prost(message, optional, tag = "10")]
+    pub delete_all: ::std::option::Option<DeleteAllRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionResponse {
+    /// At most, one of these fields should be set (most responses are empty)
+    ///
+    /// Only used for StartCommit - any way we can deterministically provide this before finishing the transaction?
+    #[prost(message, optional, tag = "2")]
+    pub commit: ::std::option::Option<super::pfs::Commit>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    #[prost(string, tag = "1")]
+    pub id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfo {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::std::option::Option<Transaction>,
+    #[prost(message, repeated, tag = "2")]
+    pub requests: ::std::vec::Vec<TransactionRequest>,
+    #[prost(message, repeated, tag = "3")]
+    pub responses: ::std::vec::Vec<TransactionResponse>,
+    #[prost(message, optional, tag = "4")]
+    pub started: ::std::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfos {
+    #[prost(message, repeated, tag = "1")]
+    pub transaction_info: ::std::vec::Vec<TransactionInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchTransactionRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::std::vec::Vec<TransactionRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StartTransactionRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InspectTransactionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::std::option::Option<Transaction>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteTransactionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::std::option::Option<Transaction>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTransactionRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishTransactionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::std::option::Option<Transaction>,
+}
+#[doc = r" Generated client implementations."]
+pub mod api_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct ApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ApiClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ApiClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        #[doc = " Transaction rpcs"]
+        pub async fn batch_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/BatchTransaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn start_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartTransactionRequest>,
+        ) -> Result<tonic::Response<super::Transaction>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/StartTransaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn inspect_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InspectTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/InspectTransaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteTransactionRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/DeleteTransaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfos>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/ListTransaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn finish_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FinishTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/FinishTransaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteAllRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/transaction.API/DeleteAll");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for ApiClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ApiClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ApiClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod api_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ApiServer."]
+    #[async_trait]
+    pub trait Api: Send + Sync + 'static {
+        #[doc = " Transaction rpcs"]
+        async fn batch_transaction(
+            &self,
+            request: tonic::Request<super::BatchTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfo>, tonic::Status>;
+        async fn start_transaction(
+            &self,
+            request: tonic::Request<super::StartTransactionRequest>,
+        ) -> Result<tonic::Response<super::Transaction>, tonic::Status>;
+        async fn inspect_transaction(
+            &self,
+            request: tonic::Request<super::InspectTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfo>, tonic::Status>;
+        async fn delete_transaction(
+            &self,
+            request: tonic::Request<super::DeleteTransactionRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+        async fn list_transaction(
+            &self,
+            request: tonic::Request<super::ListTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfos>, tonic::Status>;
+        async fn finish_transaction(
+            &self,
+            request: tonic::Request<super::FinishTransactionRequest>,
+        ) -> Result<tonic::Response<super::TransactionInfo>, tonic::Status>;
+        async fn delete_all(
+            &self,
+            request: tonic::Request<super::DeleteAllRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ApiServer<T: Api> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: Api> ApiServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ApiServer<T>
+    where
+        T: Api,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/transaction.API/BatchTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchTransactionSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::BatchTransactionRequest> for BatchTransactionSvc<T> {
+                        type Response = super::TransactionInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::BatchTransactionRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).batch_transaction(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = BatchTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transaction.API/StartTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct StartTransactionSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::StartTransactionRequest> for StartTransactionSvc<T> {
+                        type Response = super::Transaction;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::StartTransactionRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).start_transaction(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = StartTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transaction.API/InspectTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct InspectTransactionSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::InspectTransactionRequest> for InspectTransactionSvc<T> {
+                        type Response = super::TransactionInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::InspectTransactionRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).inspect_transaction(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = InspectTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transaction.API/DeleteTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteTransactionSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteTransactionRequest> for DeleteTransactionSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteTransactionRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_transaction(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transaction.API/ListTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTransactionSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::ListTransactionRequest> for ListTransactionSvc<T> {
+                        type Response = super::TransactionInfos;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::ListTransactionRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_transaction(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transaction.API/FinishTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct FinishTransactionSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::FinishTransactionRequest> for FinishTransactionSvc<T> {
+                        type Response = super::TransactionInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::FinishTransactionRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).finish_transaction(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = FinishTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transaction.API/DeleteAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteAllSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::DeleteAllRequest> for DeleteAllSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::DeleteAllRequest>) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_all(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DeleteAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Api> Clone for ApiServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: Api> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Api> tonic::transport::NamedService for ApiServer<T> {
+        const NAME: &'static str = "transaction.API";
+    }
+}
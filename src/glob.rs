@@ -0,0 +1,295 @@
+//! Matching of paths against globs, with the same syntax pachd uses for
+//! `GlobFile` and pipeline inputs.
+
+/// The most patterns a glob's alternatives may expand to. Each `{a,b}`
+/// doubles them, so without a limit, a short glob could take exponential
+/// time and memory to compile and match.
+const MAX_PATTERNS: usize = 1024;
+
+/// A compiled glob. `*` and `?` match within a path segment, `**` matches
+/// across segments, `[...]` matches a class of characters (negated by a
+/// leading `!`), and `{a,b}` matches any of its alternatives.
+#[derive(Clone, Debug)]
+pub(crate) struct Glob {
+    /// The glob, with its alternatives expanded
+    patterns: Vec<Vec<Token>>,
+}
+
+#[derive(Clone, Debug)]
+enum Token {
+    Char(char),
+    AnyChar,
+    AnySegment,
+    AnyPath,
+    Class { negated: bool, ranges: Vec<(char, char)> },
+}
+
+impl Glob {
+    /// Compiles a glob, returning a description of the problem if it's
+    /// malformed.
+    pub(crate) fn new(glob: &str) -> Result<Self, String> {
+        let patterns = expand(glob)
+            .and_then(|expanded| expanded.iter().map(|pattern| tokenize(pattern)).collect())
+            .map_err(|err| format!("{} in `{}`", err, glob))?;
+        Ok(Glob { patterns })
+    }
+
+    /// Whether a path matches the glob.
+    pub(crate) fn is_match(&self, path: &str) -> bool {
+        let path: Vec<char> = path.chars().collect();
+        self.patterns.iter().any(|tokens| {
+            let mut failed = vec![false; (tokens.len() + 1) * (path.len() + 1)];
+            matches(tokens, &path, 0, 0, &mut failed)
+        })
+    }
+}
+
+/// Expands a glob's `{a,b}` alternatives into separate patterns, failing
+/// once there are more than `MAX_PATTERNS` of them.
+fn expand(glob: &str) -> Result<Vec<String>, String> {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '{' => break,
+            '}' => return Err("unmatched `}`".into()),
+            _ => i += 1,
+        }
+    }
+    if i >= chars.len() {
+        return Ok(vec![glob.into()]);
+    }
+
+    // Split the outermost alternatives on their top-level commas
+    let prefix: String = chars[..i].iter().collect();
+    let mut alternatives = vec![String::new()];
+    let mut depth = 0;
+    let mut j = i + 1;
+    loop {
+        let c = *chars.get(j).ok_or("unclosed `{`")?;
+        match c {
+            '\\' => {
+                let escaped = *chars.get(j + 1).ok_or("trailing `\\`")?;
+                let alternative = alternatives.last_mut().expect("there's always an alternative");
+                alternative.push(c);
+                alternative.push(escaped);
+                j += 2;
+                continue;
+            }
+            '}' if depth == 0 => break,
+            ',' if depth == 0 => alternatives.push(String::new()),
+            c => {
+                if c == '{' {
+                    depth += 1;
+                } else if c == '}' {
+                    depth -= 1;
+                }
+                alternatives.last_mut().expect("there's always an alternative").push(c);
+            }
+        }
+        j += 1;
+    }
+    let suffix: String = chars[j + 1..].iter().collect();
+
+    let mut expanded = Vec::new();
+    for alternative in alternatives {
+        expanded.extend(expand(&format!("{}{}{}", prefix, alternative, suffix))?);
+        if expanded.len() > MAX_PATTERNS {
+            return Err(format!("alternatives expand to more than {} patterns", MAX_PATTERNS));
+        }
+    }
+    Ok(expanded)
+}
+
+/// Compiles a pattern without alternatives.
+fn tokenize(pattern: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '\\' => Token::Char(chars.next().ok_or("trailing `\\`")?),
+            '?' => Token::AnyChar,
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                Token::AnyPath
+            }
+            '*' => Token::AnySegment,
+            '[' => {
+                let negated = chars.peek() == Some(&'!');
+                if negated {
+                    chars.next();
+                }
+                let mut ranges = Vec::new();
+                loop {
+                    let start = match chars.next().ok_or("unclosed `[`")? {
+                        ']' if !ranges.is_empty() => break,
+                        '\\' => chars.next().ok_or("trailing `\\`")?,
+                        c => c,
+                    };
+                    // A `-` right before the closing `]` is literal
+                    let is_range = chars.peek() == Some(&'-') && chars.clone().nth(1) != Some(']');
+                    let end = if is_range {
+                        chars.next();
+                        match chars.next().ok_or("unclosed `[`")? {
+                            '\\' => chars.next().ok_or("trailing `\\`")?,
+                            c => c,
+                        }
+                    } else {
+                        start
+                    };
+                    ranges.push((start, end));
+                }
+                Token::Class { negated, ranges }
+            }
+            c => Token::Char(c),
+        });
+    }
+    Ok(tokens)
+}
+
+/// Whether the path from `p` on matches the pattern from token `t` on.
+/// Which of these have failed to match is remembered in `failed`, so that
+/// wildcards never retry them, which would otherwise take exponential time
+/// for patterns like `*a*a*a*b`.
+fn matches(tokens: &[Token], path: &[char], t: usize, p: usize, failed: &mut [bool]) -> bool {
+    let state = t * (path.len() + 1) + p;
+    if failed[state] {
+        return false;
+    }
+    let matched = match tokens.get(t) {
+        None => p == path.len(),
+        Some(Token::AnySegment) => (p..=path.len())
+            .take_while(|&i| i == p || path[i - 1] != '/')
+            .any(|i| matches(tokens, path, t + 1, i, failed)),
+        Some(Token::AnyPath) => (p..=path.len()).any(|i| matches(tokens, path, t + 1, i, failed)),
+        Some(token) => {
+            path.get(p).is_some_and(|&c| matches_char(token, c)) && matches(tokens, path, t + 1, p + 1, failed)
+        }
+    };
+    failed[state] = !matched;
+    matched
+}
+
+fn matches_char(token: &Token, c: char) -> bool {
+    match token {
+        Token::Char(expected) => c == *expected,
+        Token::AnyChar => c != '/',
+        Token::Class { negated, ranges } => {
+            c != '/' && ranges.iter().any(|&(start, end)| start <= c && c <= end) != *negated
+        }
+        Token::AnySegment | Token::AnyPath => unreachable!("wildcards match sequences"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_match(glob: &str, path: &str) -> bool {
+        Glob::new(glob).unwrap().is_match(path)
+    }
+
+    #[test]
+    fn matches_literals() {
+        assert!(is_match("/a/b.txt", "/a/b.txt"));
+        assert!(!is_match("/a/b.txt", "/a/b.txt2"));
+        assert!(!is_match("/a/b.txt", "/a"));
+        assert!(is_match(r"/a\*", "/a*"));
+        assert!(!is_match(r"/a\*", "/ab"));
+        assert!(is_match("/é/ü", "/é/ü"));
+    }
+
+    #[test]
+    fn matches_wildcards_within_segments() {
+        assert!(is_match("/*", "/a"));
+        assert!(is_match("/*", "/"));
+        assert!(!is_match("/*", "/a/b"));
+        assert!(is_match("/*/*.txt", "/a/b.txt"));
+        assert!(!is_match("/*.txt", "/a/b.txt"));
+        assert!(is_match("/?", "/é"));
+        assert!(!is_match("/?", "/ab"));
+        assert!(!is_match("/a?b", "/a/b"));
+    }
+
+    #[test]
+    fn matches_double_star_across_segments() {
+        assert!(is_match("**", "/"));
+        assert!(is_match("**", "/a/b/c"));
+        assert!(is_match("/**", "/a/b/c"));
+        assert!(is_match("**/*.tmp", "/a.tmp"));
+        assert!(is_match("**/*.tmp", "/a/b/c.tmp"));
+        assert!(!is_match("**/*.tmp", "/a/b/c.tmp/d"));
+        assert!(is_match("/a/**/z", "/a/b/c/z"));
+        assert!(!is_match("/a/**/z", "/b/c/z"));
+        assert!(is_match("/a/**", "/a/b"));
+        assert!(!is_match("/a/**", "/b/a/c"));
+    }
+
+    #[test]
+    fn matches_character_classes() {
+        assert!(is_match("/[abc]", "/b"));
+        assert!(!is_match("/[abc]", "/d"));
+        assert!(is_match("/[a-c][0-9]", "/c7"));
+        assert!(!is_match("/[a-c][0-9]", "/d7"));
+        assert!(is_match("/[!a-c]", "/d"));
+        assert!(!is_match("/[!a-c]", "/b"));
+        assert!(!is_match("/a[!b]c", "/a/c"));
+        assert!(!is_match("/a[/]c", "/a/c"));
+        assert!(is_match("/[]]", "/]"));
+        assert!(is_match("/[a-]", "/-"));
+        assert!(is_match(r"/[\]]", "/]"));
+        assert!(is_match("/[à-ÿ]", "/é"));
+    }
+
+    #[test]
+    fn matches_alternatives() {
+        assert!(is_match("/{a,b}.txt", "/a.txt"));
+        assert!(is_match("/{a,b}.txt", "/b.txt"));
+        assert!(!is_match("/{a,b}.txt", "/c.txt"));
+        assert!(is_match("/{a,b{c,d}}", "/bd"));
+        assert!(is_match("/x{,y}", "/x"));
+        assert!(is_match(r"/{a\,b,c}", "/a,b"));
+        assert!(!is_match(r"/{a\,b,c}", "/a"));
+    }
+
+    #[test]
+    fn matches_pathological_globs_quickly() {
+        // Without memoization, each of these tries exponentially many ways of
+        // splitting the path between the wildcards
+        let path = format!("/{}", "a".repeat(100));
+        let glob = format!("/{}b", "*a".repeat(30));
+        assert!(!is_match(&glob, &path));
+        let glob = format!("{}b", "**a".repeat(30));
+        assert!(!is_match(&glob, &path));
+        let glob = format!("/{}", "*a".repeat(30));
+        assert!(is_match(&glob, &path));
+
+        let path = format!("{}/b", "/a".repeat(50));
+        let glob = format!("{}/c", "/**".repeat(20));
+        assert!(!is_match(&glob, &path));
+
+        // Each `{a,b}` doubles the patterns, so they're limited
+        let glob = "/{a,b}".repeat(10);
+        assert!(is_match(&glob, &"/b".repeat(10)));
+        assert!(!is_match(&glob, &"/c".repeat(10)));
+        let error = "alternatives expand to more than 1024 patterns";
+        assert!(Glob::new(&"/{a,b}".repeat(11)).unwrap_err().starts_with(error));
+        assert!(Glob::new(&"/{a,b}".repeat(64)).unwrap_err().starts_with(error));
+        assert!(Glob::new(&"/{a,{b,{c,d}}}".repeat(40)).unwrap_err().starts_with(error));
+        let many = (0..2000).map(|i| i.to_string()).collect::<Vec<_>>().join(",");
+        assert!(Glob::new(&format!("/{{{}}}", many)).unwrap_err().starts_with(error));
+    }
+
+    #[test]
+    fn rejects_malformed_globs() {
+        let error = |glob| Glob::new(glob).unwrap_err();
+        assert_eq!(error("/a}"), "unmatched `}` in `/a}`");
+        assert_eq!(error("/{a,b"), "unclosed `{` in `/{a,b`");
+        assert_eq!(error("/[ab"), "unclosed `[` in `/[ab`");
+        assert_eq!(error("/[a-"), "unclosed `[` in `/[a-`");
+        assert_eq!(error(r"/a\"), r"trailing `\` in `/a\`");
+        assert_eq!(error(r"/{a\"), r"trailing `\` in `/{a\`");
+        assert_eq!(error(r"/[\"), r"trailing `\` in `/[\`");
+    }
+}
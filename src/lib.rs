@@ -1,88 +1,236 @@
+//! Bindings for pachd's gRPC services, along with a higher-level `Client`.
+//!
+//! Each service is behind a Cargo feature, so that only the protos that are
+//! used get compiled:
+//!
+//! - `pfs` (default): the PFS API. This also enables `auth`, whose messages
+//!   PFS refers to.
+//! - `pps` (default): the PPS API. This also enables `pfs`.
+//! - `auth`: the auth API.
+//! - `admin`: the admin API, for extracting and restoring clusters. This
+//!   also enables `legacy-versions`.
+//! - `transaction`: the transaction API, and clients that add calls to a
+//!   transaction.
+//! - `debug`: the debug API.
+//! - `enterprise`: the enterprise API.
+//! - `legacy-versions`: the PFS and PPS APIs of pachd 1.7 through 1.10, and
+//!   the `versioned` clients that speak them.
+//! - `put-dir`: `PutDir`, for uploading local directories. This also
+//!   enables `pfs`, and tokio's filesystem support.
+//! - `blocking`: synchronous clients, in `blocking`.
+//! - `testing`: an in-process fake pachd, in `testing`, for tests that
+//!   would otherwise need a cluster. This also enables `pps`.
+//!
+//! The version and health APIs, pachctl's config, and the other utility
+//! protos are always available.
+//!
+//! PFS's `Repo`, `Branch`, `Commit` and `File` can be parsed from, and
+//! formatted as, the references pachctl takes, e.g.
+//! `"images@master^2:/cats".parse::<pfs::File>()`. `CommitResolver`
+//! resolves a commit's ancestry to a commit ID on the client, for versions
+//! of pachd that can't. `PfsFileReader` reads a file in PFS with random
+//! access, and `PfsFileWriter` streams data into one. With the `put-dir`
+//! feature, `PutDir` uploads a local directory into a commit.
+//!
+//! By default, the bindings are generated from `proto/` at build time. With
+//! the `pregenerated` feature, the bindings committed under `src/gen` are
+//! used instead, so that neither `proto/` nor protoc are needed.
+
+// Generated protobuf code trips these lints, and we have no control over it
+#![allow(clippy::enum_variant_names, clippy::four_forward_slashes)]
+// `tonic::Status` is large, but it's what every gRPC call returns, and our
+// errors need to carry it as-is
+#![allow(clippy::result_large_err)]
+
 extern crate bytes;
 extern crate prost;
 extern crate prost_types;
 extern crate tonic;
 
+/// Includes the bindings for a proto package, which are either generated at
+/// build time, or committed under `src/gen` with the `pregenerated` feature.
+macro_rules! include_proto {
+    ($package:tt) => {
+        #[cfg(not(feature = "pregenerated"))]
+        tonic::include_proto!($package);
+        #[cfg(feature = "pregenerated")]
+        include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/", $package, ".rs"));
+    };
+}
+
+mod address;
+#[cfg(feature = "pfs")]
+mod ancestry;
+mod client;
+mod error;
+#[cfg(feature = "pfs")]
+mod file;
+#[cfg(any(feature = "put-dir", feature = "testing"))]
+mod glob;
+#[cfg(feature = "put-dir")]
+mod put_dir;
+#[cfg(feature = "pfs")]
+mod reference;
+mod retry;
+mod tls;
+
+pub use crate::address::{PachdAddress, Scheme, DEFAULT_PACHD_PORT};
+#[cfg(feature = "pfs")]
+pub use crate::ancestry::CommitResolver;
+pub use crate::client::{Client, ClientBuilder, VersionCheck};
+pub use crate::error::Error;
+#[cfg(feature = "pfs")]
+pub use crate::file::{PfsFileReader, PfsFileWriter, PfsFileWriterBuilder};
+#[cfg(feature = "put-dir")]
+pub use crate::put_dir::{PutDir, PutDirProgress};
+pub use crate::retry::RetryPolicy;
+pub use crate::tls::TlsConfig;
+
+#[cfg(feature = "admin")]
 pub mod admin {
-    tonic::include_proto!("admin");
+    include_proto!("admin");
 }
 
+#[cfg(feature = "auth")]
 pub mod auth {
-    tonic::include_proto!("auth");
+    include_proto!("auth");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod auth_1_7 {
-    tonic::include_proto!("auth_1_7");
+    include_proto!("auth_1_7");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod auth_1_8 {
-    tonic::include_proto!("auth_1_8");
+    include_proto!("auth_1_8");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod auth_1_9 {
-    tonic::include_proto!("auth_1_9");
+    include_proto!("auth_1_9");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod auth_1_10 {
-    tonic::include_proto!("auth_1_10");
+    include_proto!("auth_1_10");
 }
 
+pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+pub mod config;
+
+#[cfg(feature = "debug")]
 pub mod debug {
-    tonic::include_proto!("debug");
+    include_proto!("debug");
 }
 
+#[cfg(feature = "enterprise")]
 pub mod enterprise {
-    tonic::include_proto!("enterprise");
+    include_proto!("enterprise");
+}
+
+pub mod extended {
+    //! Extended traces, which identify a Jaeger trace that follows a
+    //! long-running operation, such as creating a pipeline or running a job,
+    //! across many RPCs. pachd stores these in etcd alongside the operation.
+
+    include_proto!("extended");
+}
+
+#[cfg(feature = "legacy-versions")]
+pub mod hashtree_1_7 {
+    //! The hash trees pachd 1.7 used to track the files in each commit, as
+    //! found in pachd 1.7's object storage and extracts.
+
+    include_proto!("hashtree_1_7");
+
+    /// A reference to an object in object storage, which hash tree nodes use
+    /// to refer to file contents.
+    pub use crate::pfs_1_7::Object;
 }
 
 pub mod health {
-    tonic::include_proto!("health");
+    include_proto!("health");
 }
 
+#[cfg(feature = "pfs")]
 pub mod pfs {
-    tonic::include_proto!("pfs");
+    include_proto!("pfs");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pfs_1_7 {
-    tonic::include_proto!("pfs_1_7");
+    include_proto!("pfs_1_7");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pfs_1_8 {
-    tonic::include_proto!("pfs_1_8");
+    include_proto!("pfs_1_8");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pfs_1_9 {
-    tonic::include_proto!("pfs_1_9");
+    include_proto!("pfs_1_9");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pfs_1_10 {
-    tonic::include_proto!("pfs_1_10");
+    include_proto!("pfs_1_10");
 }
 
+#[cfg(feature = "pps")]
 pub mod pps {
-    tonic::include_proto!("pps");
+    include_proto!("pps");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pps_1_7 {
-    tonic::include_proto!("pps_1_7");
+    include_proto!("pps_1_7");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pps_1_8 {
-    tonic::include_proto!("pps_1_8");
+    include_proto!("pps_1_8");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pps_1_9 {
-    tonic::include_proto!("pps_1_9");
+    include_proto!("pps_1_9");
 }
 
+#[cfg(feature = "legacy-versions")]
+#[allow(dead_code)]
 mod pps_1_10 {
-    tonic::include_proto!("pps_1_10");
+    include_proto!("pps_1_10");
 }
 
-pub mod transaction {
-    tonic::include_proto!("transaction");
-}
+pub mod shard {
+    //! The state pachd uses to assign shards to servers, as stored in etcd.
 
-pub mod version {
-    tonic::include_proto!("versionpb");
+    include_proto!("shard");
 }
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "transaction")]
+pub mod transaction;
+
+pub mod version;
+
+#[cfg(feature = "legacy-versions")]
+pub mod versioned;
@@ -0,0 +1,579 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use futures::channel::mpsc;
+use futures::future;
+use futures::sink::SinkExt;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+
+use crate::api::PfsApi;
+use crate::file::CHUNK_SIZE;
+use crate::glob::Glob;
+use crate::pfs::{Commit, File, PutFileRequest};
+use crate::Error;
+
+/// How many `PutFile` calls upload at once by default.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// The most files uploaded per `PutFile` call. Files are otherwise batched
+/// until a batch reaches `CHUNK_SIZE` bytes.
+const BATCH_FILES: usize = 100;
+
+type ProgressFn = Box<dyn FnMut(&PutDirProgress) + Send>;
+
+/// Uploads a local directory into a commit, preserving files' paths
+/// relative to it under a prefix in PFS. Create one via `PutDir::new` or
+/// `Client::put_dir`, and start it via `run`.
+///
+/// Small files are batched together into the same `PutFile` call, and
+/// several calls upload at once. Files whose paths match an ignore glob are
+/// skipped, as are directories, along with everything in them. Globs are
+/// matched against paths relative to the local directory, with a leading
+/// `/` as in PFS, e.g. `/.git` or `**/*.tmp`. Symlinks to files are uploaded
+/// as the files they point to, but symlinks to directories are skipped, to
+/// avoid cycles, as are symlinks to nothing.
+///
+/// As with `PutFile`, files are appended to any that already exist. If an
+/// upload fails, the batches uploaded before then stay in the commit.
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+/// let commit = "images@master".parse()?;
+/// let uploaded = client
+///     .put_dir(commit, "data/training", "/training")
+///     .ignore("**/.DS_Store")
+///     .concurrency(8)
+///     .progress(|progress| {
+///         println!(
+///             "{} ({}/{} files)",
+///             progress.pfs_path, progress.files_done, progress.files_total
+///         )
+///     })
+///     .run()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PutDir<P> {
+    pfs: P,
+    commit: Commit,
+    local_path: PathBuf,
+    pfs_prefix: String,
+    ignore: Vec<String>,
+    concurrency: usize,
+    progress: Option<ProgressFn>,
+}
+
+/// The progress of a `PutDir`, reported for each file once the `PutFile`
+/// call that uploaded it has finished. Files batched into the same call are
+/// reported one after another when it finishes.
+#[derive(Clone, Debug)]
+pub struct PutDirProgress {
+    /// The local path of the file that was uploaded.
+    pub local_path: PathBuf,
+    /// The file's path in PFS.
+    pub pfs_path: String,
+    /// The file's size in bytes, as of when the directory was walked.
+    pub size_bytes: u64,
+    /// How many files have been uploaded, including this one.
+    pub files_done: u64,
+    /// How many files are being uploaded in all.
+    pub files_total: u64,
+    /// How many bytes have been uploaded, including this file's.
+    pub bytes_done: u64,
+    /// How many bytes are being uploaded in all.
+    pub bytes_total: u64,
+}
+
+/// A file to upload.
+struct Upload {
+    local_path: PathBuf,
+    pfs_path: String,
+    size_bytes: u64,
+}
+
+impl<P: PfsApi + Clone + 'static> PutDir<P> {
+    /// Prepares to upload the directory at `local_path` into a commit, with
+    /// files' paths in PFS under `pfs_prefix`, e.g. `/training`. An empty
+    /// prefix uploads to the root of the commit.
+    pub fn new<L: Into<PathBuf>>(pfs: P, commit: Commit, local_path: L, pfs_prefix: &str) -> Self {
+        PutDir {
+            pfs,
+            commit,
+            local_path: local_path.into(),
+            pfs_prefix: pfs_prefix.trim_end_matches('/').into(),
+            ignore: Vec::new(),
+            concurrency: DEFAULT_CONCURRENCY,
+            progress: None,
+        }
+    }
+
+    /// Skips files and directories whose paths match a glob. Globs are
+    /// compiled by `run`, which fails if one is malformed.
+    pub fn ignore(mut self, glob: &str) -> Self {
+        self.ignore.push(glob.into());
+        self
+    }
+
+    /// Sets how many `PutFile` calls upload at once. Defaults to 4.
+    pub fn concurrency(mut self, calls: usize) -> Self {
+        self.concurrency = calls.max(1);
+        self
+    }
+
+    /// Sets a function that's called for each file once it's uploaded. Since
+    /// files are uploaded in batches, it's called for every file in a batch
+    /// at once.
+    pub fn progress<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&PutDirProgress) + Send + 'static,
+    {
+        self.progress = Some(Box::new(f));
+        self
+    }
+
+    /// Uploads the directory, returning how many files were uploaded.
+    pub async fn run(self) -> Result<u64, Error> {
+        let PutDir {
+            pfs,
+            commit,
+            local_path,
+            pfs_prefix,
+            ignore,
+            concurrency,
+            mut progress,
+        } = self;
+        let ignore = ignore
+            .iter()
+            .map(|glob| Glob::new(glob).map_err(Error::InvalidGlob))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let uploads = walk(&local_path, &pfs_prefix, &ignore).await?;
+        let files_total = uploads.len() as u64;
+        let bytes_total = uploads.iter().map(|upload| upload.size_bytes).sum();
+
+        let mut batches = stream::iter(batch(uploads))
+            .map(|batch| put_batch(pfs.clone(), commit.clone(), batch))
+            .buffer_unordered(concurrency);
+        let mut files_done = 0;
+        let mut bytes_done = 0;
+        while let Some(batch) = batches.try_next().await? {
+            for upload in batch {
+                files_done += 1;
+                bytes_done += upload.size_bytes;
+                if let Some(progress) = &mut progress {
+                    progress(&PutDirProgress {
+                        local_path: upload.local_path,
+                        pfs_path: upload.pfs_path,
+                        size_bytes: upload.size_bytes,
+                        files_done,
+                        files_total,
+                        bytes_done,
+                        bytes_total,
+                    });
+                }
+            }
+        }
+        Ok(files_done)
+    }
+}
+
+impl<P> fmt::Debug for PutDir<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PutDir")
+            .field("commit", &self.commit)
+            .field("local_path", &self.local_path)
+            .field("pfs_prefix", &self.pfs_prefix)
+            .field("ignore", &self.ignore)
+            .field("concurrency", &self.concurrency)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Lists the files to upload under a local directory, in order of their
+/// paths.
+async fn walk(root: &Path, pfs_prefix: &str, ignore: &[Glob]) -> Result<Vec<Upload>, Error> {
+    let mut uploads = Vec::new();
+    let mut dirs = vec![(root.to_path_buf(), String::new())];
+    while let Some((dir, relative_dir)) = dirs.pop() {
+        let mut entries = fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name();
+            let name = name.to_str().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is not valid UTF-8", entry.path().display()),
+                )
+            })?;
+            let relative = format!("{}/{}", relative_dir, name);
+            if ignore.iter().any(|glob| glob.is_match(&relative)) {
+                continue;
+            }
+
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push((entry.path(), relative));
+                continue;
+            }
+            let metadata = if file_type.is_symlink() {
+                match fs::metadata(entry.path()).await {
+                    Ok(metadata) => metadata,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err.into()),
+                }
+            } else {
+                entry.metadata().await?
+            };
+            if metadata.is_file() {
+                uploads.push(Upload {
+                    local_path: entry.path(),
+                    pfs_path: format!("{}{}", pfs_prefix, relative),
+                    size_bytes: metadata.len(),
+                });
+            }
+        }
+    }
+    uploads.sort_by(|a, b| a.pfs_path.cmp(&b.pfs_path));
+    Ok(uploads)
+}
+
+/// Groups files into batches, each of which is uploaded in one call.
+fn batch(uploads: Vec<Upload>) -> Vec<Vec<Upload>> {
+    let mut batches = Vec::new();
+    let mut batch: Vec<Upload> = Vec::new();
+    let mut batch_bytes = 0;
+    for upload in uploads {
+        if !batch.is_empty() && (batch.len() >= BATCH_FILES || batch_bytes + upload.size_bytes > CHUNK_SIZE as u64) {
+            batches.push(std::mem::take(&mut batch));
+            batch_bytes = 0;
+        }
+        batch_bytes += upload.size_bytes;
+        batch.push(upload);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+/// Uploads a batch of files in one `PutFile` call, streaming each file in
+/// chunks, and returns the batch once it's written.
+async fn put_batch<P: PfsApi>(mut pfs: P, commit: Commit, batch: Vec<Upload>) -> Result<Vec<Upload>, Error> {
+    let (mut requests, stream) = mpsc::channel(1);
+    let send = async move {
+        for upload in &batch {
+            let mut file = fs::File::open(&upload.local_path).await?;
+            let mut first = true;
+            loop {
+                let mut value = Vec::new();
+                (&mut file).take(CHUNK_SIZE as u64).read_to_end(&mut value).await?;
+                if !first && value.is_empty() {
+                    break;
+                }
+                // Requests without a file continue the previous one's
+                let file = if first {
+                    Some(File {
+                        commit: Some(commit.clone()),
+                        path: upload.pfs_path.clone(),
+                    })
+                } else {
+                    None
+                };
+                let request = PutFileRequest {
+                    file,
+                    value,
+                    ..Default::default()
+                };
+                // The call has ended, so it'll return why
+                if requests.send(request).await.is_err() {
+                    return Ok(batch);
+                }
+                first = false;
+            }
+        }
+        Ok::<_, Error>(batch)
+    };
+    let (batch, ()) = future::try_join(send, pfs.put_file(Box::pin(stream))).await?;
+    Ok(batch)
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::pfs::{
+        CreateRepoRequest, FileType, FinishCommitRequest, GetFileRequest, GlobFileRequest, Repo, StartCommitRequest,
+    };
+    use crate::testing::FakePachd;
+    use crate::Client;
+
+    /// A local directory of files, deleted when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(files: &[(&str, &[u8])]) -> Self {
+            static NEXT: AtomicUsize = AtomicUsize::new(0);
+            let root = std::env::temp_dir().join(format!(
+                "pachyderm-put-dir-{}-{}",
+                std::process::id(),
+                NEXT.fetch_add(1, Ordering::SeqCst)
+            ));
+            std::fs::create_dir_all(&root).unwrap();
+            for (path, contents) in files {
+                let path = root.join(path);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(path, contents).unwrap();
+            }
+            TempDir(root)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Creates the repo `images`, and starts a commit on its master branch.
+    async fn start_commit(pachd: &FakePachd) -> (Client, Commit) {
+        let client = pachd.client().await.unwrap();
+        let repo = Repo { name: "images".into() };
+        let request = CreateRepoRequest {
+            repo: Some(repo.clone()),
+            ..Default::default()
+        };
+        client.pfs().create_repo(request).await.unwrap();
+        let request = StartCommitRequest {
+            parent: Some(Commit {
+                repo: Some(repo),
+                id: String::new(),
+            }),
+            branch: "master".into(),
+            ..Default::default()
+        };
+        let commit = client.pfs().start_commit(request).await.unwrap().into_inner();
+        (client, commit)
+    }
+
+    /// Reads every file in a commit, by path.
+    async fn read_files(client: &Client, commit: &Commit) -> BTreeMap<String, Vec<u8>> {
+        let request = GlobFileRequest {
+            commit: Some(commit.clone()),
+            pattern: "/**".into(),
+        };
+        let infos = client.pfs().glob_file(request).await.unwrap().into_inner().file_info;
+        let mut files = BTreeMap::new();
+        for info in infos.into_iter().filter(|info| info.file_type == FileType::File as i32) {
+            let file = info.file.unwrap();
+            let request = GetFileRequest {
+                file: Some(file.clone()),
+                ..Default::default()
+            };
+            let stream = client.pfs().get_file(request).await.unwrap().into_inner();
+            let chunks: Vec<Vec<u8>> = stream.try_collect().await.unwrap();
+            files.insert(file.path, chunks.concat());
+        }
+        files
+    }
+
+    fn upload(pfs_path: &str, size_bytes: u64) -> Upload {
+        Upload {
+            local_path: PathBuf::new(),
+            pfs_path: pfs_path.into(),
+            size_bytes,
+        }
+    }
+
+    fn batch_sizes(batches: &[Vec<Upload>]) -> Vec<usize> {
+        batches.iter().map(Vec::len).collect()
+    }
+
+    #[test]
+    fn batches_by_count_and_size() {
+        let uploads = (0..250).map(|i| upload(&format!("/{}", i), 1)).collect();
+        assert_eq!(batch_sizes(&batch(uploads)), vec![100, 100, 50]);
+
+        let chunk = CHUNK_SIZE as u64;
+        let uploads = vec![
+            upload("/a", chunk / 2),
+            upload("/b", chunk / 2),
+            upload("/c", 1),
+            upload("/d", chunk * 3),
+            upload("/e", 1),
+        ];
+        assert_eq!(batch_sizes(&batch(uploads)), vec![2, 1, 1, 1]);
+
+        assert!(batch(Vec::new()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn uploads_files_in_batches() {
+        let dir = TempDir::new(&[]);
+        let mut expected = BTreeMap::new();
+        for i in 0..250 {
+            let path = format!("dir{}/file{:03}", i % 3, i);
+            let contents = format!("contents of {}", i).into_bytes();
+            std::fs::create_dir_all(dir.0.join(format!("dir{}", i % 3))).unwrap();
+            std::fs::write(dir.0.join(&path), &contents).unwrap();
+            expected.insert(format!("/{}", path), contents);
+        }
+
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let progress = reports.clone();
+        let uploaded = client
+            .put_dir(commit.clone(), &dir.0, "")
+            .concurrency(2)
+            .progress(move |report| progress.lock().unwrap().push(report.clone()))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(uploaded, 250);
+        assert_eq!(read_files(&client, &commit).await, expected);
+
+        let reports = reports.lock().unwrap();
+        let bytes_total: u64 = expected.values().map(|contents| contents.len() as u64).sum();
+        assert_eq!(reports.len(), 250);
+        for (i, report) in reports.iter().enumerate() {
+            assert_eq!(report.files_done, i as u64 + 1);
+            assert_eq!(report.files_total, 250);
+            assert_eq!(report.bytes_total, bytes_total);
+            assert_eq!(report.size_bytes, expected[&report.pfs_path].len() as u64);
+        }
+        assert_eq!(reports.last().unwrap().bytes_done, bytes_total);
+    }
+
+    #[tokio::test]
+    async fn skips_ignored_files_and_dirs() {
+        let dir = TempDir::new(&[
+            ("a.txt", b"a"),
+            ("b.tmp", b"b"),
+            (".git/config", b"config"),
+            ("sub/c.txt", b"c"),
+            ("sub/d.tmp", b"d"),
+            ("sub/.git/HEAD", b"head"),
+        ]);
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        let uploaded = client
+            .put_dir(commit.clone(), &dir.0, "")
+            .ignore("/.git")
+            .ignore("**/*.tmp")
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(uploaded, 3);
+        let files = read_files(&client, &commit).await;
+        let paths: Vec<&str> = files.keys().map(String::as_str).collect();
+        assert_eq!(paths, vec!["/a.txt", "/sub/.git/HEAD", "/sub/c.txt"]);
+    }
+
+    #[tokio::test]
+    async fn uploads_under_prefix() {
+        let dir = TempDir::new(&[("a.txt", b"a"), ("sub/b.txt", b"b")]);
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        client
+            .put_dir(commit.clone(), &dir.0, "/training/")
+            .run()
+            .await
+            .unwrap();
+        client
+            .put_dir(commit.clone(), &dir.0, "/nested/deeper")
+            .run()
+            .await
+            .unwrap();
+        client.put_dir(commit.clone(), &dir.0, "/").run().await.unwrap();
+
+        let files = read_files(&client, &commit).await;
+        let paths: Vec<&str> = files.keys().map(String::as_str).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/a.txt",
+                "/nested/deeper/a.txt",
+                "/nested/deeper/sub/b.txt",
+                "/sub/b.txt",
+                "/training/a.txt",
+                "/training/sub/b.txt",
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn follows_symlinks_to_files_only() {
+        let dir = TempDir::new(&[("a.txt", b"a"), ("sub/b.txt", b"b")]);
+        std::os::unix::fs::symlink(dir.0.join("a.txt"), dir.0.join("link.txt")).unwrap();
+        std::os::unix::fs::symlink(&dir.0, dir.0.join("sub/cycle")).unwrap();
+        std::os::unix::fs::symlink(dir.0.join("missing.txt"), dir.0.join("dangling.txt")).unwrap();
+
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        let uploaded = client.put_dir(commit.clone(), &dir.0, "").run().await.unwrap();
+
+        assert_eq!(uploaded, 3);
+        let files = read_files(&client, &commit).await;
+        assert_eq!(files["/link.txt"], b"a");
+        let paths: Vec<&str> = files.keys().map(String::as_str).collect();
+        assert_eq!(paths, vec!["/a.txt", "/link.txt", "/sub/b.txt"]);
+    }
+
+    #[tokio::test]
+    async fn fails_on_invalid_glob() {
+        let dir = TempDir::new(&[("a.txt", b"a")]);
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        let err = client
+            .put_dir(commit.clone(), &dir.0, "")
+            .ignore("{a,b")
+            .run()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::InvalidGlob(_)), "{:?}", err);
+        assert!(err.to_string().starts_with("invalid glob: "), "{}", err);
+        assert!(read_files(&client, &commit).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fails_on_missing_dir() {
+        let dir = TempDir::new(&[]);
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        let err = client
+            .put_dir(commit, dir.0.join("missing"), "")
+            .run()
+            .await
+            .unwrap_err();
+
+        match err {
+            Error::Io(err) => assert_eq!(err.kind(), io::ErrorKind::NotFound),
+            err => panic!("expected an I/O error, got {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_on_finished_commit() {
+        let dir = TempDir::new(&[("a.txt", b"a"), ("b.txt", b"b")]);
+        let pachd = FakePachd::start().await.unwrap();
+        let (client, commit) = start_commit(&pachd).await;
+        let request = FinishCommitRequest {
+            commit: Some(commit.clone()),
+            ..Default::default()
+        };
+        client.pfs().finish_commit(request).await.unwrap();
+
+        let err = client.put_dir(commit, &dir.0, "").run().await.unwrap_err();
+        assert!(matches!(err, Error::CommitFinished(_)), "{:?}", err);
+    }
+}
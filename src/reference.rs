@@ -0,0 +1,379 @@
+//! pachctl's syntax for referring to repos, branches, commits and files, e.g.
+//! `images`, `images@master`, `images@master^2` and `images@master:/cats`.
+//!
+//! A commit is given by ID or branch, optionally followed by ancestry
+//! suffixes, each of which goes back some generations: `^` and `~` go back
+//! one, and `^N` and `~N` go back `N`, so `master^2` and `master~2` are both
+//! the grandparent of `master`'s head. Suffixes add up, so `master^^~3` goes
+//! back five. Unlike git, `^N` never means a commit's `N`th parent, since
+//! commits only have one.
+//!
+//! Ancestry is kept in the commit's ID as written, which pachd resolves, as
+//! pachctl relies on.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::pfs::{Branch, Commit, File, Repo};
+use crate::Error;
+
+/// A reference, split into its parts.
+struct Parts<'a> {
+    repo: &'a str,
+    /// The branch or commit, with any ancestry suffixes
+    commit: Option<&'a str>,
+    path: Option<&'a str>,
+}
+
+impl<'a> Parts<'a> {
+    /// Splits and validates a reference. Each part is checked only for
+    /// being well-formed, and not for whether it's allowed in what's being
+    /// parsed.
+    fn parse(s: &'a str) -> Result<Self, Error> {
+        let (repo, rest) = match s.find('@') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+        if rest.is_none() && repo.contains(':') {
+            return Err(invalid(s, "missing `@` and branch or commit before the path"));
+        }
+        check_name(s, repo, "repo name")?;
+
+        let (commit, path) = match rest {
+            Some(rest) => match rest.find(':') {
+                Some(i) => (Some(&rest[..i]), Some(&rest[i + 1..])),
+                None => (Some(rest), None),
+            },
+            None => (None, None),
+        };
+        if let Some(commit) = commit {
+            let (base, _) = parse_ancestry(commit).map_err(|msg| invalid(s, &msg))?;
+            if base.is_empty() {
+                return Err(invalid(s, "missing branch or commit after `@`"));
+            }
+            check_name(s, base, "branch or commit")?;
+        }
+
+        Ok(Parts { repo, commit, path })
+    }
+
+    fn repo(&self) -> Option<Repo> {
+        Some(Repo { name: self.repo.into() })
+    }
+}
+
+/// Splits a commit ID or branch name from its ancestry suffixes, returning
+/// it and the number of generations the suffixes go back.
+pub(crate) fn parse_ancestry(commit: &str) -> Result<(&str, u64), String> {
+    let start = match commit.find(['^', '~']) {
+        Some(start) => start,
+        None => return Ok((commit, 0)),
+    };
+
+    let mut generations: u64 = 0;
+    let mut chars = commit[start..].chars().peekable();
+    while let Some(op) = chars.next() {
+        if op != '^' && op != '~' {
+            return Err(format!("unexpected `{}` after ancestry suffix", op));
+        }
+        let mut digits = String::new();
+        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+            digits.push(c);
+        }
+        let n = match digits.as_str() {
+            "" => 1,
+            n => n
+                .parse()
+                .map_err(|_| format!("too many generations in ancestry suffix `{}{}`", op, n))?,
+        };
+        generations = generations
+            .checked_add(n)
+            .ok_or_else(|| format!("too many generations in ancestry `{}`", &commit[start..]))?;
+    }
+    Ok((&commit[..start], generations))
+}
+
+/// Checks that a repo, branch or commit ID only has the characters pachd
+/// allows in names.
+fn check_name(s: &str, name: &str, what: &str) -> Result<(), Error> {
+    if name.is_empty() {
+        return Err(invalid(s, &format!("missing {}", what)));
+    }
+    match name
+        .chars()
+        .find(|&c| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+    {
+        Some(c) => Err(invalid(s, &format!("invalid character `{}` in {} `{}`", c, what, name))),
+        None => Ok(()),
+    }
+}
+
+fn invalid(s: &str, msg: &str) -> Error {
+    Error::InvalidReference(format!("{} in `{}`", msg, s))
+}
+
+/// Parses a repo by name, e.g. `images`.
+impl FromStr for Repo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = Parts::parse(s)?;
+        if parts.commit.is_some() {
+            return Err(invalid(s, "unexpected branch or commit after a repo"));
+        }
+        Ok(Repo {
+            name: parts.repo.into(),
+        })
+    }
+}
+
+/// Formats a repo as its name.
+impl fmt::Display for Repo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Parses a branch, e.g. `images@master`.
+impl FromStr for Branch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = Parts::parse(s)?;
+        let name = parts
+            .commit
+            .ok_or_else(|| invalid(s, "missing `@` and branch after the repo"))?;
+        if let Some(i) = name.find(['^', '~']) {
+            return Err(invalid(
+                s,
+                &format!("unexpected ancestry suffix `{}` after a branch", &name[i..]),
+            ));
+        }
+        if parts.path.is_some() {
+            return Err(invalid(s, "unexpected path after a branch"));
+        }
+        Ok(Branch {
+            repo: parts.repo(),
+            name: name.into(),
+        })
+    }
+}
+
+/// Formats a branch as `repo@branch`.
+impl fmt::Display for Branch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", repo_name(&self.repo), self.name)
+    }
+}
+
+/// Parses a commit, given by ID or branch, optionally with ancestry
+/// suffixes, e.g. `images@master` or `images@master^2`. As with pachctl,
+/// `^N` and `~N` both go back `N` generations, and `^` and `~` go back one.
+/// The suffixes are kept in the ID, for pachd to resolve.
+impl FromStr for Commit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = Parts::parse(s)?;
+        let id = parts
+            .commit
+            .ok_or_else(|| invalid(s, "missing `@` and branch or commit after the repo"))?;
+        if parts.path.is_some() {
+            return Err(invalid(s, "unexpected path after a commit"));
+        }
+        Ok(Commit {
+            repo: parts.repo(),
+            id: id.into(),
+        })
+    }
+}
+
+/// Formats a commit as `repo@id`, where the ID may be a branch, and may
+/// have ancestry suffixes.
+impl fmt::Display for Commit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", repo_name(&self.repo), self.id)
+    }
+}
+
+/// Parses a file in a commit, e.g. `images@master:/cats/1.png`. Without a
+/// path, e.g. `images@master`, this is the commit's root directory.
+impl FromStr for File {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = Parts::parse(s)?;
+        let id = parts
+            .commit
+            .ok_or_else(|| invalid(s, "missing `@` and branch or commit after the repo"))?;
+        Ok(File {
+            commit: Some(Commit {
+                repo: parts.repo(),
+                id: id.into(),
+            }),
+            path: parts.path.unwrap_or_default().into(),
+        })
+    }
+}
+
+/// Formats a file as `repo@id:path`, leaving off the path if it's empty.
+impl fmt::Display for File {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(commit) = &self.commit {
+            write!(f, "{}", commit)?;
+        }
+        if !self.path.is_empty() {
+            write!(f, ":{}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+fn repo_name(repo: &Option<Repo>) -> &str {
+    repo.as_ref().map_or("", |repo| &repo.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error<T: FromStr<Err = Error> + fmt::Debug>(s: &str) -> String {
+        match s.parse::<T>() {
+            Err(Error::InvalidReference(msg)) => msg,
+            other => panic!("expected `{}` to be an invalid reference, got {:?}", s, other),
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for s in &["images", "my-repo_2"] {
+            assert_eq!(s.parse::<Repo>().unwrap().to_string(), *s);
+        }
+        for s in &["images@master", "images@feature-1"] {
+            assert_eq!(s.parse::<Branch>().unwrap().to_string(), *s);
+        }
+        for s in &[
+            "images@master",
+            "images@master^2",
+            "images@0123abcd~",
+            "images@master^^~3",
+        ] {
+            assert_eq!(s.parse::<Commit>().unwrap().to_string(), *s);
+        }
+        for s in &[
+            "images@master",
+            "images@master:/cats/1.png",
+            "images@master^:/a b/ü.txt",
+        ] {
+            assert_eq!(s.parse::<File>().unwrap().to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn parses_parts() {
+        let file: File = "images@master~2:/cats".parse().unwrap();
+        assert_eq!(file.path, "/cats");
+        let commit = file.commit.unwrap();
+        assert_eq!(commit.id, "master~2");
+        assert_eq!(commit.repo.unwrap().name, "images");
+
+        let root: File = "images@master".parse().unwrap();
+        assert_eq!(root.path, "");
+    }
+
+    #[test]
+    fn parses_ancestry() {
+        assert_eq!(parse_ancestry("master"), Ok(("master", 0)));
+        assert_eq!(parse_ancestry("master^"), Ok(("master", 1)));
+        assert_eq!(parse_ancestry("master~"), Ok(("master", 1)));
+        assert_eq!(parse_ancestry("master^3"), Ok(("master", 3)));
+        assert_eq!(parse_ancestry("master~10"), Ok(("master", 10)));
+        assert_eq!(parse_ancestry("master^^~3^2"), Ok(("master", 7)));
+        assert_eq!(
+            parse_ancestry("master^x"),
+            Err("unexpected `x` after ancestry suffix".to_string())
+        );
+        assert_eq!(
+            parse_ancestry("master^99999999999999999999"),
+            Err("too many generations in ancestry suffix `^99999999999999999999`".to_string())
+        );
+        assert_eq!(
+            parse_ancestry("master^18446744073709551615^"),
+            Err("too many generations in ancestry `^18446744073709551615^`".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_references() {
+        assert_eq!(error::<Repo>(""), "missing repo name in ``");
+        assert_eq!(
+            error::<Commit>("images@"),
+            "missing branch or commit after `@` in `images@`"
+        );
+        assert_eq!(error::<Commit>("@master"), "missing repo name in `@master`");
+        assert_eq!(
+            error::<File>("images:/cats"),
+            "missing `@` and branch or commit before the path in `images:/cats`"
+        );
+        assert_eq!(
+            error::<Repo>("images@master"),
+            "unexpected branch or commit after a repo in `images@master`"
+        );
+        assert_eq!(
+            error::<Branch>("images"),
+            "missing `@` and branch after the repo in `images`"
+        );
+        assert_eq!(
+            error::<Branch>("images@master^"),
+            "unexpected ancestry suffix `^` after a branch in `images@master^`"
+        );
+        assert_eq!(
+            error::<Branch>("images@master:/cats"),
+            "unexpected path after a branch in `images@master:/cats`"
+        );
+        assert_eq!(
+            error::<Commit>("images"),
+            "missing `@` and branch or commit after the repo in `images`"
+        );
+        assert_eq!(
+            error::<Commit>("images@master:/cats"),
+            "unexpected path after a commit in `images@master:/cats`"
+        );
+        assert_eq!(
+            error::<File>("images"),
+            "missing `@` and branch or commit after the repo in `images`"
+        );
+        assert_eq!(
+            error::<Repo>("ima ges"),
+            "invalid character ` ` in repo name `ima ges` in `ima ges`"
+        );
+        assert_eq!(
+            error::<Commit>("images@mas.ter"),
+            "invalid character `.` in branch or commit `mas.ter` in `images@mas.ter`"
+        );
+        assert_eq!(
+            error::<Commit>("images@^"),
+            "missing branch or commit after `@` in `images@^`"
+        );
+        assert_eq!(
+            error::<Commit>("images@master^x"),
+            "unexpected `x` after ancestry suffix in `images@master^x`"
+        );
+        assert_eq!(
+            error::<Commit>("images@master^é"),
+            "unexpected `é` after ancestry suffix in `images@master^é`"
+        );
+        assert_eq!(
+            error::<Commit>("images@master^2é"),
+            "unexpected `é` after ancestry suffix in `images@master^2é`"
+        );
+        assert_eq!(
+            error::<Repo>("imagés"),
+            "invalid character `é` in repo name `imagés` in `imagés`"
+        );
+        assert_eq!(
+            error::<Branch>("images@mäster"),
+            "invalid character `ä` in branch or commit `mäster` in `images@mäster`"
+        );
+    }
+}
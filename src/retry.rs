@@ -0,0 +1,241 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use tonic::Code;
+
+use crate::error::is_unreachable;
+use crate::Error;
+
+/// How to retry calls that fail transiently, e.g. while pachd is restarting.
+///
+/// Failed attempts are retried with exponential backoff and full jitter,
+/// until either an attempt succeeds, a non-transient error occurs, or
+/// `max_elapsed_time` has passed. Only `Unavailable` and `DeadlineExceeded`
+/// errors, including failures to connect to pachd, are considered transient.
+///
+/// Setting a policy via `ClientBuilder::retry` applies it to the idempotent
+/// calls of the versioned PFS and PPS clients only, e.g. `inspect_repo`,
+/// `list_commit`, `get_file`, `inspect_job` and `list_pipeline`. Calls with
+/// side effects, e.g. `put_file`, `finish_commit` and `create_pipeline`, are
+/// never retried, and neither are calls made via the other clients, e.g.
+/// `Client::pfs`. Those can be retried explicitly via `retry`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    max_elapsed_time: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            max_elapsed_time: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy with default settings: backoff starts at
+    /// 100ms, doubles after each attempt up to 10s, and retries stop after
+    /// 60s.
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+
+    /// Sets the upper bound of the delay before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the maximum upper bound of the delay between retries.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets how much the upper bound of the delay grows after each retry.
+    /// Panics if `multiplier` is less than 1, or isn't finite.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        assert!(
+            multiplier.is_finite() && multiplier >= 1.0,
+            "retry multiplier must be finite and at least 1, but was {}",
+            multiplier
+        );
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets how long to keep retrying for before giving up and returning the
+    /// last error.
+    pub fn max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = max_elapsed_time;
+        self
+    }
+
+    /// Runs `f` until it succeeds, fails with a non-transient error, or the
+    /// policy gives up. `f` must be safe to run more than once, e.g.:
+    ///
+    /// ```no_run
+    /// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+    /// use pachyderm::pfs::{InspectRepoRequest, Repo};
+    /// use pachyderm::RetryPolicy;
+    ///
+    /// let request = InspectRepoRequest {
+    ///     repo: Some(Repo { name: "images".into() }),
+    /// };
+    /// let info = RetryPolicy::new()
+    ///     .retry(|| {
+    ///         let mut pfs = client.pfs();
+    ///         let request = request.clone();
+    ///         async move { pfs.inspect_repo(request).await }
+    ///     })
+    ///     .await?
+    ///     .into_inner();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry<F, Fut, T, E>(&self, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        Error: From<E>,
+    {
+        let mut backoff = self.backoff();
+        loop {
+            match f().await.map_err(Error::from) {
+                Err(err) => backoff.wait(err).await?,
+                result => return result,
+            }
+        }
+    }
+
+    /// The upper bound of the delay after the one bounded by `backoff`.
+    /// Growing past what a `Duration` can hold just caps it.
+    fn grow(&self, backoff: Duration) -> Duration {
+        Duration::try_from_secs_f64(backoff.as_secs_f64() * self.multiplier)
+            .map_or(self.max_backoff, |next| next.min(self.max_backoff))
+    }
+
+    pub(crate) fn backoff(&self) -> Backoff {
+        Backoff {
+            policy: self.clone(),
+            started: Instant::now(),
+            next: self.initial_backoff,
+        }
+    }
+}
+
+/// The state of a retried call.
+#[derive(Debug)]
+pub(crate) struct Backoff {
+    policy: RetryPolicy,
+    started: Instant,
+    next: Duration,
+}
+
+impl Backoff {
+    /// Waits before the next attempt if `err` is transient and the policy
+    /// hasn't given up, or returns `err` otherwise.
+    pub(crate) async fn wait(&mut self, err: Error) -> Result<(), Error> {
+        if !is_transient(&err) {
+            return Err(err);
+        }
+
+        let delay = self.next.mul_f64(rand::thread_rng().gen());
+        if self.started.elapsed().saturating_add(delay) > self.policy.max_elapsed_time {
+            return Err(err);
+        }
+        self.next = self.policy.grow(self.next);
+
+        tokio::time::delay_for(delay).await;
+        Ok(())
+    }
+
+    /// Starts over, e.g. after a stream made progress, so that a later
+    /// failure gets the full retry budget again.
+    #[cfg(feature = "legacy-versions")]
+    pub(crate) fn reset(&mut self) {
+        self.started = Instant::now();
+        self.next = self.policy.initial_backoff;
+    }
+}
+
+/// Whether an error may go away by itself, i.e. pachd couldn't be reached,
+/// e.g. because it's restarting, or the call timed out.
+fn is_transient(err: &Error) -> bool {
+    match err {
+        Error::Unavailable(_) => true,
+        _ => err
+            .status()
+            .is_some_and(|status| status.code() == Code::DeadlineExceeded || is_unreachable(status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::Status;
+
+    use super::*;
+
+    #[test]
+    fn retries_only_transient_errors() {
+        assert!(is_transient(&Status::unavailable("connection reset").into()));
+        assert!(is_transient(&Status::deadline_exceeded("timed out").into()));
+        assert!(is_transient(&Error::Other(Status::unknown("transport error"))));
+        assert!(is_transient(&Error::Other(Status::unknown(
+            "Service was not ready: transport error"
+        ))));
+        assert!(!is_transient(&Status::unknown("pipeline failed").into()));
+        assert!(!is_transient(&Status::not_found("repo images not found").into()));
+        assert!(!is_transient(&Status::invalid_argument("invalid commit").into()));
+    }
+
+    #[test]
+    fn grows_backoff_up_to_the_max() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5))
+            .multiplier(2.0);
+        assert_eq!(policy.grow(Duration::from_secs(1)), Duration::from_secs(2));
+        assert_eq!(policy.grow(Duration::from_secs(2)), Duration::from_secs(4));
+        assert_eq!(policy.grow(Duration::from_secs(4)), Duration::from_secs(5));
+        assert_eq!(policy.grow(Duration::from_secs(5)), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn caps_backoff_that_overflows() {
+        let policy = RetryPolicy::new().max_backoff(Duration::MAX).multiplier(1e300);
+        assert_eq!(policy.grow(Duration::from_secs(1)), Duration::MAX);
+        assert_eq!(policy.grow(Duration::MAX), Duration::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "retry multiplier must be finite and at least 1")]
+    fn rejects_shrinking_multiplier() {
+        RetryPolicy::new().multiplier(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "retry multiplier must be finite and at least 1")]
+    fn rejects_negative_multiplier() {
+        RetryPolicy::new().multiplier(-2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "retry multiplier must be finite and at least 1")]
+    fn rejects_nan_multiplier() {
+        RetryPolicy::new().multiplier(f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "retry multiplier must be finite and at least 1")]
+    fn rejects_infinite_multiplier() {
+        RetryPolicy::new().multiplier(f64::INFINITY);
+    }
+}
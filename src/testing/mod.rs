@@ -0,0 +1,359 @@
+//! An in-process fake of pachd, for testing code that uses this library
+//! without a cluster.
+//!
+//! `FakePachd` serves pachd's gRPC API on a local port, keeping everything
+//! in memory. It implements the core of PFS: repos, branches, commits with
+//! parents that are open until finished, and `PutFile`, `GetFile`,
+//! `ListFile`, `GlobFile`, `DeleteFile`, `DiffFile` and `SubscribeCommit`.
+//! It also implements enough of PPS to create, inspect, stop and delete
+//! pipelines, and to list and wait on their jobs, datums and logs, all of
+//! which the test scripts through `FakePps`. Calls it doesn't implement fail
+//! with `Code::Unimplemented`. Errors carry the same messages pachd's do, so
+//! they're classified into the same `Error` variants.
+//!
+//! ```
+//! use pachyderm::pfs::{CreateRepoRequest, Repo};
+//! use pachyderm::testing::FakePachd;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), pachyderm::Error> {
+//! let pachd = FakePachd::start().await?;
+//! let client = pachd.client().await?;
+//! let request = CreateRepoRequest {
+//!     repo: Some(Repo { name: "images".into() }),
+//!     ..Default::default()
+//! };
+//! client.pfs().create_repo(request).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::net::{Ipv4Addr, SocketAddr};
+
+use futures::channel::oneshot;
+use futures::stream;
+use log::warn;
+use tokio::net::TcpListener;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+use crate::health::health_server::{Health, HealthServer};
+use crate::pfs::api_server::ApiServer as PfsServer;
+use crate::pps::api_server::ApiServer as PpsServer;
+use crate::version::api_server::{Api as VersionApi, ApiServer as VersionServer};
+use crate::version::Version;
+use crate::{Client, Error};
+
+mod pfs;
+mod pps;
+
+use self::pfs::FakePfs;
+pub use self::pps::FakePps;
+
+/// A fake pachd, served on a local port until it's dropped. It must be
+/// started from within a tokio runtime, which it runs on.
+#[derive(Debug)]
+pub struct FakePachd {
+    address: SocketAddr,
+    shutdown: Option<oneshot::Sender<()>>,
+    pps: FakePps,
+}
+
+impl FakePachd {
+    /// Starts serving a fake pachd with no repos, on a free port on the
+    /// loopback interface.
+    pub async fn start() -> Result<Self, Error> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let address = listener.local_addr()?;
+        let incoming = stream::unfold(listener, |mut listener| async move {
+            let conn = listener.accept().await.map(|(conn, _)| conn);
+            Some((conn, listener))
+        });
+
+        let pfs = FakePfs::new();
+        let pps = FakePps::new(pfs.clone());
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let server = Server::builder()
+            .add_service(PfsServer::new(pfs))
+            .add_service(PpsServer::new(pps.clone()))
+            .add_service(VersionServer::new(FakeVersion))
+            .add_service(HealthServer::new(FakeHealth))
+            .serve_with_incoming_shutdown(incoming, async {
+                let _ = shutdown_rx.await;
+            });
+        tokio::spawn(async move {
+            if let Err(err) = server.await {
+                warn!("fake pachd failed: {}", err);
+            }
+        });
+
+        Ok(FakePachd {
+            address,
+            shutdown: Some(shutdown_tx),
+            pps,
+        })
+    }
+
+    /// Gets the fake's address, in the format `Client::connect` accepts.
+    pub fn address(&self) -> String {
+        format!("grpc://{}", self.address)
+    }
+
+    /// Connects a client to the fake.
+    pub async fn client(&self) -> Result<Client, Error> {
+        Client::connect(&self.address()).await
+    }
+
+    /// Gets a handle for scripting what the fake's pipelines do.
+    pub fn pps(&self) -> FakePps {
+        self.pps.clone()
+    }
+}
+
+impl Drop for FakePachd {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// Reports the latest version of pachd this library supports.
+struct FakeVersion;
+
+#[tonic::async_trait]
+impl VersionApi for FakeVersion {
+    async fn get_version(&self, _: Request<()>) -> Result<Response<Version>, Status> {
+        Ok(Response::new(Version {
+            major: 1,
+            minor: 11,
+            micro: 0,
+            additional: "-fake".into(),
+        }))
+    }
+}
+
+struct FakeHealth;
+
+#[tonic::async_trait]
+impl Health for FakeHealth {
+    async fn health(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        Ok(Response::new(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::{self, TryStreamExt};
+    use tonic::Code;
+
+    use super::*;
+    use crate::pfs::{
+        BuildCommitRequest, Commit, CreateRepoRequest, File, FinishCommitRequest, GetFileRequest, InspectBranchRequest,
+        InspectCommitRequest, InspectRepoRequest, ListFileRequest, PutFileRequest, Repo, StartCommitRequest,
+    };
+    use crate::pps::{
+        CreatePipelineRequest, DatumInfo, DatumState, Input, InspectJobRequest, JobState, PfsInput, Pipeline,
+    };
+
+    async fn create_repo(client: &Client, name: &str) -> Result<(), Error> {
+        let request = CreateRepoRequest {
+            repo: Some(Repo { name: name.into() }),
+            ..Default::default()
+        };
+        client.pfs().create_repo(request).await?;
+        Ok(())
+    }
+
+    async fn start_commit(client: &Client, repo: &str) -> Result<Commit, Error> {
+        let request = StartCommitRequest {
+            parent: Some(Commit {
+                repo: Some(Repo { name: repo.into() }),
+                id: String::new(),
+            }),
+            branch: "master".into(),
+            ..Default::default()
+        };
+        Ok(client.pfs().start_commit(request).await?.into_inner())
+    }
+
+    async fn finish_commit(client: &Client, commit: &Commit) -> Result<(), Error> {
+        let request = FinishCommitRequest {
+            commit: Some(commit.clone()),
+            ..Default::default()
+        };
+        client.pfs().finish_commit(request).await?;
+        Ok(())
+    }
+
+    async fn put_file(client: &Client, commit: &Commit, path: &str, chunks: &[&[u8]]) -> Result<(), Error> {
+        let requests: Vec<_> = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| PutFileRequest {
+                // Requests after the first continue the same file
+                file: if i == 0 {
+                    Some(File {
+                        commit: Some(commit.clone()),
+                        path: path.into(),
+                    })
+                } else {
+                    None
+                },
+                value: chunk.to_vec(),
+                ..Default::default()
+            })
+            .collect();
+        client.pfs().put_file(stream::iter(requests)).await?;
+        Ok(())
+    }
+
+    async fn get_file(client: &Client, file: &str) -> Result<Vec<u8>, Error> {
+        let request = GetFileRequest {
+            file: Some(file.parse()?),
+            ..Default::default()
+        };
+        let chunks: Vec<Vec<u8>> = client.pfs().get_file(request).await?.into_inner().try_collect().await?;
+        Ok(chunks.concat())
+    }
+
+    #[tokio::test]
+    async fn serves_the_latest_version() -> Result<(), Error> {
+        let pachd = FakePachd::start().await?;
+        let client = pachd.client().await?;
+        let version = client.server_version().expect("the version was checked on connecting");
+        assert_eq!((version.major, version.minor), (1, 11));
+        client.health().health(()).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stores_files_in_commits() -> Result<(), Error> {
+        let pachd = FakePachd::start().await?;
+        let client = pachd.client().await?;
+        create_repo(&client, "images").await?;
+
+        let first = start_commit(&client, "images").await?;
+        put_file(&client, &first, "/a.txt", &[b"hello, ", b"world"]).await?;
+        put_file(&client, &first, "/dir/b.txt", &[b"b"]).await?;
+        finish_commit(&client, &first).await?;
+
+        let second = start_commit(&client, "images").await?;
+        put_file(&client, &second, "/a.txt", &[b"!"]).await?;
+        finish_commit(&client, &second).await?;
+
+        assert_eq!(get_file(&client, "images@master:/a.txt").await?, b"hello, world!");
+        assert_eq!(get_file(&client, "images@master^:/a.txt").await?, b"hello, world");
+        assert_eq!(get_file(&client, "images@master:/dir/b.txt").await?, b"b");
+
+        let request = InspectCommitRequest {
+            commit: Some("images@master".parse()?),
+            ..Default::default()
+        };
+        let info = client.pfs().inspect_commit(request).await?.into_inner();
+        assert_eq!(info.commit, Some(second));
+        assert_eq!(info.parent_commit, Some(first));
+        assert!(info.finished.is_some());
+
+        let request = ListFileRequest {
+            file: Some("images@master:/".parse()?),
+            ..Default::default()
+        };
+        let infos = client.pfs().list_file(request).await?.into_inner().file_info;
+        let paths: Vec<String> = infos
+            .into_iter()
+            .filter_map(|info| info.file)
+            .map(|file| file.path)
+            .collect();
+        assert_eq!(paths, vec!["/a.txt", "/dir"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_like_pachd() -> Result<(), Error> {
+        let pachd = FakePachd::start().await?;
+        let client = pachd.client().await?;
+        create_repo(&client, "images").await?;
+        let commit = start_commit(&client, "images").await?;
+        finish_commit(&client, &commit).await?;
+
+        let err = create_repo(&client, "images").await.unwrap_err();
+        assert!(matches!(err, Error::RepoExists(_)), "{:?}", err);
+        let request = InspectRepoRequest {
+            repo: Some(Repo { name: "missing".into() }),
+        };
+        let err = Error::from(client.pfs().inspect_repo(request).await.unwrap_err());
+        assert!(matches!(err, Error::RepoNotFound(_)), "{:?}", err);
+        let request = InspectBranchRequest {
+            branch: Some("images@missing".parse()?),
+        };
+        let err = Error::from(client.pfs().inspect_branch(request).await.unwrap_err());
+        assert!(matches!(err, Error::BranchNotFound(_)), "{:?}", err);
+        let err = get_file(&client, "images@missing:/a.txt").await.unwrap_err();
+        assert!(matches!(err, Error::CommitNotFound(_)), "{:?}", err);
+        let err = get_file(&client, "images@master^:/a.txt").await.unwrap_err();
+        assert!(matches!(err, Error::CommitNotFound(_)), "{:?}", err);
+        let err = get_file(&client, "images@master:/a.txt").await.unwrap_err();
+        assert!(matches!(err, Error::FileNotFound(_)), "{:?}", err);
+        let err = put_file(&client, &commit, "/a.txt", &[b"a"]).await.unwrap_err();
+        assert!(matches!(err, Error::CommitFinished(_)), "{:?}", err);
+
+        let status = client
+            .pfs()
+            .build_commit(BuildCommitRequest::default())
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unimplemented);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn runs_scripted_jobs() -> Result<(), Error> {
+        let pachd = FakePachd::start().await?;
+        let client = pachd.client().await?;
+        create_repo(&client, "images").await?;
+        let request = CreatePipelineRequest {
+            pipeline: Some(Pipeline { name: "edges".into() }),
+            input: Some(Input {
+                pfs: Some(PfsInput {
+                    repo: "images".into(),
+                    glob: "/*".into(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        client.pps().create_pipeline(request).await?;
+        let commit = start_commit(&client, "images").await?;
+        finish_commit(&client, &commit).await?;
+
+        let pps = pachd.pps();
+        let job = pps.wait_for_job("edges").await?;
+        for state in [DatumState::Success, DatumState::Success, DatumState::Failed] {
+            let datum = DatumInfo {
+                state: state as i32,
+                ..Default::default()
+            };
+            pps.add_datum(&job.id, datum)?;
+        }
+        pps.set_job_state(&job.id, JobState::JobSuccess, "")?;
+
+        let request = InspectJobRequest {
+            job: Some(job.clone()),
+            block_state: true,
+            ..Default::default()
+        };
+        let info = client.pps().inspect_job(request).await?.into_inner();
+        assert_eq!(info.state, JobState::JobSuccess as i32);
+        assert_eq!((info.data_processed, info.data_failed, info.data_total), (2, 1, 3));
+
+        let request = InspectCommitRequest {
+            commit: info.output_commit,
+            ..Default::default()
+        };
+        let output = client.pfs().inspect_commit(request).await?.into_inner();
+        assert!(output.finished.is_some());
+        Ok(())
+    }
+}
@@ -0,0 +1,1452 @@
+//! The fake's PFS API.
+//!
+//! Each commit holds a complete copy of its files, taken from its parent when
+//! it's started, so reading a commit never needs to look at its ancestors.
+//! Writes made by a single call are staged, and only applied once they've
+//! all succeeded.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use futures::stream::{self, Stream};
+use prost_types::Timestamp;
+use serde::de::IgnoredAny;
+use tokio::sync::watch;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::glob::Glob;
+use crate::pfs::api_server::Api;
+use crate::pfs::{
+    self, Branch, BranchInfo, Commit, CommitInfo, CommitOrigin, CommitState, Delimiter, File, FileInfo, FileType,
+    OriginKind, Repo, RepoInfo,
+};
+use crate::reference::parse_ancestry;
+
+/// The most file content sent back in a single message.
+const CHUNK_SIZE: usize = 1 << 20;
+
+type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + Sync + 'static>>;
+
+/// A file system's files, by their absolute paths. Directories are implied
+/// by the files beneath them.
+type Files = BTreeMap<String, Vec<u8>>;
+
+/// A repo and the ID of one of its commits.
+type CommitKey = (String, String);
+
+#[derive(Clone)]
+pub(crate) struct FakePfs {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    /// Notified whenever a commit or branch changes, for calls that wait on
+    /// them
+    changed: watch::Sender<()>,
+    changes: watch::Receiver<()>,
+}
+
+#[derive(Default)]
+pub(super) struct State {
+    repos: BTreeMap<String, RepoState>,
+    /// Incremented for every repo and branch created, to order them
+    seq: u64,
+}
+
+struct RepoState {
+    seq: u64,
+    created: Timestamp,
+    description: String,
+    branches: BTreeMap<String, BranchState>,
+    commits: HashMap<String, CommitData>,
+    /// Commit IDs, oldest first
+    commit_order: Vec<String>,
+}
+
+struct BranchState {
+    seq: u64,
+    head: Option<String>,
+    provenance: Vec<Branch>,
+}
+
+struct CommitData {
+    info: CommitInfo,
+    files: Files,
+}
+
+impl FakePfs {
+    pub(crate) fn new() -> Self {
+        let (changed, changes) = watch::channel(());
+        FakePfs {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State::default()),
+                changed,
+                changes,
+            }),
+        }
+    }
+
+    pub(super) fn state(&self) -> MutexGuard<'_, State> {
+        self.shared.state.lock().expect("fake PFS state lock poisoned")
+    }
+
+    /// Wakes up calls waiting on commits or branches.
+    pub(super) fn notify(&self) {
+        let _ = self.shared.changed.broadcast(());
+    }
+
+    /// Watches for changes, which PPS shares so that its calls can wait on
+    /// commits, and vice versa.
+    pub(super) fn changes(&self) -> watch::Receiver<()> {
+        self.shared.changes.clone()
+    }
+
+    /// Runs `f` with the state, notifying waiting calls if it succeeds.
+    fn update<T, F>(&self, f: F) -> Result<T, Status>
+    where
+        F: FnOnce(&mut State) -> Result<T, Status>,
+    {
+        let result = f(&mut self.state());
+        if result.is_ok() {
+            self.notify();
+        }
+        result
+    }
+
+    /// Waits until `f` returns something.
+    async fn wait_for<T, F>(&self, mut f: F) -> Result<T, Status>
+    where
+        F: FnMut(&State) -> Result<Option<T>, Status>,
+    {
+        let mut changes = self.changes();
+        loop {
+            if let Some(value) = f(&self.state())? {
+                return Ok(value);
+            }
+            if changes.recv().await.is_none() {
+                return Err(Status::unavailable("the fake pachd is shutting down"));
+            }
+        }
+    }
+}
+
+impl State {
+    fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    fn repo(&self, name: &str) -> Result<&RepoState, Status> {
+        self.repos.get(name).ok_or_else(|| repo_not_found(name))
+    }
+
+    fn repo_mut(&mut self, name: &str) -> Result<&mut RepoState, Status> {
+        self.repos.get_mut(name).ok_or_else(|| repo_not_found(name))
+    }
+
+    /// Resolves a commit, which may be given by branch, and may have an
+    /// ancestry suffix, to its repo and ID.
+    fn resolve(&self, commit: &Option<Commit>) -> Result<CommitKey, Status> {
+        let (repo, id) = commit_parts(commit)?;
+        let id = self.repo(repo)?.resolve(repo, id)?;
+        Ok((repo.into(), id))
+    }
+
+    fn commit(&self, (repo, id): &CommitKey) -> &CommitData {
+        &self.repos[repo].commits[id]
+    }
+
+    fn commit_mut(&mut self, (repo, id): &CommitKey) -> &mut CommitData {
+        self.repos
+            .get_mut(repo)
+            .and_then(|repo| repo.commits.get_mut(id))
+            .expect("commit exists")
+    }
+
+    /// Starts a commit, making it the head of `branch` if one is given.
+    fn start_commit(
+        &mut self,
+        repo_name: &str,
+        parent: Option<String>,
+        branch: Option<&str>,
+        description: String,
+        provenance: Vec<pfs::CommitProvenance>,
+    ) -> Result<String, Status> {
+        let seq = self.next_seq();
+        let repo = self.repo_mut(repo_name)?;
+        if let Some(parent) = &parent {
+            if repo.commits[parent].info.finished.is_none() {
+                return Err(Status::unknown(format!(
+                    "parent commit {}@{} has not been finished",
+                    repo_name, parent
+                )));
+            }
+        }
+
+        let id = format!("{:032x}", rand::random::<u128>());
+        let commit = Commit {
+            repo: Some(Repo { name: repo_name.into() }),
+            id: id.clone(),
+        };
+        let files = match &parent {
+            Some(parent) => {
+                let parent = repo.commits.get_mut(parent).expect("parent exists");
+                parent.info.child_commits.push(commit.clone());
+                parent.files.clone()
+            }
+            None => Files::new(),
+        };
+        let info = CommitInfo {
+            commit: Some(commit),
+            branch: branch.map(|name| Branch {
+                repo: Some(Repo { name: repo_name.into() }),
+                name: name.into(),
+            }),
+            origin: Some(CommitOrigin {
+                kind: OriginKind::User as i32,
+            }),
+            description,
+            parent_commit: parent.map(|id| Commit {
+                repo: Some(Repo { name: repo_name.into() }),
+                id,
+            }),
+            started: Some(SystemTime::now().into()),
+            provenance,
+            ..Default::default()
+        };
+        repo.commits.insert(id.clone(), CommitData { info, files });
+        repo.commit_order.push(id.clone());
+        if let Some(branch) = branch {
+            repo.branches
+                .entry(branch.into())
+                .or_insert_with(|| BranchState {
+                    seq,
+                    head: None,
+                    provenance: Vec::new(),
+                })
+                .head = Some(id.clone());
+        }
+        Ok(id)
+    }
+
+    fn finish_commit(&mut self, key: &CommitKey) -> Result<(), Status> {
+        let commit = self.commit_mut(key);
+        if commit.info.finished.is_some() {
+            return Err(Status::unknown(format!(
+                "commit {} in repo {} has already finished",
+                key.1, key.0
+            )));
+        }
+        commit.info.finished = Some(SystemTime::now().into());
+        commit.info.size_bytes = commit.files.values().map(|data| data.len() as u64).sum();
+        Ok(())
+    }
+
+    /// Removes a commit, reparenting its children, and moving any branches
+    /// it's the head of back to its parent.
+    fn remove_commit(&mut self, (repo_name, id): &CommitKey) {
+        let repo = self.repos.get_mut(repo_name).expect("repo exists");
+        let removed = repo.commits.remove(id).expect("commit exists");
+        repo.commit_order.retain(|other| other != id);
+        let parent = removed.info.parent_commit.map(|parent| parent.id);
+
+        if let Some(parent) = &parent {
+            let parent = repo.commits.get_mut(parent).expect("parent exists");
+            parent.info.child_commits.retain(|child| &child.id != id);
+            parent
+                .info
+                .child_commits
+                .extend(removed.info.child_commits.iter().cloned());
+        }
+        for child in &removed.info.child_commits {
+            let child = repo.commits.get_mut(&child.id).expect("child exists");
+            child.info.parent_commit = parent.clone().map(|id| Commit {
+                repo: Some(Repo {
+                    name: repo_name.clone(),
+                }),
+                id,
+            });
+        }
+        for branch in repo.branches.values_mut() {
+            if branch.head.as_ref() == Some(id) {
+                branch.head = parent.clone();
+            }
+        }
+    }
+
+    /// Checks whether a commit has reached a state.
+    fn reached(&self, info: &CommitInfo, state: i32) -> bool {
+        match CommitState::from_i32(state) {
+            Some(CommitState::Finished) => info.finished.is_some(),
+            Some(CommitState::Ready) => info.provenance.iter().all(|provenance| {
+                let finished = || -> Result<bool, Status> {
+                    let key = self.resolve(&provenance.commit)?;
+                    Ok(self.commit(&key).info.finished.is_some())
+                };
+                finished().unwrap_or(true)
+            }),
+            _ => true,
+        }
+    }
+
+    fn repo_info(&self, name: &str) -> Result<RepoInfo, Status> {
+        let repo = self.repo(name)?;
+        let size_bytes = repo
+            .branches
+            .get("master")
+            .and_then(|master| master.head.as_ref())
+            .map(|head| &repo.commits[head].info)
+            .filter(|info| info.finished.is_some())
+            .map_or(0, |info| info.size_bytes);
+        Ok(RepoInfo {
+            repo: Some(Repo { name: name.into() }),
+            created: Some(repo.created.clone()),
+            size_bytes,
+            description: repo.description.clone(),
+            branches: repo.branches.keys().map(|branch| new_branch(name, branch)).collect(),
+            auth_info: None,
+        })
+    }
+
+    fn branch_info(&self, repo_name: &str, name: &str) -> Result<BranchInfo, Status> {
+        let branch = self
+            .repo(repo_name)?
+            .branches
+            .get(name)
+            .ok_or_else(|| Status::unknown(format!("branch {} not found in repo {}", name, repo_name)))?;
+        let this = new_branch(repo_name, name);
+        let subvenance = self
+            .repos
+            .iter()
+            .flat_map(|(other_repo, repo)| {
+                repo.branches
+                    .iter()
+                    .filter(|(_, other)| other.provenance.contains(&this))
+                    .map(move |(other, _)| new_branch(other_repo, other))
+            })
+            .collect();
+        Ok(BranchInfo {
+            branch: Some(this),
+            head: branch.head.clone().map(|id| Commit {
+                repo: Some(Repo { name: repo_name.into() }),
+                id,
+            }),
+            provenance: branch.provenance.clone(),
+            subvenance,
+            direct_provenance: branch.provenance.clone(),
+            name: name.into(),
+        })
+    }
+
+    /// Lists commits, newest first, as `ListCommit` does.
+    fn list_commit(&self, request: pfs::ListCommitRequest) -> Result<Vec<CommitInfo>, Status> {
+        let repo_name = &request.repo.as_ref().ok_or_else(|| missing("repo"))?.name;
+        let repo = self.repo(repo_name)?;
+        let resolve = |commit: &Option<Commit>| match commit {
+            Some(commit) if !commit.id.is_empty() => repo.resolve(repo_name, &commit.id).map(Some),
+            _ => Ok(None),
+        };
+        let from = resolve(&request.from)?;
+        let to = resolve(&request.to)?;
+
+        let mut ids = match &to {
+            Some(to) => repo.ancestors(to),
+            None => repo.commit_order.iter().rev().cloned().collect(),
+        };
+        if let Some(from) = &from {
+            match to {
+                Some(_) => ids.truncate(ids.iter().position(|id| id == from).unwrap_or(ids.len())),
+                // Without `to`, every commit descended from `from` is listed
+                None => ids.retain(|id| id != from && repo.ancestors(id).contains(from)),
+            }
+        }
+
+        if request.number > 0 {
+            ids.truncate(request.number as usize);
+        }
+        if request.reverse {
+            ids.reverse();
+        }
+        Ok(ids.iter().map(|id| repo.commits[id].info.clone()).collect())
+    }
+
+    /// Reads a file or directory in a commit.
+    fn file_info(&self, file: &Option<File>) -> Result<(CommitKey, String), Status> {
+        let file = file.as_ref().ok_or_else(|| missing("file"))?;
+        let key = self.resolve(&file.commit)?;
+        Ok((key, normalize(&file.path)))
+    }
+
+    /// Applies the writes `f` makes, or none of them if it fails.
+    fn write<F>(&mut self, f: F) -> Result<(), Status>
+    where
+        F: FnOnce(&mut Writes<'_>) -> Result<(), Status>,
+    {
+        let mut writes = Writes {
+            state: self,
+            staged: HashMap::new(),
+            started: Vec::new(),
+        };
+        match f(&mut writes) {
+            Ok(()) => writes.apply(),
+            Err(err) => {
+                writes.abort();
+                Err(err)
+            }
+        }
+    }
+}
+
+/// What PPS needs of PFS, to manage pipelines' output repos and the commits
+/// their jobs write.
+impl State {
+    /// Creates a pipeline's output branch, and its repo if it doesn't exist,
+    /// with the pipeline's inputs as its provenance.
+    pub(super) fn create_output_branch(
+        &mut self,
+        branch: &Branch,
+        provenance: Vec<Branch>,
+        description: &str,
+    ) -> Result<(), Status> {
+        for provenance in &provenance {
+            self.repo(&provenance.repo.as_ref().ok_or_else(|| missing("provenance.repo"))?.name)?;
+        }
+        let repo_name = &branch.repo.as_ref().ok_or_else(|| missing("branch.repo"))?.name;
+        let (repo_seq, branch_seq) = (self.next_seq(), self.next_seq());
+        let repo = self.repos.entry(repo_name.clone()).or_insert_with(|| RepoState {
+            seq: repo_seq,
+            created: SystemTime::now().into(),
+            description: description.into(),
+            branches: BTreeMap::new(),
+            commits: HashMap::new(),
+            commit_order: Vec::new(),
+        });
+        repo.branches
+            .entry(branch.name.clone())
+            .or_insert_with(|| BranchState {
+                seq: branch_seq,
+                head: None,
+                provenance: Vec::new(),
+            })
+            .provenance = provenance;
+        Ok(())
+    }
+
+    pub(super) fn delete_repo(&mut self, name: &str) {
+        self.repos.remove(name);
+    }
+
+    /// Gets the head of a branch, if it has one.
+    pub(super) fn head(&self, branch: &Branch) -> Option<&CommitInfo> {
+        let repo = self.repos.get(&branch.repo.as_ref()?.name)?;
+        let head = repo.branches.get(&branch.name)?.head.as_ref()?;
+        Some(&repo.commits[head].info)
+    }
+
+    /// Starts a commit on a pipeline's output branch, for a job.
+    pub(super) fn start_output_commit(
+        &mut self,
+        branch: &Branch,
+        provenance: Vec<pfs::CommitProvenance>,
+    ) -> Result<Commit, Status> {
+        let repo_name = &branch.repo.as_ref().ok_or_else(|| missing("branch.repo"))?.name;
+        let parent = self
+            .repo(repo_name)?
+            .branches
+            .get(&branch.name)
+            .and_then(|branch| branch.head.clone());
+        let id = self.start_commit(repo_name, parent, Some(&branch.name), String::new(), provenance)?;
+        let key = (repo_name.clone(), id.clone());
+        self.commit_mut(&key).info.origin = Some(CommitOrigin {
+            kind: OriginKind::Auto as i32,
+        });
+        Ok(Commit {
+            repo: Some(Repo {
+                name: repo_name.clone(),
+            }),
+            id,
+        })
+    }
+
+    /// Finishes a job's output commit, unless it's already finished.
+    pub(super) fn finish_output_commit(&mut self, commit: &Commit) -> Result<(), Status> {
+        let key = self.resolve(&Some(commit.clone()))?;
+        if self.commit(&key).info.finished.is_none() {
+            self.finish_commit(&key)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves a commit, which may be given by branch, and may have an
+    /// ancestry suffix, to its ID.
+    pub(super) fn resolve_commit(&self, commit: &Commit) -> Result<Commit, Status> {
+        let (repo, id) = self.resolve(&Some(commit.clone()))?;
+        Ok(Commit {
+            repo: Some(Repo { name: repo }),
+            id,
+        })
+    }
+}
+
+impl RepoState {
+    /// Resolves a commit ID or branch name, optionally followed by ancestry
+    /// suffixes such as `^`, `^2` or `~2`, to a commit ID.
+    fn resolve(&self, repo_name: &str, id: &str) -> Result<String, Status> {
+        let not_found = || Status::unknown(format!("commit {} not found in repo {}", id, repo_name));
+        // Malformed suffixes are taken as part of the ID, which won't exist
+        let (base, generations) = parse_ancestry(id).unwrap_or((id, 0));
+        let mut commit = if self.commits.contains_key(base) {
+            base.to_string()
+        } else {
+            self.branches
+                .get(base)
+                .and_then(|branch| branch.head.clone())
+                .ok_or_else(not_found)?
+        };
+        for _ in 0..generations {
+            commit = self.commits[&commit]
+                .info
+                .parent_commit
+                .as_ref()
+                .ok_or_else(not_found)?
+                .id
+                .clone();
+        }
+        Ok(commit)
+    }
+
+    /// Gets a commit and all of its ancestors.
+    fn ancestors(&self, id: &str) -> Vec<String> {
+        let mut ancestors = vec![id.to_string()];
+        while let Some(parent) = &self.commits[ancestors.last().expect("not empty")].info.parent_commit {
+            ancestors.push(parent.id.clone());
+        }
+        ancestors
+    }
+}
+
+/// Staged changes to the files of open commits.
+struct Writes<'a> {
+    state: &'a mut State,
+    staged: HashMap<CommitKey, Files>,
+    /// Commits started to write to a branch whose head was finished, which
+    /// are finished once the writes are applied
+    started: Vec<CommitKey>,
+}
+
+impl Writes<'_> {
+    /// Gets the staged files of the commit to write to. Writing to a branch
+    /// whose head is finished (or that doesn't exist yet) starts a commit on
+    /// it, which is finished when the writes are applied, as with pachd.
+    fn files(&mut self, commit: &Option<Commit>) -> Result<&mut Files, Status> {
+        let (repo_name, id) = commit_parts(commit)?;
+        let repo = self.state.repo(repo_name)?;
+        let head = repo.branches.get(id).map(|branch| branch.head.clone());
+        let key = match head {
+            Some(Some(head)) if repo.commits[&head].info.finished.is_none() => (repo_name.to_string(), head),
+            Some(head) if parse_ancestry(id).map_or(true, |(_, generations)| generations == 0) => {
+                self.start(repo_name, head, id)?
+            }
+            None if !repo.commits.contains_key(id)
+                && parse_ancestry(id).map_or(true, |(_, generations)| generations == 0) =>
+            {
+                self.start(repo_name, None, id)?
+            }
+            _ => {
+                let key = (repo_name.to_string(), repo.resolve(repo_name, id)?);
+                if self.state.commit(&key).info.finished.is_some() {
+                    return Err(Status::unknown(format!(
+                        "commit {} in repo {} has already finished",
+                        key.1, key.0
+                    )));
+                }
+                key
+            }
+        };
+
+        let state = &self.state;
+        Ok(self
+            .staged
+            .entry(key)
+            .or_insert_with_key(|key| state.commit(key).files.clone()))
+    }
+
+    fn start(&mut self, repo: &str, parent: Option<String>, branch: &str) -> Result<CommitKey, Status> {
+        let id = self
+            .state
+            .start_commit(repo, parent, Some(branch), String::new(), Vec::new())?;
+        let key = (repo.to_string(), id);
+        self.started.push(key.clone());
+        Ok(key)
+    }
+
+    fn apply(self) -> Result<(), Status> {
+        for (key, files) in self.staged {
+            self.state.commit_mut(&key).files = files;
+        }
+        for key in &self.started {
+            self.state.finish_commit(key)?;
+        }
+        Ok(())
+    }
+
+    fn abort(self) {
+        for key in self.started.iter().rev() {
+            self.state.remove_commit(key);
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Api for FakePfs {
+    async fn create_repo(&self, request: Request<pfs::CreateRepoRequest>) -> Result<Response<()>, Status> {
+        let pfs::CreateRepoRequest {
+            repo,
+            description,
+            update,
+        } = request.into_inner();
+        let name = repo.ok_or_else(|| missing("repo"))?.name;
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(Status::unknown(format!(
+                "repo name ({}) invalid: only alphanumeric characters, underscores, and dashes are allowed",
+                name
+            )));
+        }
+
+        self.update(move |state| {
+            let seq = state.next_seq();
+            match state.repos.get_mut(&name) {
+                Some(repo) if update => repo.description = description,
+                Some(_) => return Err(Status::unknown(format!("repo {} already exists", name))),
+                None => {
+                    let repo = RepoState {
+                        seq,
+                        created: SystemTime::now().into(),
+                        description,
+                        branches: BTreeMap::new(),
+                        commits: HashMap::new(),
+                        commit_order: Vec::new(),
+                    };
+                    state.repos.insert(name, repo);
+                }
+            }
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn inspect_repo(&self, request: Request<pfs::InspectRepoRequest>) -> Result<Response<RepoInfo>, Status> {
+        let repo = request.into_inner().repo.ok_or_else(|| missing("repo"))?;
+        Ok(Response::new(self.state().repo_info(&repo.name)?))
+    }
+
+    async fn list_repo(&self, _: Request<pfs::ListRepoRequest>) -> Result<Response<pfs::ListRepoResponse>, Status> {
+        let state = self.state();
+        let mut repos: Vec<_> = state.repos.iter().collect();
+        repos.sort_by_key(|(_, repo)| std::cmp::Reverse(repo.seq));
+        let repo_info = repos
+            .into_iter()
+            .map(|(name, _)| state.repo_info(name))
+            .collect::<Result<_, _>>()?;
+        Ok(Response::new(pfs::ListRepoResponse { repo_info }))
+    }
+
+    async fn delete_repo(&self, request: Request<pfs::DeleteRepoRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.update(move |state| {
+            if request.all {
+                state.repos.clear();
+            } else {
+                let name = request.repo.ok_or_else(|| missing("repo"))?.name;
+                state.repos.remove(&name).ok_or_else(|| repo_not_found(&name))?;
+            }
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn start_commit(&self, request: Request<pfs::StartCommitRequest>) -> Result<Response<Commit>, Status> {
+        let pfs::StartCommitRequest {
+            parent,
+            description,
+            branch,
+            provenance,
+        } = request.into_inner();
+        let parent = parent.ok_or_else(|| missing("parent"))?;
+        let repo_name = parent.repo.as_ref().ok_or_else(|| missing("parent.repo"))?.name.clone();
+
+        self.update(move |state| {
+            let repo = state.repo(&repo_name)?;
+            // As with older versions of pachd, the branch may be given as the
+            // parent's ID instead
+            let (branch, parent_id) = if branch.is_empty() && repo.branches.contains_key(&parent.id) {
+                (parent.id.clone(), String::new())
+            } else {
+                (branch, parent.id)
+            };
+            let parent = if !parent_id.is_empty() {
+                Some(repo.resolve(&repo_name, &parent_id)?)
+            } else {
+                repo.branches.get(&branch).and_then(|branch| branch.head.clone())
+            };
+            let branch = Some(branch.as_str()).filter(|branch| !branch.is_empty());
+
+            let id = state.start_commit(&repo_name, parent, branch, description, provenance)?;
+            Ok(Response::new(Commit {
+                repo: Some(Repo {
+                    name: repo_name.clone(),
+                }),
+                id,
+            }))
+        })
+    }
+
+    async fn finish_commit(&self, request: Request<pfs::FinishCommitRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.update(move |state| {
+            let key = state.resolve(&request.commit)?;
+            state.finish_commit(&key)?;
+            if !request.description.is_empty() {
+                state.commit_mut(&key).info.description = request.description;
+            }
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn inspect_commit(
+        &self,
+        request: Request<pfs::InspectCommitRequest>,
+    ) -> Result<Response<CommitInfo>, Status> {
+        let request = request.into_inner();
+        let info = self
+            .wait_for(|state| {
+                let info = &state.commit(&state.resolve(&request.commit)?).info;
+                Ok(Some(info.clone()).filter(|info| state.reached(info, request.block_state)))
+            })
+            .await?;
+        Ok(Response::new(info))
+    }
+
+    async fn list_commit(
+        &self,
+        request: Request<pfs::ListCommitRequest>,
+    ) -> Result<Response<pfs::CommitInfos>, Status> {
+        let commit_info = self.state().list_commit(request.into_inner())?;
+        Ok(Response::new(pfs::CommitInfos { commit_info }))
+    }
+
+    type ListCommitStreamStream = ResponseStream<CommitInfo>;
+
+    async fn list_commit_stream(
+        &self,
+        request: Request<pfs::ListCommitRequest>,
+    ) -> Result<Response<Self::ListCommitStreamStream>, Status> {
+        let infos = self.state().list_commit(request.into_inner())?;
+        Ok(Response::new(Box::pin(stream::iter(infos.into_iter().map(Ok)))))
+    }
+
+    async fn delete_commit(&self, request: Request<pfs::DeleteCommitRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.update(move |state| {
+            let key = state.resolve(&request.commit)?;
+            state.remove_commit(&key);
+            Ok(Response::new(()))
+        })
+    }
+
+    type FlushCommitStream = ResponseStream<CommitInfo>;
+
+    async fn flush_commit(
+        &self,
+        _: Request<pfs::FlushCommitRequest>,
+    ) -> Result<Response<Self::FlushCommitStream>, Status> {
+        Err(unimplemented("FlushCommit"))
+    }
+
+    type SubscribeCommitStream = ResponseStream<CommitInfo>;
+
+    async fn subscribe_commit(
+        &self,
+        request: Request<pfs::SubscribeCommitRequest>,
+    ) -> Result<Response<Self::SubscribeCommitStream>, Status> {
+        let request = request.into_inner();
+        let repo_name = request.repo.ok_or_else(|| missing("repo"))?.name;
+        let from = {
+            let state = self.state();
+            let repo = state.repo(&repo_name)?;
+            match request.from {
+                Some(from) if !from.id.is_empty() => Some(repo.resolve(&repo_name, &from.id)?),
+                _ => None,
+            }
+        };
+
+        let subscription = Subscription {
+            shared: self.shared.clone(),
+            changes: self.shared.changes.clone(),
+            repo: repo_name,
+            branch: request.branch,
+            state: request.state,
+            next: 0,
+            from,
+        };
+        Ok(Response::new(Box::pin(stream::unfold(
+            Some(subscription),
+            |subscription| async move {
+                let mut subscription = subscription?;
+                match subscription.next().await {
+                    Some(Ok(info)) => Some((Ok(info), Some(subscription))),
+                    Some(Err(err)) => Some((Err(err), None)),
+                    None => None,
+                }
+            },
+        ))))
+    }
+
+    async fn build_commit(&self, _: Request<pfs::BuildCommitRequest>) -> Result<Response<Commit>, Status> {
+        Err(unimplemented("BuildCommit"))
+    }
+
+    async fn create_branch(&self, request: Request<pfs::CreateBranchRequest>) -> Result<Response<()>, Status> {
+        let pfs::CreateBranchRequest {
+            head,
+            s_branch,
+            branch,
+            provenance,
+        } = request.into_inner();
+        // Older versions of pachd take the branch's name by itself, in the
+        // head's repo
+        let Branch { repo, name } = match branch {
+            Some(branch) => branch,
+            None => Branch {
+                repo: head.as_ref().and_then(|head| head.repo.clone()),
+                name: s_branch,
+            },
+        };
+        let repo_name = repo.ok_or_else(|| missing("branch.repo"))?.name;
+
+        self.update(move |state| {
+            for provenance in &provenance {
+                state.repo(&provenance.repo.as_ref().ok_or_else(|| missing("provenance.repo"))?.name)?;
+            }
+            let head = match &head {
+                Some(head) if head.repo.as_ref().is_some_and(|repo| repo.name != repo_name) => {
+                    return Err(Status::unknown("branch and head commit must belong to the same repo"))
+                }
+                Some(head) => Some(state.repo(&repo_name)?.resolve(&repo_name, &head.id)?),
+                None => None,
+            };
+
+            let seq = state.next_seq();
+            let repo = state.repo_mut(&repo_name)?;
+            let branch = repo.branches.entry(name).or_insert_with(|| BranchState {
+                seq,
+                head: None,
+                provenance: Vec::new(),
+            });
+            if head.is_some() {
+                branch.head = head;
+            }
+            branch.provenance = provenance;
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn inspect_branch(
+        &self,
+        request: Request<pfs::InspectBranchRequest>,
+    ) -> Result<Response<BranchInfo>, Status> {
+        let branch = request.into_inner().branch.ok_or_else(|| missing("branch"))?;
+        let repo = branch.repo.ok_or_else(|| missing("branch.repo"))?;
+        Ok(Response::new(self.state().branch_info(&repo.name, &branch.name)?))
+    }
+
+    async fn list_branch(
+        &self,
+        request: Request<pfs::ListBranchRequest>,
+    ) -> Result<Response<pfs::BranchInfos>, Status> {
+        let request = request.into_inner();
+        let repo_name = request.repo.ok_or_else(|| missing("repo"))?.name;
+        let state = self.state();
+        let mut branches: Vec<_> = state.repo(&repo_name)?.branches.iter().collect();
+        branches.sort_by_key(|(_, branch)| branch.seq);
+        if !request.reverse {
+            branches.reverse();
+        }
+        let branch_info = branches
+            .into_iter()
+            .map(|(name, _)| state.branch_info(&repo_name, name))
+            .collect::<Result<_, _>>()?;
+        Ok(Response::new(pfs::BranchInfos { branch_info }))
+    }
+
+    async fn delete_branch(&self, request: Request<pfs::DeleteBranchRequest>) -> Result<Response<()>, Status> {
+        let Branch { repo, name } = request.into_inner().branch.ok_or_else(|| missing("branch"))?;
+        let repo_name = repo.ok_or_else(|| missing("branch.repo"))?.name;
+        self.update(move |state| {
+            state
+                .repo_mut(&repo_name)?
+                .branches
+                .remove(&name)
+                .ok_or_else(|| Status::unknown(format!("branch {} not found in repo {}", name, repo_name)))?;
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn put_file(&self, request: Request<Streaming<pfs::PutFileRequest>>) -> Result<Response<()>, Status> {
+        // Requests without a file append to the previous request's file
+        let mut requests = request.into_inner();
+        let mut puts: Vec<pfs::PutFileRequest> = Vec::new();
+        while let Some(request) = requests.message().await? {
+            match puts.last_mut() {
+                Some(put) if request.file.is_none() => put.value.extend(request.value),
+                _ if request.file.is_none() => return Err(missing("file")),
+                _ => puts.push(request),
+            }
+        }
+
+        self.update(move |state| {
+            state.write(|writes| {
+                for put in puts {
+                    let file = put.file.as_ref().expect("checked above");
+                    let path = normalize(&file.path);
+                    let files = writes.files(&file.commit)?;
+                    put_file(files, &path, put)?;
+                }
+                Ok(())
+            })?;
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn copy_file(&self, request: Request<pfs::CopyFileRequest>) -> Result<Response<()>, Status> {
+        let pfs::CopyFileRequest { src, dst, overwrite } = request.into_inner();
+        let dst = dst.ok_or_else(|| missing("dst"))?;
+        let dst_path = normalize(&dst.path);
+
+        self.update(move |state| {
+            let (src_key, src_path) = state.file_info(&src)?;
+            let src = &state.commit(&src_key).files;
+            if !exists(src, &src_path) {
+                return Err(file_not_found(&src_key, &src_path));
+            }
+            let copied: Vec<_> = files_under(src, &src_path)
+                .map(|(path, data)| (format!("{}{}", dst_path, &path[src_path.len()..]), data.clone()))
+                .collect();
+
+            state.write(|writes| {
+                let files = writes.files(&dst.commit)?;
+                if overwrite {
+                    delete(files, &dst_path);
+                }
+                for (path, data) in copied {
+                    check_writable(files, &path)?;
+                    files.entry(path).or_default().extend(data);
+                }
+                Ok(())
+            })?;
+            Ok(Response::new(()))
+        })
+    }
+
+    type GetFileStream = ResponseStream<Vec<u8>>;
+
+    async fn get_file(&self, request: Request<pfs::GetFileRequest>) -> Result<Response<Self::GetFileStream>, Status> {
+        let request = request.into_inner();
+        let state = self.state();
+        let (key, path) = state.file_info(&request.file)?;
+        let files = &state.commit(&key).files;
+        if !exists(files, &path) {
+            return Err(file_not_found(&key, &path));
+        }
+
+        // Directories read as the concatenation of their files, as with
+        // directories of split files in pachd
+        let data: Vec<u8> = files_under(files, &path)
+            .flat_map(|(_, data)| data.iter().copied())
+            .collect();
+        let start = (request.offset_bytes.max(0) as usize).min(data.len());
+        let end = match request.size_bytes {
+            size if size > 0 => (start + size as usize).min(data.len()),
+            _ => data.len(),
+        };
+        let chunks: Vec<_> = data[start..end]
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| Ok(chunk.to_vec()))
+            .collect();
+        Ok(Response::new(Box::pin(stream::iter(chunks))))
+    }
+
+    async fn inspect_file(&self, request: Request<pfs::InspectFileRequest>) -> Result<Response<FileInfo>, Status> {
+        let state = self.state();
+        let (key, path) = state.file_info(&request.into_inner().file)?;
+        let commit = state.commit(&key);
+        let info = file_info(&key, commit, &path).ok_or_else(|| file_not_found(&key, &path))?;
+        Ok(Response::new(info))
+    }
+
+    async fn list_file(&self, request: Request<pfs::ListFileRequest>) -> Result<Response<pfs::FileInfos>, Status> {
+        let file_info = self.list(request.into_inner())?;
+        Ok(Response::new(pfs::FileInfos { file_info }))
+    }
+
+    type ListFileStreamStream = ResponseStream<FileInfo>;
+
+    async fn list_file_stream(
+        &self,
+        request: Request<pfs::ListFileRequest>,
+    ) -> Result<Response<Self::ListFileStreamStream>, Status> {
+        let infos = self.list(request.into_inner())?;
+        Ok(Response::new(Box::pin(stream::iter(infos.into_iter().map(Ok)))))
+    }
+
+    type WalkFileStream = ResponseStream<FileInfo>;
+
+    async fn walk_file(
+        &self,
+        request: Request<pfs::WalkFileRequest>,
+    ) -> Result<Response<Self::WalkFileStream>, Status> {
+        let state = self.state();
+        let (key, path) = state.file_info(&request.into_inner().file)?;
+        let commit = state.commit(&key);
+        if !exists(&commit.files, &path) {
+            return Err(file_not_found(&key, &path));
+        }
+        let mut paths = all_paths(&commit.files);
+        paths.insert("/".into());
+        let infos: Vec<_> = paths
+            .into_iter()
+            .filter(|other| is_under(other, &path))
+            .filter_map(|other| file_info(&key, commit, &other).map(Ok))
+            .collect();
+        Ok(Response::new(Box::pin(stream::iter(infos))))
+    }
+
+    async fn glob_file(&self, request: Request<pfs::GlobFileRequest>) -> Result<Response<pfs::FileInfos>, Status> {
+        let file_info = self.glob(request.into_inner())?;
+        Ok(Response::new(pfs::FileInfos { file_info }))
+    }
+
+    type GlobFileStreamStream = ResponseStream<FileInfo>;
+
+    async fn glob_file_stream(
+        &self,
+        request: Request<pfs::GlobFileRequest>,
+    ) -> Result<Response<Self::GlobFileStreamStream>, Status> {
+        let infos = self.glob(request.into_inner())?;
+        Ok(Response::new(Box::pin(stream::iter(infos.into_iter().map(Ok)))))
+    }
+
+    async fn diff_file(
+        &self,
+        request: Request<pfs::DiffFileRequest>,
+    ) -> Result<Response<pfs::DiffFileResponse>, Status> {
+        let request = request.into_inner();
+        let state = self.state();
+        let (new_key, new_path) = state.file_info(&request.new_file)?;
+        let new_commit = state.commit(&new_key);
+
+        // By default, the file is compared to itself in the parent commit
+        let old = match &request.old_file {
+            Some(old_file) if old_file.commit.is_some() => Some(state.file_info(&request.old_file)?),
+            _ => new_commit
+                .info
+                .parent_commit
+                .as_ref()
+                .map(|parent| ((new_key.0.clone(), parent.id.clone()), new_path.clone())),
+        };
+        let empty = CommitData {
+            info: CommitInfo::default(),
+            files: Files::new(),
+        };
+        let (old_key, old_commit, old_path) = match old {
+            Some((key, path)) => {
+                let commit = state.commit(&key);
+                (key, commit, path)
+            }
+            None => (new_key.clone(), &empty, new_path.clone()),
+        };
+
+        let changed = |(this_key, this, this_path): (&CommitKey, &CommitData, &str),
+                       (other, other_path): (&CommitData, &str)| {
+            files_under(&this.files, this_path)
+                .filter(|(path, data)| {
+                    let other_file = format!("{}{}", other_path, &path[this_path.len()..]);
+                    other.files.get(&other_file) != Some(*data)
+                })
+                .filter_map(|(path, _)| file_info(this_key, this, path))
+                .collect()
+        };
+        Ok(Response::new(pfs::DiffFileResponse {
+            new_files: changed((&new_key, new_commit, &new_path), (old_commit, &old_path)),
+            old_files: changed((&old_key, old_commit, &old_path), (new_commit, &new_path)),
+        }))
+    }
+
+    async fn delete_file(&self, request: Request<pfs::DeleteFileRequest>) -> Result<Response<()>, Status> {
+        let file = request.into_inner().file.ok_or_else(|| missing("file"))?;
+        let path = normalize(&file.path);
+        self.update(move |state| {
+            state.write(|writes| {
+                delete(writes.files(&file.commit)?, &path);
+                Ok(())
+            })?;
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn delete_all(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        self.update(move |state| {
+            state.repos.clear();
+            Ok(Response::new(()))
+        })
+    }
+
+    type FsckStream = ResponseStream<pfs::FsckResponse>;
+
+    async fn fsck(&self, _: Request<pfs::FsckRequest>) -> Result<Response<Self::FsckStream>, Status> {
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+
+    async fn file_operation_v2(
+        &self,
+        _: Request<Streaming<pfs::FileOperationRequestV2>>,
+    ) -> Result<Response<()>, Status> {
+        Err(unimplemented("FileOperationV2"))
+    }
+
+    type GetTarV2Stream = ResponseStream<Vec<u8>>;
+
+    async fn get_tar_v2(&self, _: Request<pfs::GetTarRequestV2>) -> Result<Response<Self::GetTarV2Stream>, Status> {
+        Err(unimplemented("GetTarV2"))
+    }
+
+    type GetTarConditionalV2Stream = ResponseStream<pfs::GetTarConditionalResponseV2>;
+
+    async fn get_tar_conditional_v2(
+        &self,
+        _: Request<Streaming<pfs::GetTarConditionalRequestV2>>,
+    ) -> Result<Response<Self::GetTarConditionalV2Stream>, Status> {
+        Err(unimplemented("GetTarConditionalV2"))
+    }
+
+    type ListFileV2Stream = ResponseStream<pfs::FileInfoV2>;
+
+    async fn list_file_v2(&self, _: Request<pfs::ListFileRequest>) -> Result<Response<Self::ListFileV2Stream>, Status> {
+        Err(unimplemented("ListFileV2"))
+    }
+
+    type GlobFileV2Stream = ResponseStream<pfs::FileInfoV2>;
+
+    async fn glob_file_v2(&self, _: Request<pfs::GlobFileRequest>) -> Result<Response<Self::GlobFileV2Stream>, Status> {
+        Err(unimplemented("GlobFileV2"))
+    }
+}
+
+impl FakePfs {
+    fn list(&self, request: pfs::ListFileRequest) -> Result<Vec<FileInfo>, Status> {
+        if request.history != 0 {
+            return Err(unimplemented("ListFile with history"));
+        }
+        let state = self.state();
+        let (key, path) = state.file_info(&request.file)?;
+        let commit = state.commit(&key);
+        if commit.files.contains_key(&path) {
+            return Ok(file_info(&key, commit, &path).into_iter().collect());
+        }
+        if !exists(&commit.files, &path) {
+            return Err(file_not_found(&key, &path));
+        }
+        Ok(children(&commit.files, &path)
+            .filter_map(|child| file_info(&key, commit, &child))
+            .collect())
+    }
+
+    fn glob(&self, request: pfs::GlobFileRequest) -> Result<Vec<FileInfo>, Status> {
+        let state = self.state();
+        let key = state.resolve(&request.commit)?;
+        let commit = state.commit(&key);
+        let glob = Glob::new(&normalize(&request.pattern))
+            .map_err(|msg| Status::invalid_argument(format!("invalid glob: {}", msg)))?;
+        let mut paths = all_paths(&commit.files);
+        paths.insert("/".into());
+        Ok(paths
+            .into_iter()
+            .filter(|path| glob.is_match(path))
+            .filter_map(|path| file_info(&key, commit, &path))
+            .collect())
+    }
+}
+
+/// The state of a `SubscribeCommit` call.
+struct Subscription {
+    shared: Arc<Shared>,
+    changes: watch::Receiver<()>,
+    repo: String,
+    branch: String,
+    state: i32,
+    /// The index into the repo's commits of the next one to check
+    next: usize,
+    /// The commit to start after, until it's been passed
+    from: Option<String>,
+}
+
+impl Subscription {
+    /// Waits for the next commit on the branch to reach the subscribed
+    /// state. Commits are sent in the order they were started.
+    async fn next(&mut self) -> Option<Result<CommitInfo, Status>> {
+        loop {
+            match self.poll() {
+                Ok(Some(info)) => return Some(Ok(info)),
+                Ok(None) => {}
+                Err(err) => return Some(Err(err)),
+            }
+            self.changes.recv().await?;
+        }
+    }
+
+    fn poll(&mut self) -> Result<Option<CommitInfo>, Status> {
+        let state = self.shared.state.lock().expect("fake PFS state lock poisoned");
+        let repo = state.repo(&self.repo)?;
+        while let Some(id) = repo.commit_order.get(self.next) {
+            let info = &repo.commits[id].info;
+            if let Some(from) = &self.from {
+                if from == id {
+                    self.from = None;
+                }
+                self.next += 1;
+                continue;
+            }
+            if info.branch.as_ref().is_none_or(|branch| branch.name != self.branch) {
+                self.next += 1;
+                continue;
+            }
+            if !state.reached(info, self.state) {
+                return Ok(None);
+            }
+            self.next += 1;
+            return Ok(Some(info.clone()));
+        }
+        Ok(None)
+    }
+}
+
+/// Applies a `PutFile` request to a commit's files.
+fn put_file(files: &mut Files, path: &str, put: pfs::PutFileRequest) -> Result<(), Status> {
+    if put.delete {
+        delete(files, path);
+        return Ok(());
+    }
+    if !put.url.is_empty() {
+        return Err(unimplemented("PutFile from a URL"));
+    }
+    if put.header_records > 0 {
+        return Err(unimplemented("PutFile with header records"));
+    }
+
+    let delimiter = Delimiter::from_i32(put.delimiter).unwrap_or(Delimiter::None);
+    if delimiter == Delimiter::None {
+        check_writable(files, path)?;
+        let file = files.entry(path.into()).or_default();
+        match put.overwrite_index {
+            Some(overwrite) if overwrite.index == 0 => *file = put.value,
+            Some(_) => return Err(unimplemented("PutFile with a non-zero overwrite index")),
+            None => file.extend(put.value),
+        }
+        return Ok(());
+    }
+
+    // Split files are written to a directory, as files named by their index
+    if files.contains_key(path) {
+        return Err(Status::unknown(format!(
+            "cannot split data into {}, which is already a file",
+            path
+        )));
+    }
+    let index = |child: &str| u64::from_str_radix(child[path.len()..].trim_start_matches('/'), 16).ok();
+    let mut next = children(files, path)
+        .filter_map(|child| index(&child))
+        .max()
+        .map_or(0, |i| i + 1);
+    if let Some(overwrite) = put.overwrite_index {
+        let from = overwrite.index.max(0) as u64;
+        files.retain(|file, _| !is_under(file, path) || index(file).is_none_or(|i| i < from));
+        next = from;
+    }
+
+    let records = split(&put.value, delimiter)?;
+    let mut shard: Vec<u8> = Vec::new();
+    let mut shard_records = 0;
+    for (i, record) in records.iter().enumerate() {
+        shard.extend_from_slice(record);
+        shard_records += 1;
+        let full = (put.target_file_datums > 0 && shard_records >= put.target_file_datums)
+            || (put.target_file_bytes > 0 && shard.len() as i64 >= put.target_file_bytes)
+            || (put.target_file_datums <= 0 && put.target_file_bytes <= 0);
+        if full || i == records.len() - 1 {
+            let shard_path = format!("{}/{:016x}", path.trim_end_matches('/'), next);
+            check_writable(files, &shard_path)?;
+            files.insert(shard_path, std::mem::take(&mut shard));
+            shard_records = 0;
+            next += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Splits data into records by a delimiter. CSV records are split by line,
+/// so quoted fields can't contain newlines.
+fn split(data: &[u8], delimiter: Delimiter) -> Result<Vec<&[u8]>, Status> {
+    match delimiter {
+        Delimiter::None => Ok(vec![data]),
+        Delimiter::Line | Delimiter::Csv => Ok(data.split_inclusive(|&b| b == b'\n').collect()),
+        Delimiter::Json => {
+            let mut records = Vec::new();
+            let mut values = serde_json::Deserializer::from_slice(data).into_iter::<IgnoredAny>();
+            let mut start = 0;
+            while let Some(value) = values.next() {
+                value.map_err(|err| Status::unknown(format!("invalid JSON: {}", err)))?;
+                let end = values.byte_offset();
+                records.push(&data[start..end]);
+                start = end;
+            }
+            Ok(records)
+        }
+        Delimiter::Sql => Err(unimplemented("PutFile with the SQL delimiter")),
+    }
+}
+
+/// Checks that a file can be written at `path`, i.e. that it's not a
+/// directory, and that it's not beneath a file.
+fn check_writable(files: &Files, path: &str) -> Result<(), Status> {
+    if path == "/" || children(files, path).next_back().is_some() {
+        return Err(Status::unknown(format!(
+            "cannot write to {}, which is a directory",
+            path
+        )));
+    }
+    let mut parent = path;
+    while let Some(i) = parent.rfind('/').filter(|&i| i > 0) {
+        parent = &parent[..i];
+        if files.contains_key(parent) {
+            return Err(Status::unknown(format!(
+                "cannot write to {}, since {} is a file",
+                path, parent
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Deletes a file, or a directory and everything beneath it.
+fn delete(files: &mut Files, path: &str) {
+    files.retain(|file, _| !is_under(file, path));
+}
+
+/// Normalizes a path to be absolute, without a trailing slash, e.g.
+/// `dir//file/` becomes `/dir/file`.
+fn normalize(path: &str) -> String {
+    let segments: Vec<_> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    format!("/{}", segments.join("/"))
+}
+
+/// Whether `path` is `dir`, or beneath it.
+fn is_under(path: &str, dir: &str) -> bool {
+    dir == "/" || path == dir || (path.starts_with(dir) && path[dir.len()..].starts_with('/'))
+}
+
+/// Whether a file or directory exists.
+fn exists(files: &Files, path: &str) -> bool {
+    path == "/" || files.keys().any(|file| is_under(file, path))
+}
+
+/// Gets the files at or beneath a path.
+fn files_under<'a>(files: &'a Files, path: &'a str) -> impl Iterator<Item = (&'a String, &'a Vec<u8>)> + 'a {
+    files.iter().filter(move |(file, _)| is_under(file, path))
+}
+
+/// Gets the paths of the files and directories directly beneath a directory.
+fn children(files: &Files, dir: &str) -> std::collections::btree_set::IntoIter<String> {
+    let prefix = if dir == "/" {
+        "/".to_string()
+    } else {
+        format!("{}/", dir)
+    };
+    files
+        .keys()
+        .filter_map(|file| file.strip_prefix(&prefix))
+        .map(|rest| format!("{}{}", prefix, rest.split('/').next().expect("split is never empty")))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+}
+
+/// Gets the paths of every file and directory, other than the root.
+fn all_paths(files: &Files) -> BTreeSet<String> {
+    let mut paths = BTreeSet::new();
+    for file in files.keys() {
+        for (i, _) in file.match_indices('/').skip(1) {
+            paths.insert(file[..i].to_string());
+        }
+        paths.insert(file.clone());
+    }
+    paths
+}
+
+fn file_info(key: &CommitKey, commit: &CommitData, path: &str) -> Option<FileInfo> {
+    let file = File {
+        commit: Some(Commit {
+            repo: Some(Repo { name: key.0.clone() }),
+            id: key.1.clone(),
+        }),
+        path: path.into(),
+    };
+    let (file_type, children) = match commit.files.get(path) {
+        Some(_) => (FileType::File, Vec::new()),
+        None if exists(&commit.files, path) => {
+            let names = children(&commit.files, path)
+                .map(|child| child.rsplit('/').next().expect("rsplit is never empty").to_string())
+                .collect();
+            (FileType::Dir, names)
+        }
+        None => return None,
+    };
+    Some(FileInfo {
+        file: Some(file),
+        file_type: file_type as i32,
+        size_bytes: files_under(&commit.files, path)
+            .map(|(_, data)| data.len() as u64)
+            .sum(),
+        committed: commit.info.finished.clone(),
+        children,
+        ..Default::default()
+    })
+}
+
+fn commit_parts(commit: &Option<Commit>) -> Result<(&str, &str), Status> {
+    let commit = commit.as_ref().ok_or_else(|| missing("commit"))?;
+    let repo = commit.repo.as_ref().ok_or_else(|| missing("commit.repo"))?;
+    Ok((&repo.name, &commit.id))
+}
+
+fn new_branch(repo: &str, name: &str) -> Branch {
+    Branch {
+        repo: Some(Repo { name: repo.into() }),
+        name: name.into(),
+    }
+}
+
+fn repo_not_found(name: &str) -> Status {
+    Status::unknown(format!("repo {} not found", name))
+}
+
+fn file_not_found((repo, id): &CommitKey, path: &str) -> Status {
+    Status::unknown(format!("file {} not found in repo {} at commit {}", path, repo, id))
+}
+
+pub(super) fn missing(field: &str) -> Status {
+    Status::invalid_argument(format!("`{}` must be set", field))
+}
+
+pub(super) fn unimplemented(call: &str) -> Status {
+    Status::unimplemented(format!("{} isn't implemented by the fake pachd", call))
+}
@@ -0,0 +1,1106 @@
+//! The fake's PPS API, and the handle tests use to script what its pipelines
+//! do.
+//!
+//! Pipelines don't run anything. Instead, whenever a pipeline's inputs have
+//! new finished commits and its previous job is done, the fake creates a job
+//! for them, with an open output commit, which then sits in `JOB_STARTING`
+//! until a test moves it along. Jobs are created lazily, by whichever call
+//! next looks at the state, so they always appear in the same order.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use futures::stream::{self, Stream, StreamExt};
+use tonic::{Request, Response, Status};
+
+use super::pfs::{self, missing, unimplemented, FakePfs};
+use crate::pfs::{Branch, Commit, CommitProvenance, Repo};
+use crate::pps::api_server::Api;
+use crate::pps::{
+    self, Datum, DatumInfo, DatumState, Input, Job, JobInfo, JobState, LogMessage, PipelineInfo, PipelineState,
+};
+use crate::Error;
+
+type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + Sync + 'static>>;
+
+/// A handle on the fake pachd's PPS, for scripting its pipelines' jobs,
+/// datums and logs.
+///
+/// New pipelines are `PIPELINE_RUNNING`. Each job the fake creates stays
+/// in `JOB_STARTING`, with no datums, until `set_job_state` and `add_datum`
+/// are called on it. Once a job is finished, by moving it to
+/// `JOB_SUCCESS`, `JOB_FAILURE` or `JOB_KILLED`, its output commit is
+/// finished, and the pipeline's next job can be created.
+///
+/// ```
+/// use pachyderm::pfs::{Commit, CreateRepoRequest, FinishCommitRequest, Repo, StartCommitRequest};
+/// use pachyderm::pps::{CreatePipelineRequest, Input, InspectJobRequest, JobState, PfsInput, Pipeline};
+/// use pachyderm::testing::FakePachd;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), pachyderm::Error> {
+/// let pachd = FakePachd::start().await?;
+/// let client = pachd.client().await?;
+/// client
+///     .pfs()
+///     .create_repo(CreateRepoRequest {
+///         repo: Some(Repo { name: "images".into() }),
+///         ..Default::default()
+///     })
+///     .await?;
+/// client
+///     .pps()
+///     .create_pipeline(CreatePipelineRequest {
+///         pipeline: Some(Pipeline { name: "edges".into() }),
+///         input: Some(Input {
+///             pfs: Some(PfsInput {
+///                 repo: "images".into(),
+///                 glob: "/*".into(),
+///                 ..Default::default()
+///             }),
+///             ..Default::default()
+///         }),
+///         ..Default::default()
+///     })
+///     .await?;
+///
+/// // Committing to the input creates a job for the pipeline
+/// let parent = Commit {
+///     repo: Some(Repo { name: "images".into() }),
+///     ..Default::default()
+/// };
+/// let request = StartCommitRequest {
+///     parent: Some(parent),
+///     branch: "master".into(),
+///     ..Default::default()
+/// };
+/// let commit = client.pfs().start_commit(request).await?.into_inner();
+/// let request = FinishCommitRequest {
+///     commit: Some(commit),
+///     ..Default::default()
+/// };
+/// client.pfs().finish_commit(request).await?;
+///
+/// // Wait on the job the way the code under test would, while the test
+/// // decides how it turns out
+/// let pps = pachd.pps();
+/// let job = pps.wait_for_job("edges").await?;
+/// let request = InspectJobRequest {
+///     job: Some(job.clone()),
+///     block_state: true,
+///     ..Default::default()
+/// };
+/// let mut pps_client = client.pps();
+/// let waiting = tokio::spawn(async move { pps_client.inspect_job(request).await });
+/// pps.set_job_state(&job.id, JobState::JobFailure, "out of memory")?;
+/// let info = waiting.await.expect("inspecting the job panicked")?.into_inner();
+/// assert_eq!(info.state, JobState::JobFailure as i32);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FakePps {
+    pfs: FakePfs,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Every version of each pipeline, oldest first
+    pipelines: BTreeMap<String, Vec<PipelineInfo>>,
+    /// Oldest first
+    jobs: Vec<JobData>,
+    logs: Vec<LogMessage>,
+}
+
+struct JobData {
+    info: JobInfo,
+    /// The commits the job is processing, one per input branch that had one
+    inputs: Vec<Commit>,
+    datums: Vec<DatumInfo>,
+}
+
+impl FakePps {
+    pub(super) fn new(pfs: FakePfs) -> Self {
+        FakePps {
+            pfs,
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// Sets a pipeline's state, and the reason it's in it.
+    pub fn set_pipeline_state(&self, pipeline: &str, state: PipelineState, reason: &str) -> Result<(), Error> {
+        self.update(|pps, _| {
+            let info = pps.pipeline_mut(pipeline)?;
+            info.state = state as i32;
+            info.reason = reason.into();
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// Moves a job to a state, giving the reason it's in it. Moving a job to
+    /// `JOB_SUCCESS`, `JOB_FAILURE` or `JOB_KILLED` finishes it, along with
+    /// its output commit.
+    pub fn set_job_state(&self, job: &str, state: JobState, reason: &str) -> Result<(), Error> {
+        self.update(|pps, pfs| {
+            let job = pps.job_mut(job)?;
+            job.info.reason = reason.into();
+            job.set_state(state, pfs);
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// Adds a datum to a job, generating its ID if it doesn't have one, and
+    /// updates the job's datum counts to match. The datum belongs to the job
+    /// regardless of which job it names.
+    pub fn add_datum(&self, job: &str, mut datum: DatumInfo) -> Result<Datum, Error> {
+        let datum = self.update(move |pps, _| {
+            let job = pps.job_mut(job)?;
+            let id = match datum.datum.take() {
+                Some(Datum { id, .. }) if !id.is_empty() => id,
+                _ => format!("{:032x}{:032x}", rand::random::<u128>(), rand::random::<u128>()),
+            };
+            let key = Datum {
+                id,
+                job: job.info.job.clone(),
+            };
+            datum.datum = Some(key.clone());
+            job.datums.push(datum);
+
+            job.info.data_processed = count_datums(&job.datums, DatumState::Success);
+            job.info.data_skipped = count_datums(&job.datums, DatumState::Skipped);
+            job.info.data_failed = count_datums(&job.datums, DatumState::Failed);
+            job.info.data_recovered = count_datums(&job.datums, DatumState::Recovered);
+            job.info.data_total = job.datums.len() as i64;
+            Ok(key)
+        })?;
+        Ok(datum)
+    }
+
+    /// Adds a line to the logs `GetLogs` returns, timestamping it now if it
+    /// isn't already. Its pipeline, job and datum are taken as given, so
+    /// they needn't exist.
+    pub fn log(&self, mut message: LogMessage) {
+        if message.ts.is_none() {
+            message.ts = Some(SystemTime::now().into());
+        }
+        self.state().logs.push(message);
+        self.pfs.notify();
+    }
+
+    /// Waits until a pipeline exists, and gets it.
+    pub async fn wait_for_pipeline(&self, pipeline: &str) -> Result<PipelineInfo, Error> {
+        let info = self
+            .wait_for(|pps, _| Ok(pps.pipelines.get(pipeline).map(|_| pps.pipeline_info(pipeline))))
+            .await?;
+        Ok(info)
+    }
+
+    /// Waits until a pipeline has a job that isn't finished, and gets it.
+    /// Since a pipeline's next job isn't created until its previous one is
+    /// finished, this returns each of its jobs in turn.
+    pub async fn wait_for_job(&self, pipeline: &str) -> Result<Job, Error> {
+        let job = self
+            .wait_for(|pps, _| {
+                let job = pps
+                    .jobs
+                    .iter()
+                    .find(|job| job.pipeline() == pipeline && !job.is_finished());
+                Ok(job.map(|job| job.info.job.clone().expect("jobs have IDs")))
+            })
+            .await?;
+        Ok(job)
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("fake PPS state lock poisoned")
+    }
+
+    /// Runs `f` with PPS's and PFS's state, once any jobs that are due have
+    /// been created. Waiting calls are notified if anything changed, which
+    /// is assumed to be the case if `f` writes and succeeds.
+    fn run<T, F>(&self, writes: bool, f: F) -> Result<T, Status>
+    where
+        F: FnOnce(&mut State, &mut pfs::State) -> Result<T, Status>,
+    {
+        let (result, created) = {
+            let mut state = self.state();
+            let mut pfs = self.pfs.state();
+            let created = state.create_jobs(&mut pfs);
+            (f(&mut state, &mut pfs), created)
+        };
+        if created || (writes && result.is_ok()) {
+            self.pfs.notify();
+        }
+        result
+    }
+
+    fn read<T, F>(&self, f: F) -> Result<T, Status>
+    where
+        F: FnOnce(&State, &pfs::State) -> Result<T, Status>,
+    {
+        self.run(false, |state, pfs| f(state, pfs))
+    }
+
+    fn update<T, F>(&self, f: F) -> Result<T, Status>
+    where
+        F: FnOnce(&mut State, &mut pfs::State) -> Result<T, Status>,
+    {
+        self.run(true, f)
+    }
+
+    /// Waits until `f` returns something. This is woken by changes to
+    /// either PPS or PFS.
+    async fn wait_for<T, F>(&self, mut f: F) -> Result<T, Status>
+    where
+        F: FnMut(&State, &pfs::State) -> Result<Option<T>, Status>,
+    {
+        let mut changes = self.pfs.changes();
+        loop {
+            if let Some(value) = self.read(|state, pfs| f(state, pfs))? {
+                return Ok(value);
+            }
+            if changes.recv().await.is_none() {
+                return Err(Status::unavailable("the fake pachd is shutting down"));
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FakePps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakePps").finish_non_exhaustive()
+    }
+}
+
+impl State {
+    fn pipeline(&self, name: &str) -> Result<&PipelineInfo, Status> {
+        self.pipelines
+            .get(name)
+            .and_then(|versions| versions.last())
+            .ok_or_else(|| pipeline_not_found(name))
+    }
+
+    fn pipeline_mut(&mut self, name: &str) -> Result<&mut PipelineInfo, Status> {
+        self.pipelines
+            .get_mut(name)
+            .and_then(|versions| versions.last_mut())
+            .ok_or_else(|| pipeline_not_found(name))
+    }
+
+    /// Gets the current version of a pipeline, with its job counts.
+    fn pipeline_info(&self, name: &str) -> PipelineInfo {
+        let mut info = self.pipelines[name].last().expect("pipelines have a version").clone();
+        self.count_jobs(&mut info);
+        info
+    }
+
+    fn count_jobs(&self, info: &mut PipelineInfo) {
+        let name = &info.pipeline.as_ref().expect("pipelines have names").name;
+        let jobs: Vec<_> = self.jobs.iter().filter(|job| job.pipeline() == name).collect();
+        let mut job_counts = HashMap::new();
+        for job in &jobs {
+            *job_counts.entry(job.info.state).or_insert(0) += 1;
+        }
+        info.job_counts = job_counts;
+        info.last_job_state = jobs.last().map_or(0, |job| job.info.state);
+    }
+
+    fn job(&self, job: &Option<Job>) -> Result<&JobData, Status> {
+        let id = &job.as_ref().ok_or_else(|| missing("job"))?.id;
+        self.jobs
+            .iter()
+            .find(|job| job.id() == id)
+            .ok_or_else(|| job_not_found(id))
+    }
+
+    fn job_mut(&mut self, id: &str) -> Result<&mut JobData, Status> {
+        self.jobs
+            .iter_mut()
+            .find(|job| job.id() == id)
+            .ok_or_else(|| job_not_found(id))
+    }
+
+    /// Creates a job for each pipeline that isn't stopped, and whose inputs
+    /// have new commits, once its previous job is finished. Returns whether
+    /// any were created.
+    fn create_jobs(&mut self, pfs: &mut pfs::State) -> bool {
+        let mut created = Vec::new();
+        for (name, versions) in &self.pipelines {
+            let pipeline = versions.last().expect("pipelines have a version");
+            let previous = self.jobs.iter().rev().find(|job| job.pipeline() == name);
+            if pipeline.stopped || previous.is_some_and(|job| !job.is_finished()) {
+                continue;
+            }
+
+            let provenance: Vec<_> = input_branches(&pipeline.input)
+                .into_iter()
+                .filter_map(|branch| {
+                    let head = pfs.head(&branch).filter(|head| head.finished.is_some())?;
+                    Some(CommitProvenance {
+                        commit: head.commit.clone(),
+                        branch: Some(branch),
+                    })
+                })
+                .collect();
+            let inputs: Vec<_> = provenance.iter().filter_map(|input| input.commit.clone()).collect();
+            let unchanged =
+                previous.is_some_and(|job| job.inputs == inputs && job.info.pipeline_version == pipeline.version);
+            if inputs.is_empty() || unchanged {
+                continue;
+            }
+
+            let output_branch = Branch {
+                repo: Some(Repo { name: name.clone() }),
+                name: pipeline.output_branch.clone(),
+            };
+            // If the output commit can't be started, such as because someone
+            // else left an open commit on the output branch, this is tried
+            // again by the next call
+            let output_commit = match pfs.start_output_commit(&output_branch, provenance) {
+                Ok(commit) => commit,
+                Err(_) => continue,
+            };
+            let info = JobInfo {
+                job: Some(Job {
+                    id: format!("{:032x}", rand::random::<u128>()),
+                }),
+                transform: pipeline.transform.clone(),
+                pipeline: pipeline.pipeline.clone(),
+                pipeline_version: pipeline.version,
+                spec_commit: pipeline.spec_commit.clone(),
+                parallelism_spec: pipeline.parallelism_spec.clone(),
+                egress: pipeline.egress.clone(),
+                parent_job: previous.and_then(|job| job.info.job.clone()),
+                started: Some(SystemTime::now().into()),
+                output_commit: Some(output_commit),
+                state: JobState::JobStarting as i32,
+                service: pipeline.service.clone(),
+                spout: pipeline.spout.clone(),
+                output_repo: output_branch.repo.clone(),
+                output_branch: output_branch.name.clone(),
+                resource_requests: pipeline.resource_requests.clone(),
+                resource_limits: pipeline.resource_limits.clone(),
+                sidecar_resource_limits: pipeline.sidecar_resource_limits.clone(),
+                input: pipeline.input.as_ref().map(|input| with_commits(input, &inputs)),
+                enable_stats: pipeline.enable_stats,
+                salt: pipeline.salt.clone(),
+                chunk_spec: pipeline.chunk_spec.clone(),
+                datum_timeout: pipeline.datum_timeout.clone(),
+                job_timeout: pipeline.job_timeout.clone(),
+                datum_tries: pipeline.datum_tries,
+                scheduling_spec: pipeline.scheduling_spec.clone(),
+                pod_spec: pipeline.pod_spec.clone(),
+                pod_patch: pipeline.pod_patch.clone(),
+                ..Default::default()
+            };
+            created.push(JobData {
+                info,
+                inputs,
+                datums: Vec::new(),
+            });
+        }
+
+        let any = !created.is_empty();
+        self.jobs.extend(created);
+        any
+    }
+
+    /// Lists jobs, newest first, as `ListJob` does.
+    fn list_job(&self, pfs: &pfs::State, request: pps::ListJobRequest) -> Result<Vec<JobInfo>, Status> {
+        let pipeline = match &request.pipeline {
+            Some(pipeline) => Some(self.pipeline(&pipeline.name)?),
+            None => None,
+        };
+        let input_commits = request
+            .input_commit
+            .iter()
+            .map(|commit| pfs.resolve_commit(commit))
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_commit = match &request.output_commit {
+            Some(commit) => Some(pfs.resolve_commit(commit)?),
+            None => None,
+        };
+
+        Ok(self
+            .jobs
+            .iter()
+            .rev()
+            .filter(|job| {
+                pipeline.is_none_or(|pipeline| {
+                    let name = &pipeline.pipeline.as_ref().expect("pipelines have names").name;
+                    job.pipeline() == name
+                        && (request.history < 0
+                            || job.info.pipeline_version + request.history as u64 >= pipeline.version)
+                })
+            })
+            .filter(|job| input_commits.iter().all(|commit| job.inputs.contains(commit)))
+            .filter(|job| output_commit.is_none() || job.info.output_commit == output_commit)
+            .map(|job| job.info.clone())
+            .collect())
+    }
+
+    /// Removes a pipeline, its jobs, and, unless it's kept, its output repo.
+    fn delete_pipeline(&mut self, pfs: &mut pfs::State, name: &str, keep_repo: bool) {
+        self.pipelines.remove(name);
+        self.jobs.retain(|job| job.pipeline() != name);
+        if !keep_repo {
+            pfs.delete_repo(name);
+        }
+    }
+}
+
+impl JobData {
+    fn id(&self) -> &str {
+        &self.info.job.as_ref().expect("jobs have IDs").id
+    }
+
+    fn pipeline(&self) -> &str {
+        &self.info.pipeline.as_ref().expect("jobs have pipelines").name
+    }
+
+    fn is_finished(&self) -> bool {
+        is_finished(self.info.state)
+    }
+
+    fn set_state(&mut self, state: JobState, pfs: &mut pfs::State) {
+        self.info.state = state as i32;
+        if self.is_finished() {
+            self.info.finished = Some(SystemTime::now().into());
+            if let Some(commit) = &self.info.output_commit {
+                // The output commit may have been deleted out from under the
+                // job, which pachd also tolerates
+                let _ = pfs.finish_output_commit(commit);
+            }
+        } else {
+            self.info.finished = None;
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Api for FakePps {
+    async fn create_job(&self, _: Request<pps::CreateJobRequest>) -> Result<Response<Job>, Status> {
+        Err(unimplemented("CreateJob"))
+    }
+
+    async fn inspect_job(&self, request: Request<pps::InspectJobRequest>) -> Result<Response<JobInfo>, Status> {
+        let request = request.into_inner();
+        let info = self
+            .wait_for(|pps, pfs| {
+                let job = match &request.output_commit {
+                    Some(commit) if request.job.is_none() => {
+                        let commit = pfs.resolve_commit(commit)?;
+                        pps.jobs
+                            .iter()
+                            .find(|job| job.info.output_commit.as_ref() == Some(&commit))
+                            .ok_or_else(|| Status::unknown(format!("job with output commit {} not found", commit.id)))?
+                    }
+                    _ => pps.job(&request.job)?,
+                };
+                Ok(Some(job.info.clone()).filter(|info| !request.block_state || is_finished(info.state)))
+            })
+            .await?;
+        Ok(Response::new(info))
+    }
+
+    async fn list_job(&self, request: Request<pps::ListJobRequest>) -> Result<Response<pps::JobInfos>, Status> {
+        let job_info = self.read(|pps, pfs| pps.list_job(pfs, request.into_inner()))?;
+        Ok(Response::new(pps::JobInfos { job_info }))
+    }
+
+    type ListJobStreamStream = ResponseStream<JobInfo>;
+
+    async fn list_job_stream(
+        &self,
+        request: Request<pps::ListJobRequest>,
+    ) -> Result<Response<Self::ListJobStreamStream>, Status> {
+        let infos = self.read(|pps, pfs| pps.list_job(pfs, request.into_inner()))?;
+        Ok(Response::new(Box::pin(stream::iter(infos.into_iter().map(Ok)))))
+    }
+
+    type FlushJobStream = ResponseStream<JobInfo>;
+
+    async fn flush_job(
+        &self,
+        request: Request<pps::FlushJobRequest>,
+    ) -> Result<Response<Self::FlushJobStream>, Status> {
+        let request = request.into_inner();
+        if request.commits.is_empty() {
+            return Err(missing("commits"));
+        }
+        let commits = self.read(|_, pfs| {
+            request
+                .commits
+                .iter()
+                .map(|commit| pfs.resolve_commit(commit))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        let to_pipelines: Vec<_> = request.to_pipelines.into_iter().map(|pipeline| pipeline.name).collect();
+        for pipeline in &to_pipelines {
+            self.read(|pps, _| pps.pipeline(pipeline).map(|_| ()))?;
+        }
+
+        let flush = Flush {
+            pps: self.clone(),
+            commits,
+            to_pipelines,
+            sent: Vec::new(),
+        };
+        Ok(Response::new(Box::pin(stream::unfold(
+            Some(flush),
+            |flush| async move {
+                let mut flush = flush?;
+                match flush.next().await {
+                    Ok(Some(info)) => Some((Ok(info), Some(flush))),
+                    Ok(None) => None,
+                    Err(err) => Some((Err(err), None)),
+                }
+            },
+        ))))
+    }
+
+    async fn delete_job(&self, request: Request<pps::DeleteJobRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.update(move |pps, _| {
+            let id = pps.job(&request.job)?.id().to_string();
+            pps.jobs.retain(|job| job.id() != id);
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn stop_job(&self, request: Request<pps::StopJobRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.update(move |pps, pfs| {
+            let id = pps.job(&request.job)?.id().to_string();
+            let job = pps.job_mut(&id)?;
+            if !job.is_finished() {
+                job.set_state(JobState::JobKilled, pfs);
+            }
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn inspect_datum(&self, request: Request<pps::InspectDatumRequest>) -> Result<Response<DatumInfo>, Status> {
+        let datum = request.into_inner().datum.ok_or_else(|| missing("datum"))?;
+        let info = self.read(|pps, _| {
+            pps.job(&datum.job)?
+                .datums
+                .iter()
+                .find(|info| info.datum.as_ref().is_some_and(|other| other.id == datum.id))
+                .cloned()
+                .ok_or_else(|| Status::unknown(format!("datum {} not found", datum.id)))
+        })?;
+        Ok(Response::new(info))
+    }
+
+    async fn list_datum(
+        &self,
+        request: Request<pps::ListDatumRequest>,
+    ) -> Result<Response<pps::ListDatumResponse>, Status> {
+        let request = request.into_inner();
+        let datums = self.read(|pps, _| Ok(pps.job(&request.job)?.datums.clone()))?;
+        let (datum_infos, total_pages) = page(datums, request.page_size, request.page);
+        Ok(Response::new(pps::ListDatumResponse {
+            datum_infos,
+            total_pages,
+            page: request.page,
+        }))
+    }
+
+    type ListDatumStreamStream = ResponseStream<pps::ListDatumStreamResponse>;
+
+    async fn list_datum_stream(
+        &self,
+        request: Request<pps::ListDatumRequest>,
+    ) -> Result<Response<Self::ListDatumStreamStream>, Status> {
+        let request = request.into_inner();
+        let datums = self.read(|pps, _| Ok(pps.job(&request.job)?.datums.clone()))?;
+        let (datums, total_pages) = page(datums, request.page_size, request.page);
+        let responses = datums.into_iter().map(move |datum_info| {
+            Ok(pps::ListDatumStreamResponse {
+                datum_info: Some(datum_info),
+                total_pages,
+                page: request.page,
+            })
+        });
+        Ok(Response::new(Box::pin(stream::iter(responses))))
+    }
+
+    async fn restart_datum(&self, _: Request<pps::RestartDatumRequest>) -> Result<Response<()>, Status> {
+        Err(unimplemented("RestartDatum"))
+    }
+
+    async fn create_pipeline(&self, request: Request<pps::CreatePipelineRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        let name = request
+            .pipeline
+            .as_ref()
+            .ok_or_else(|| missing("pipeline"))?
+            .name
+            .clone();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(Status::unknown(format!(
+                "pipeline name ({}) invalid: only alphanumeric characters, underscores, and dashes are allowed",
+                name
+            )));
+        }
+        let output_branch = Some(request.output_branch.as_str())
+            .filter(|branch| !branch.is_empty())
+            .unwrap_or("master")
+            .to_string();
+
+        self.update(move |pps, pfs| {
+            let previous = pps.pipelines.get(&name).and_then(|versions| versions.last());
+            if previous.is_some() && !request.update {
+                return Err(Status::unknown(format!("pipeline {} already exists", name)));
+            }
+            let branch = Branch {
+                repo: Some(Repo { name: name.clone() }),
+                name: output_branch.clone(),
+            };
+            pfs.create_output_branch(&branch, input_branches(&request.input), &request.description)?;
+
+            let info = PipelineInfo {
+                id: format!("{:032x}", rand::random::<u128>()),
+                pipeline: request.pipeline,
+                version: previous.map_or(1, |previous| previous.version + 1),
+                transform: request.transform,
+                tf_job: request.tf_job,
+                parallelism_spec: request.parallelism_spec,
+                hashtree_spec: request.hashtree_spec,
+                egress: request.egress,
+                created_at: Some(SystemTime::now().into()),
+                state: previous.map_or(PipelineState::PipelineRunning as i32, |previous| previous.state),
+                stopped: previous.is_some_and(|previous| previous.stopped),
+                output_branch,
+                resource_requests: request.resource_requests,
+                resource_limits: request.resource_limits,
+                sidecar_resource_limits: request.sidecar_resource_limits,
+                input: request.input,
+                description: request.description,
+                cache_size: request.cache_size,
+                enable_stats: request.enable_stats,
+                salt: request.salt,
+                max_queue_size: request.max_queue_size,
+                service: request.service,
+                spout: request.spout,
+                chunk_spec: request.chunk_spec,
+                datum_timeout: request.datum_timeout,
+                job_timeout: request.job_timeout,
+                spec_commit: request.spec_commit,
+                standby: request.standby,
+                datum_tries: request.datum_tries,
+                scheduling_spec: request.scheduling_spec,
+                pod_spec: request.pod_spec,
+                pod_patch: request.pod_patch,
+                metadata: request.metadata,
+                ..Default::default()
+            };
+            pps.pipelines.entry(name).or_default().push(info);
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn inspect_pipeline(
+        &self,
+        request: Request<pps::InspectPipelineRequest>,
+    ) -> Result<Response<PipelineInfo>, Status> {
+        let pipeline = request.into_inner().pipeline.ok_or_else(|| missing("pipeline"))?;
+        let info = self.read(|pps, _| {
+            pps.pipeline(&pipeline.name)?;
+            Ok(pps.pipeline_info(&pipeline.name))
+        })?;
+        Ok(Response::new(info))
+    }
+
+    async fn list_pipeline(
+        &self,
+        request: Request<pps::ListPipelineRequest>,
+    ) -> Result<Response<pps::PipelineInfos>, Status> {
+        let request = request.into_inner();
+        let pipeline_info = self.read(|pps, _| {
+            let names: Vec<&String> = match &request.pipeline {
+                Some(pipeline) => {
+                    pps.pipeline(&pipeline.name)?;
+                    vec![&pipeline.name]
+                }
+                None => pps.pipelines.keys().collect(),
+            };
+            let mut infos = Vec::new();
+            for name in names {
+                // Versions are listed newest first, with `history` giving
+                // how many previous ones to include, or -1 for all of them
+                let versions = pps.pipelines[name].iter().rev();
+                let count = if request.history < 0 {
+                    usize::MAX
+                } else {
+                    request.history as usize + 1
+                };
+                infos.extend(versions.take(count).cloned().map(|mut info| {
+                    pps.count_jobs(&mut info);
+                    info
+                }));
+            }
+            Ok(infos)
+        })?;
+        Ok(Response::new(pps::PipelineInfos { pipeline_info }))
+    }
+
+    async fn delete_pipeline(&self, request: Request<pps::DeletePipelineRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.update(move |pps, pfs| {
+            if request.all {
+                let names: Vec<_> = pps.pipelines.keys().cloned().collect();
+                for name in names {
+                    pps.delete_pipeline(pfs, &name, request.keep_repo);
+                }
+            } else {
+                let name = request.pipeline.ok_or_else(|| missing("pipeline"))?.name;
+                pps.pipeline(&name)?;
+                pps.delete_pipeline(pfs, &name, request.keep_repo);
+            }
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn start_pipeline(&self, request: Request<pps::StartPipelineRequest>) -> Result<Response<()>, Status> {
+        let pipeline = request.into_inner().pipeline.ok_or_else(|| missing("pipeline"))?;
+        self.update(move |pps, _| {
+            let info = pps.pipeline_mut(&pipeline.name)?;
+            info.stopped = false;
+            info.state = PipelineState::PipelineRunning as i32;
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn stop_pipeline(&self, request: Request<pps::StopPipelineRequest>) -> Result<Response<()>, Status> {
+        let pipeline = request.into_inner().pipeline.ok_or_else(|| missing("pipeline"))?;
+        self.update(move |pps, _| {
+            let info = pps.pipeline_mut(&pipeline.name)?;
+            info.stopped = true;
+            info.state = PipelineState::PipelinePaused as i32;
+            Ok(Response::new(()))
+        })
+    }
+
+    async fn run_pipeline(&self, _: Request<pps::RunPipelineRequest>) -> Result<Response<()>, Status> {
+        Err(unimplemented("RunPipeline"))
+    }
+
+    async fn run_cron(&self, _: Request<pps::RunCronRequest>) -> Result<Response<()>, Status> {
+        Err(unimplemented("RunCron"))
+    }
+
+    async fn create_secret(&self, _: Request<pps::CreateSecretRequest>) -> Result<Response<()>, Status> {
+        Err(unimplemented("CreateSecret"))
+    }
+
+    async fn delete_secret(&self, _: Request<pps::DeleteSecretRequest>) -> Result<Response<()>, Status> {
+        Err(unimplemented("DeleteSecret"))
+    }
+
+    async fn list_secret(&self, _: Request<()>) -> Result<Response<pps::SecretInfos>, Status> {
+        Err(unimplemented("ListSecret"))
+    }
+
+    async fn inspect_secret(&self, _: Request<pps::InspectSecretRequest>) -> Result<Response<pps::SecretInfo>, Status> {
+        Err(unimplemented("InspectSecret"))
+    }
+
+    async fn delete_all(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        self.update(|pps, pfs| {
+            let names: Vec<_> = pps.pipelines.keys().cloned().collect();
+            for name in names {
+                pps.delete_pipeline(pfs, &name, false);
+            }
+            pps.logs.clear();
+            Ok(Response::new(()))
+        })
+    }
+
+    type GetLogsStream = ResponseStream<LogMessage>;
+
+    async fn get_logs(&self, request: Request<pps::GetLogsRequest>) -> Result<Response<Self::GetLogsStream>, Status> {
+        let request = request.into_inner();
+        let (mut logs, next) = self.read(|pps, _| {
+            if let Some(pipeline) = &request.pipeline {
+                pps.pipeline(&pipeline.name)?;
+            }
+            if request.job.is_some() {
+                pps.job(&request.job)?;
+            }
+            let logs: Vec<_> = pps
+                .logs
+                .iter()
+                .filter(|log| log_matches(&request, log))
+                .cloned()
+                .collect();
+            Ok((logs, pps.logs.len()))
+        })?;
+        if request.tail > 0 {
+            logs.drain(..logs.len().saturating_sub(request.tail as usize));
+        }
+        let logs = stream::iter(logs.into_iter().map(Ok));
+        if !request.follow {
+            return Ok(Response::new(Box::pin(logs)));
+        }
+
+        let follow = Follow {
+            pps: self.clone(),
+            request,
+            next,
+        };
+        let followed = stream::unfold(Some(follow), |follow| async move {
+            let mut follow = follow?;
+            match follow.next().await {
+                Ok(Some(logs)) => Some((Ok(logs), Some(follow))),
+                Ok(None) => None,
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+        .flat_map(|logs: Result<Vec<LogMessage>, Status>| match logs {
+            Ok(logs) => stream::iter(logs.into_iter().map(Ok).collect::<Vec<_>>()),
+            Err(err) => stream::iter(vec![Err(err)]),
+        });
+        Ok(Response::new(Box::pin(logs.chain(followed))))
+    }
+
+    async fn garbage_collect(
+        &self,
+        _: Request<pps::GarbageCollectRequest>,
+    ) -> Result<Response<pps::GarbageCollectResponse>, Status> {
+        Err(unimplemented("GarbageCollect"))
+    }
+
+    async fn activate_auth(
+        &self,
+        _: Request<pps::ActivateAuthRequest>,
+    ) -> Result<Response<pps::ActivateAuthResponse>, Status> {
+        Err(unimplemented("ActivateAuth"))
+    }
+
+    async fn update_job_state(&self, request: Request<pps::UpdateJobStateRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        let state = JobState::from_i32(request.state).ok_or_else(|| Status::invalid_argument("invalid job state"))?;
+        self.update(move |pps, pfs| {
+            let id = pps.job(&request.job)?.id().to_string();
+            let job = pps.job_mut(&id)?;
+            job.info.reason = request.reason;
+            job.info.restart = request.restart;
+            job.info.data_processed = request.data_processed;
+            job.info.data_skipped = request.data_skipped;
+            job.info.data_failed = request.data_failed;
+            job.info.data_recovered = request.data_recovered;
+            job.info.data_total = request.data_total;
+            job.info.stats = request.stats;
+            job.set_state(state, pfs);
+            Ok(Response::new(()))
+        })
+    }
+}
+
+/// A `FlushJob` call, which streams the jobs downstream of some commits as
+/// they finish.
+struct Flush {
+    pps: FakePps,
+    commits: Vec<Commit>,
+    /// The pipelines to wait for, or all of those downstream if empty
+    to_pipelines: Vec<String>,
+    /// The IDs of the jobs already sent
+    sent: Vec<String>,
+}
+
+impl Flush {
+    /// Waits for the next downstream job to finish, or returns `None` once
+    /// every downstream pipeline's job for the commits has been sent.
+    async fn next(&mut self) -> Result<Option<JobInfo>, Status> {
+        let (commits, to_pipelines, sent) = (&self.commits, &self.to_pipelines, &self.sent);
+        let next = self
+            .pps
+            .wait_for(|pps, _| {
+                // Jobs are created after those whose output commits they
+                // read, so one pass finds everything downstream
+                let mut commits = commits.clone();
+                let mut repos: Vec<_> = commits.iter().filter_map(|commit| commit.repo.clone()).collect();
+                let mut jobs = Vec::new();
+                for job in &pps.jobs {
+                    if job.inputs.iter().any(|input| commits.contains(input)) {
+                        commits.extend(job.info.output_commit.clone());
+                        jobs.push(job);
+                    }
+                }
+                for (name, versions) in &pps.pipelines {
+                    let branches = input_branches(&versions.last().expect("pipelines have a version").input);
+                    if branches
+                        .iter()
+                        .any(|branch| branch.repo.as_ref().is_some_and(|repo| repos.contains(repo)))
+                    {
+                        repos.push(Repo { name: name.clone() });
+                    }
+                }
+                let wanted = |name: &str| {
+                    if to_pipelines.is_empty() {
+                        repos.iter().any(|repo| repo.name == name)
+                    } else {
+                        to_pipelines.iter().any(|pipeline| pipeline == name)
+                    }
+                };
+
+                let jobs: Vec<_> = jobs.into_iter().filter(|job| wanted(job.pipeline())).collect();
+                if let Some(job) = jobs
+                    .iter()
+                    .find(|job| job.is_finished() && !sent.iter().any(|id| id == job.id()))
+                {
+                    return Ok(Some(Some(job.info.clone())));
+                }
+                let done = pps
+                    .pipelines
+                    .keys()
+                    .filter(|name| wanted(name))
+                    .all(|name| jobs.iter().any(|job| job.pipeline() == name))
+                    && jobs.iter().all(|job| job.is_finished());
+                Ok(if done { Some(None) } else { None })
+            })
+            .await?;
+        if let Some(info) = &next {
+            self.sent.push(info.job.as_ref().expect("jobs have IDs").id.clone());
+        }
+        Ok(next)
+    }
+}
+
+/// A followed `GetLogs` call, which streams logs as they're added.
+struct Follow {
+    pps: FakePps,
+    request: pps::GetLogsRequest,
+    /// The index of the first log not yet looked at
+    next: usize,
+}
+
+impl Follow {
+    /// Waits for more matching logs, or returns `None` once the job the
+    /// logs are for is finished.
+    async fn next(&mut self) -> Result<Option<Vec<LogMessage>>, Status> {
+        let (request, next) = (&self.request, self.next);
+        let (logs, len) = self
+            .pps
+            .wait_for(|pps, _| {
+                // Logs are only ever appended, except by `DeleteAll`
+                let next = next.min(pps.logs.len());
+                let logs: Vec<_> = pps.logs[next..]
+                    .iter()
+                    .filter(|log| log_matches(request, log))
+                    .cloned()
+                    .collect();
+                let done = request.job.is_some() && pps.job(&request.job).map_or(true, |job| job.is_finished());
+                Ok(Some((logs, pps.logs.len())).filter(|(logs, _)| !logs.is_empty() || done))
+            })
+            .await?;
+        self.next = len;
+        Ok(Some(logs).filter(|logs| !logs.is_empty()))
+    }
+}
+
+/// Whether a log line passes a `GetLogs` call's filters.
+fn log_matches(request: &pps::GetLogsRequest, log: &LogMessage) -> bool {
+    request
+        .pipeline
+        .as_ref()
+        .is_none_or(|pipeline| pipeline.name == log.pipeline_name)
+        && request.job.as_ref().is_none_or(|job| job.id == log.job_id)
+        && request.datum.as_ref().is_none_or(|datum| datum.id == log.datum_id)
+        && request.master == log.master
+        && request.data_filters.iter().all(|filter| {
+            // Input files can be picked out by path, or by hash in hex or
+            // base64
+            log.data.iter().any(|file| {
+                let hex: String = file.hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+                file.path == *filter || hex == *filter || base64::encode(&file.hash) == *filter
+            })
+        })
+}
+
+/// Gets the branches a pipeline's PFS inputs read from.
+fn input_branches(input: &Option<Input>) -> Vec<Branch> {
+    fn walk(input: &Input, branches: &mut Vec<Branch>) {
+        if let Some(pfs) = &input.pfs {
+            let branch = Branch {
+                repo: Some(Repo { name: pfs.repo.clone() }),
+                name: if pfs.branch.is_empty() {
+                    "master".into()
+                } else {
+                    pfs.branch.clone()
+                },
+            };
+            if !branches.contains(&branch) {
+                branches.push(branch);
+            }
+        }
+        for input in input.join.iter().chain(&input.cross).chain(&input.union) {
+            walk(input, branches);
+        }
+    }
+
+    let mut branches = Vec::new();
+    if let Some(input) = input {
+        walk(input, &mut branches);
+    }
+    branches
+}
+
+/// Fills in the commits a job's PFS inputs read from.
+fn with_commits(input: &Input, commits: &[Commit]) -> Input {
+    let mut input = input.clone();
+    if let Some(pfs) = &mut input.pfs {
+        if let Some(commit) = commits
+            .iter()
+            .find(|commit| commit.repo.as_ref().is_some_and(|repo| repo.name == pfs.repo))
+        {
+            pfs.commit = commit.id.clone();
+        }
+    }
+    for nested in input.join.iter_mut().chain(&mut input.cross).chain(&mut input.union) {
+        *nested = with_commits(nested, commits);
+    }
+    input
+}
+
+/// Gets a page of datums, and the number of pages, as `ListDatum` does. A
+/// page size of zero gets every datum.
+fn page(datums: Vec<DatumInfo>, page_size: i64, page: i64) -> (Vec<DatumInfo>, i64) {
+    if page_size <= 0 {
+        return (datums, 0);
+    }
+    let total_pages = (datums.len() as i64 + page_size - 1) / page_size;
+    let page = datums
+        .into_iter()
+        .skip((page.max(0) * page_size) as usize)
+        .take(page_size as usize)
+        .collect();
+    (page, total_pages)
+}
+
+fn count_datums(datums: &[DatumInfo], state: DatumState) -> i64 {
+    datums.iter().filter(|datum| datum.state == state as i32).count() as i64
+}
+
+fn is_finished(state: i32) -> bool {
+    matches!(
+        JobState::from_i32(state),
+        Some(JobState::JobSuccess) | Some(JobState::JobFailure) | Some(JobState::JobKilled)
+    )
+}
+
+fn pipeline_not_found(name: &str) -> Status {
+    Status::unknown(format!("pipeline {} not found", name))
+}
+
+fn job_not_found(id: &str) -> Status {
+    Status::unknown(format!("job {} not found", id))
+}
@@ -0,0 +1,109 @@
+use std::io::Cursor;
+
+use rustls::internal::pemfile;
+use rustls::{ClientConfig, PrivateKey};
+use tonic::transport::ClientTlsConfig;
+
+use crate::Error;
+
+/// TLS settings for connecting to pachd.
+///
+/// By default, the system's root certificates are trusted. When connecting
+/// with a pachctl context that has `server_cas` set, those certificates are
+/// trusted instead, unless others were set explicitly here.
+#[derive(Clone, Debug, Default)]
+pub struct TlsConfig {
+    ca_certificates: Option<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+    domain_name: Option<String>,
+}
+
+impl TlsConfig {
+    /// Creates a TLS config with default settings.
+    pub fn new() -> Self {
+        TlsConfig::default()
+    }
+
+    /// Trusts the given PEM-encoded root certificates, instead of the
+    /// system's.
+    pub fn ca_certificates<P: Into<Vec<u8>>>(mut self, pem: P) -> Self {
+        self.ca_certificates = Some(pem.into());
+        self
+    }
+
+    /// Presents a client certificate to pachd. `cert` is the PEM-encoded
+    /// certificate chain, and `key` is the PEM-encoded PKCS8 or RSA private
+    /// key.
+    pub fn identity<C: Into<Vec<u8>>, K: Into<Vec<u8>>>(mut self, cert: C, key: K) -> Self {
+        self.identity = Some((cert.into(), key.into()));
+        self
+    }
+
+    /// Sets the domain name to verify pachd's certificate against. Defaults
+    /// to the host pachd is connected to.
+    pub fn domain_name<S: Into<String>>(mut self, domain_name: S) -> Self {
+        self.domain_name = Some(domain_name.into());
+        self
+    }
+
+    pub(crate) fn has_ca_certificates(&self) -> bool {
+        self.ca_certificates.is_some()
+    }
+
+    /// Builds the equivalent tonic TLS config.
+    pub(crate) fn to_tonic(&self) -> Result<ClientTlsConfig, Error> {
+        let mut config = ClientConfig::new();
+        // gRPC requires HTTP/2
+        config.set_protocols(&[b"h2".to_vec()]);
+
+        match &self.ca_certificates {
+            Some(pem) => {
+                let (valid, _) = config
+                    .root_store
+                    .add_pem_file(&mut Cursor::new(pem))
+                    .map_err(|_| Error::Tls("could not parse the CA certificates".into()))?;
+                if valid == 0 {
+                    return Err(Error::Tls("no valid CA certificates were provided".into()));
+                }
+            }
+            None => {
+                config.root_store = match rustls_native_certs::load_native_certs() {
+                    Ok(store) | Err((Some(store), _)) => store,
+                    Err((None, err)) => {
+                        return Err(Error::Tls(format!(
+                            "could not load the system's root certificates: {}",
+                            err
+                        )))
+                    }
+                };
+            }
+        }
+
+        if let Some((cert, key)) = &self.identity {
+            let certs = pemfile::certs(&mut Cursor::new(cert))
+                .map_err(|_| Error::Tls("could not parse the client certificate".into()))?;
+            let key = private_key(key)?;
+            config
+                .set_single_client_cert(certs, key)
+                .map_err(|err| Error::Tls(format!("invalid client certificate: {}", err)))?;
+        }
+
+        let mut tls = ClientTlsConfig::new().rustls_client_config(config);
+        if let Some(domain_name) = &self.domain_name {
+            tls = tls.domain_name(domain_name.clone());
+        }
+        Ok(tls)
+    }
+}
+
+/// Parses the first PKCS8 or RSA private key in the given PEM.
+fn private_key(pem: &[u8]) -> Result<PrivateKey, Error> {
+    let parse_err = || Error::Tls("could not parse the client private key".into());
+    let mut keys = pemfile::pkcs8_private_keys(&mut Cursor::new(pem)).map_err(|_| parse_err())?;
+    if keys.is_empty() {
+        keys = pemfile::rsa_private_keys(&mut Cursor::new(pem)).map_err(|_| parse_err())?;
+    }
+    keys.into_iter()
+        .next()
+        .ok_or_else(|| Error::Tls("no PKCS8 or RSA private key was found".into()))
+}
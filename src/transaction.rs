@@ -0,0 +1,670 @@
+//! pachd's transaction API, as well as a client that adds calls to a
+//! transaction instead of running them immediately.
+
+use std::convert::TryFrom;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::task::{Context, Poll};
+
+use futures::future::{self, Either, FutureExt, Ready};
+use log::warn;
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
+use tonic::codegen::http::header::{HeaderValue, CONTENT_TYPE};
+use tonic::codegen::http::{Request as HttpRequest, Response as HttpResponse};
+use tonic::metadata::MetadataValue;
+use tonic::transport::channel::ResponseFuture;
+use tonic::transport::{Body, Channel, Error as TransportError};
+use tonic::{Code, Status};
+
+use crate::{pfs, pps, Client, Error};
+
+include_proto!("transaction");
+
+/// The metadata key pachd reads the transaction to add a request to from.
+pub(crate) const TRANSACTION_KEY: &str = "pach-transaction";
+
+/// The calls that can be added to a transaction, as listed in
+/// `TransactionRequest`.
+const TRANSACTIONAL_PATHS: &[&str] = &[
+    "/pfs.API/CreateRepo",
+    "/pfs.API/DeleteRepo",
+    "/pfs.API/StartCommit",
+    "/pfs.API/FinishCommit",
+    "/pfs.API/DeleteCommit",
+    "/pfs.API/CreateBranch",
+    "/pfs.API/DeleteBranch",
+    "/pps.API/UpdateJobState",
+    "/transaction.API/DeleteAll",
+];
+
+/// Defines `Operation`, with a variant for each of the fields of
+/// `TransactionRequest`, and conversions between the two.
+macro_rules! operations {
+    ($($variant:ident($field:ident: $request:ty),)*) => {
+        /// A single operation in a transaction. Unlike a `TransactionRequest`,
+        /// which has a field for each operation, exactly one of which should be
+        /// set, an `Operation` is always valid.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Operation {
+            $($variant($request),)*
+        }
+
+        impl Operation {
+            /// Gets the name of the operation's field in `TransactionRequest`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Operation::$variant(_) => stringify!($field),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$request> for Operation {
+                fn from(request: $request) -> Self {
+                    Operation::$variant(request)
+                }
+            }
+        )*
+
+        impl From<Operation> for TransactionRequest {
+            fn from(operation: Operation) -> Self {
+                let mut request = TransactionRequest::default();
+                match operation {
+                    $(Operation::$variant(operation) => request.$field = Some(operation),)*
+                }
+                request
+            }
+        }
+
+        impl TryFrom<TransactionRequest> for Operation {
+            type Error = Error;
+
+            /// Fails unless exactly one operation is set.
+            fn try_from(request: TransactionRequest) -> Result<Self, Self::Error> {
+                let mut operations = Vec::new();
+                $(
+                    if let Some(operation) = request.$field {
+                        operations.push(Operation::$variant(operation));
+                    }
+                )*
+
+                match operations.len() {
+                    0 => Err(Error::InvalidTransactionRequest("no operation is set".into())),
+                    1 => Ok(operations.pop().expect("operations has one element")),
+                    _ => {
+                        let names: Vec<&str> = operations.iter().map(Operation::name).collect();
+                        Err(Error::InvalidTransactionRequest(format!(
+                            "exactly one operation must be set, but {} are: {}",
+                            names.len(),
+                            names.join(", ")
+                        )))
+                    }
+                }
+            }
+        }
+    };
+}
+
+operations! {
+    CreateRepo(create_repo: pfs::CreateRepoRequest),
+    DeleteRepo(delete_repo: pfs::DeleteRepoRequest),
+    StartCommit(start_commit: pfs::StartCommitRequest),
+    FinishCommit(finish_commit: pfs::FinishCommitRequest),
+    DeleteCommit(delete_commit: pfs::DeleteCommitRequest),
+    CreateBranch(create_branch: pfs::CreateBranchRequest),
+    DeleteBranch(delete_branch: pfs::DeleteBranchRequest),
+    UpdateJobState(update_job_state: pps::UpdateJobStateRequest),
+    DeleteAll(delete_all: DeleteAllRequest),
+}
+
+/// Builds a `BatchTransactionRequest`, whose operations are all run in a
+/// single transaction. Run it via `Client::batch_transaction`, e.g.:
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+/// use pachyderm::pfs::{Branch, Commit, CreateBranchRequest, Repo, StartCommitRequest};
+/// use pachyderm::transaction::{BatchTransaction, Operation};
+///
+/// let repo = Repo { name: "images".into() };
+/// let batch = BatchTransaction::new()
+///     .operation(CreateBranchRequest {
+///         branch: Some(Branch { repo: Some(repo.clone()), name: "staging".into() }),
+///         ..Default::default()
+///     })
+///     .operation(StartCommitRequest {
+///         parent: Some(Commit { repo: Some(repo), id: "".into() }),
+///         branch: "staging".into(),
+///         ..Default::default()
+///     });
+///
+/// for (operation, response) in client.batch_transaction(batch).await? {
+///     if let Operation::StartCommit(_) = operation {
+///         println!("started {:?}", response.commit);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchTransaction {
+    operations: Vec<Operation>,
+}
+
+impl BatchTransaction {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        BatchTransaction::default()
+    }
+
+    /// Adds an operation to the batch. This takes either an `Operation`, or
+    /// any of the requests it wraps, e.g. a `pfs::CreateRepoRequest`.
+    pub fn operation<O: Into<Operation>>(mut self, operation: O) -> Self {
+        self.operations.push(operation.into());
+        self
+    }
+
+    /// Gets the operations in the batch, in the order they will be run.
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    /// Consumes the batch, returning its operations.
+    pub fn into_operations(self) -> Vec<Operation> {
+        self.operations
+    }
+}
+
+impl From<BatchTransaction> for BatchTransactionRequest {
+    fn from(batch: BatchTransaction) -> Self {
+        BatchTransactionRequest {
+            requests: batch.operations.into_iter().map(TransactionRequest::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<BatchTransactionRequest> for BatchTransaction {
+    type Error = Error;
+
+    /// Fails unless each request sets exactly one operation.
+    fn try_from(request: BatchTransactionRequest) -> Result<Self, Self::Error> {
+        let operations = request
+            .requests
+            .into_iter()
+            .map(Operation::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(BatchTransaction { operations })
+    }
+}
+
+impl Client {
+    /// Creates a client that adds calls to the given transaction, rather
+    /// than running them immediately. It shares this client's connection
+    /// and options, including its auth token.
+    pub fn with_transaction(&self, transaction: &Transaction) -> Result<TransactionClient, Error> {
+        let id = MetadataValue::from_str(&transaction.id)
+            .map_err(|_| Error::InvalidMetadata("the transaction ID".into()))?;
+        let client = self.with_metadata(TRANSACTION_KEY, id);
+        Ok(TransactionClient::new(client, transaction.clone()))
+    }
+
+    /// Runs `f` in a new transaction. If `f` succeeds, the transaction is
+    /// finished, and the responses to the calls it added are returned
+    /// alongside its result, in the order the calls were made. If `f` fails
+    /// or panics, or the transaction can't be finished, the transaction is
+    /// deleted instead, so none of its calls take effect.
+    ///
+    /// For instance, to atomically create a branch and start a commit on it,
+    /// then get the new commit:
+    ///
+    /// ```no_run
+    /// # async fn run(client: pachyderm::Client) -> Result<(), pachyderm::Error> {
+    /// use pachyderm::pfs::{Branch, CreateBranchRequest, Repo, StartCommitRequest};
+    ///
+    /// let repo = Repo { name: "images".into() };
+    /// let (_, responses) = client
+    ///     .run_transaction(|tx| async move {
+    ///         let branch = CreateBranchRequest {
+    ///             branch: Some(Branch { repo: Some(repo.clone()), name: "staging".into() }),
+    ///             ..Default::default()
+    ///         };
+    ///         tx.pfs().create_branch(branch).await?;
+    ///         let commit = StartCommitRequest {
+    ///             parent: Some(pachyderm::pfs::Commit { repo: Some(repo), id: "".into() }),
+    ///             branch: "staging".into(),
+    ///             ..Default::default()
+    ///         };
+    ///         tx.pfs().start_commit(commit).await?;
+    ///         Ok(())
+    ///     })
+    ///     .await?;
+    /// let commit = responses[1].commit.clone();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_transaction<F, Fut, T>(&self, f: F) -> Result<(T, Vec<TransactionResponse>), Error>
+    where
+        F: FnOnce(TransactionClient) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let transaction = self
+            .transaction()
+            .start_transaction(StartTransactionRequest {})
+            .await?
+            .into_inner();
+
+        let client = self.with_transaction(&transaction);
+        let result = AssertUnwindSafe(async move { f(client?).await }).catch_unwind().await;
+        match result {
+            Ok(Ok(value)) => {
+                let request = FinishTransactionRequest {
+                    transaction: Some(transaction.clone()),
+                };
+                match self.transaction().finish_transaction(request).await {
+                    Ok(info) => Ok((value, info.into_inner().responses)),
+                    Err(err) => {
+                        // Otherwise the transaction is left open, and would
+                        // run if someone else finished it
+                        self.delete_transaction(transaction).await;
+                        Err(err.into())
+                    }
+                }
+            }
+            Ok(Err(err)) => {
+                self.delete_transaction(transaction).await;
+                Err(err)
+            }
+            Err(panic) => {
+                self.delete_transaction(transaction).await;
+                panic::resume_unwind(panic)
+            }
+        }
+    }
+
+    /// Runs a batch of operations in a single transaction, returning each
+    /// operation along with pachd's response to it, e.g. the commit created
+    /// by an `Operation::StartCommit`. Fails if pachd doesn't respond to
+    /// every operation.
+    pub async fn batch_transaction(
+        &self,
+        batch: BatchTransaction,
+    ) -> Result<Vec<(Operation, TransactionResponse)>, Error> {
+        let operations = batch.clone().into_operations();
+        let info = self
+            .transaction()
+            .batch_transaction(BatchTransactionRequest::from(batch))
+            .await?
+            .into_inner();
+
+        // Pairing up anything but one response per operation would hand
+        // operations responses that aren't theirs
+        if info.responses.len() != operations.len() {
+            return Err(Error::Other(Status::internal(format!(
+                "pachd returned {} responses to a batch of {} operations",
+                info.responses.len(),
+                operations.len()
+            ))));
+        }
+        Ok(operations.into_iter().zip(info.responses).collect())
+    }
+
+    /// Deletes a transaction that is being rolled back. Failures are only
+    /// logged, since the caller is more interested in why it was rolled back.
+    async fn delete_transaction(&self, transaction: Transaction) {
+        let id = transaction.id.clone();
+        let request = DeleteTransactionRequest {
+            transaction: Some(transaction),
+        };
+        if let Err(err) = self.transaction().delete_transaction(request).await {
+            warn!("could not delete transaction {}: {}", id, Error::from(err));
+        }
+    }
+}
+
+/// A client that adds calls to a transaction, rather than running them
+/// immediately. Create one via `Client::with_transaction`.
+///
+/// As with pachctl, each request carries the transaction's ID, and pachd
+/// adds it to the transaction rather than running it. The calls this works
+/// for are the ones listed in `TransactionRequest`. Any other call fails
+/// with `FailedPrecondition` before it is sent, since pachd would otherwise
+/// run it immediately, outside of the transaction.
+///
+/// For instance, to add calls to pachctl's active transaction:
+///
+/// ```no_run
+/// # async fn run(client: pachyderm::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// use pachyderm::config::Config;
+/// use pachyderm::pfs::{CreateRepoRequest, Repo};
+/// use pachyderm::transaction::Transaction;
+///
+/// let config = Config::read()?;
+/// let (_, context) = config.active_context()?;
+/// let transaction = Transaction {
+///     id: context.active_transaction.clone(),
+/// };
+///
+/// let request = CreateRepoRequest {
+///     repo: Some(Repo { name: "images".into() }),
+///     ..Default::default()
+/// };
+/// client.with_transaction(&transaction)?.pfs().create_repo(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TransactionClient {
+    client: Client,
+    transaction: Transaction,
+}
+
+impl TransactionClient {
+    pub(crate) fn new(client: Client, transaction: Transaction) -> Self {
+        TransactionClient { client, transaction }
+    }
+
+    /// Gets the transaction calls are added to.
+    pub fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    /// Gets a client for the PFS API whose transactional calls are added to
+    /// the transaction.
+    pub fn pfs(&self) -> pfs::api_client::ApiClient<TransactionChannel> {
+        pfs::api_client::ApiClient::with_interceptor(self.channel(), self.client.interceptor())
+    }
+
+    /// Gets a client for the PPS API whose transactional calls are added to
+    /// the transaction.
+    pub fn pps(&self) -> pps::api_client::ApiClient<TransactionChannel> {
+        pps::api_client::ApiClient::with_interceptor(self.channel(), self.client.interceptor())
+    }
+
+    /// Adds deleting everything in PFS and PPS to the transaction.
+    pub async fn delete_all(&self) -> Result<(), Error> {
+        let mut client = api_client::ApiClient::with_interceptor(self.channel(), self.client.interceptor());
+        client.delete_all(DeleteAllRequest {}).await?;
+        Ok(())
+    }
+
+    fn channel(&self) -> TransactionChannel {
+        TransactionChannel(self.client.channel().clone())
+    }
+}
+
+/// A channel that only lets through calls that can be added to a
+/// transaction. Other calls fail with `FailedPrecondition` without being
+/// sent to pachd.
+#[derive(Clone, Debug)]
+pub struct TransactionChannel(Channel);
+
+impl GrpcService<BoxBody> for TransactionChannel {
+    type ResponseBody = Body;
+    type Error = TransportError;
+    type Future = Either<ResponseFuture, Ready<Result<HttpResponse<Body>, TransportError>>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        GrpcService::poll_ready(&mut self.0, cx)
+    }
+
+    fn call(&mut self, request: HttpRequest<BoxBody>) -> Self::Future {
+        let path = request.uri().path();
+        if TRANSACTIONAL_PATHS.contains(&path) {
+            return Either::Left(GrpcService::call(&mut self.0, request));
+        }
+        let message = format!("`{}` can't be run in a transaction", path);
+        Either::Right(future::ready(Ok(status_response(Code::FailedPrecondition, &message))))
+    }
+}
+
+/// Builds a trailers-only response carrying the given status, which tonic
+/// turns into an error for the caller.
+fn status_response(code: Code, message: &str) -> HttpResponse<Body> {
+    let mut response = HttpResponse::new(Body::empty());
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/grpc"));
+    headers.insert("grpc-status", HeaderValue::from(code as i32));
+    // gRPC messages only need percent-encoding outside of printable ASCII,
+    // and request paths never are
+    if let Ok(message) = HeaderValue::from_str(message) {
+        headers.insert("grpc-message", message);
+    }
+    response
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::net::Ipv4Addr;
+    use std::sync::{Arc, Mutex};
+
+    use futures::stream;
+    use tokio::net::TcpListener;
+    use tonic::transport::Server;
+    use tonic::{Request, Response};
+
+    use super::api_server::{Api, ApiServer};
+    use super::*;
+
+    /// What a `FakeTransactions` has been asked to do.
+    #[derive(Debug, Default)]
+    struct Log {
+        started: usize,
+        finished: Vec<String>,
+        deleted: Vec<String>,
+        /// The transaction each `DeleteAll` was added to, by its metadata
+        delete_alls: Vec<Option<String>>,
+        fail_finish: bool,
+    }
+
+    /// Serves the transaction API, logging calls rather than running them.
+    #[derive(Clone, Default)]
+    struct FakeTransactions(Arc<Mutex<Log>>);
+
+    impl FakeTransactions {
+        /// Serves the API on a free local port, and connects a client to it.
+        async fn start(self) -> Client {
+            let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+            let address = listener.local_addr().unwrap();
+            let incoming = stream::unfold(listener, |mut listener| async move {
+                let conn = listener.accept().await.map(|(conn, _)| conn);
+                Some((conn, listener))
+            });
+            let server = Server::builder()
+                .add_service(ApiServer::new(self))
+                .serve_with_incoming(incoming);
+            tokio::spawn(server);
+
+            let channel = Channel::from_shared(format!("http://{}", address)).unwrap();
+            Client::new(channel.connect().await.unwrap())
+        }
+
+        fn log(&self) -> std::sync::MutexGuard<'_, Log> {
+            self.0.lock().unwrap()
+        }
+    }
+
+    #[tonic::async_trait]
+    impl Api for FakeTransactions {
+        async fn batch_transaction(
+            &self,
+            _: Request<BatchTransactionRequest>,
+        ) -> Result<Response<TransactionInfo>, Status> {
+            Err(Status::unimplemented("BatchTransaction"))
+        }
+
+        async fn start_transaction(
+            &self,
+            _: Request<StartTransactionRequest>,
+        ) -> Result<Response<Transaction>, Status> {
+            let mut log = self.log();
+            let id = format!("tx-{}", log.started);
+            log.started += 1;
+            Ok(Response::new(Transaction { id }))
+        }
+
+        async fn inspect_transaction(
+            &self,
+            _: Request<InspectTransactionRequest>,
+        ) -> Result<Response<TransactionInfo>, Status> {
+            Err(Status::unimplemented("InspectTransaction"))
+        }
+
+        async fn delete_transaction(&self, request: Request<DeleteTransactionRequest>) -> Result<Response<()>, Status> {
+            let id = request.into_inner().transaction.unwrap().id;
+            self.log().deleted.push(id);
+            Ok(Response::new(()))
+        }
+
+        async fn list_transaction(
+            &self,
+            _: Request<ListTransactionRequest>,
+        ) -> Result<Response<TransactionInfos>, Status> {
+            Err(Status::unimplemented("ListTransaction"))
+        }
+
+        async fn finish_transaction(
+            &self,
+            request: Request<FinishTransactionRequest>,
+        ) -> Result<Response<TransactionInfo>, Status> {
+            let transaction = request.into_inner().transaction.unwrap();
+            let mut log = self.log();
+            if log.fail_finish {
+                return Err(Status::unknown("transaction conflict"));
+            }
+            let added = log
+                .delete_alls
+                .iter()
+                .filter(|id| id.as_deref() == Some(&*transaction.id))
+                .count();
+            log.finished.push(transaction.id.clone());
+            Ok(Response::new(TransactionInfo {
+                transaction: Some(transaction),
+                responses: vec![TransactionResponse::default(); added],
+                ..Default::default()
+            }))
+        }
+
+        async fn delete_all(&self, request: Request<DeleteAllRequest>) -> Result<Response<()>, Status> {
+            let id = request
+                .metadata()
+                .get(TRANSACTION_KEY)
+                .map(|id| id.to_str().unwrap().to_string());
+            self.log().delete_alls.push(id);
+            Ok(Response::new(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn finishes_transaction() {
+        let fake = FakeTransactions::default();
+        let client = fake.clone().start().await;
+
+        let (value, responses) = client
+            .run_transaction(|tx| async move {
+                tx.delete_all().await?;
+                tx.delete_all().await?;
+                Ok(42)
+            })
+            .await
+            .unwrap();
+        assert_eq!(value, 42);
+        assert_eq!(responses.len(), 2);
+        assert_eq!(fake.log().finished, vec!["tx-0"]);
+        assert!(fake.log().deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rolls_back_on_error() {
+        let fake = FakeTransactions::default();
+        let client = fake.clone().start().await;
+
+        let err = client
+            .run_transaction(|tx| async move {
+                tx.delete_all().await?;
+                Err::<(), _>(Error::Other(Status::internal("oops")))
+            })
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Other(status) if status.message() == "oops"),
+            "{:?}",
+            err
+        );
+        assert!(fake.log().finished.is_empty());
+        assert_eq!(fake.log().deleted, vec!["tx-0"]);
+    }
+
+    #[tokio::test]
+    async fn rolls_back_on_panic() {
+        let fake = FakeTransactions::default();
+        let client = fake.clone().start().await;
+
+        let run = client.run_transaction(|tx| async move {
+            tx.delete_all().await?;
+            panic!("oops");
+            #[allow(unreachable_code)]
+            Ok(())
+        });
+        let panic = AssertUnwindSafe(run).catch_unwind().await.unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"oops"));
+        assert!(fake.log().finished.is_empty());
+        assert_eq!(fake.log().deleted, vec!["tx-0"]);
+    }
+
+    #[tokio::test]
+    async fn rolls_back_when_finishing_fails() {
+        let fake = FakeTransactions::default();
+        fake.log().fail_finish = true;
+        let client = fake.clone().start().await;
+
+        let err = client
+            .run_transaction(|tx| async move { tx.delete_all().await })
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Other(status) if status.message() == "transaction conflict"),
+            "{:?}",
+            err
+        );
+        assert_eq!(fake.log().deleted, vec!["tx-0"]);
+    }
+
+    #[tokio::test]
+    async fn adds_transactional_calls_to_the_transaction() {
+        let fake = FakeTransactions::default();
+        let client = fake.clone().start().await;
+
+        let transaction = Transaction { id: "tx-7".into() };
+        client
+            .with_transaction(&transaction)
+            .unwrap()
+            .delete_all()
+            .await
+            .unwrap();
+        client.transaction().delete_all(DeleteAllRequest {}).await.unwrap();
+        assert_eq!(fake.log().delete_alls, vec![Some("tx-7".into()), None]);
+    }
+
+    #[tokio::test]
+    async fn rejects_calls_that_cant_be_transactional() {
+        let fake = FakeTransactions::default();
+        let client = fake.clone().start().await;
+        let tx = client.with_transaction(&Transaction { id: "tx-7".into() }).unwrap();
+
+        let request = pfs::InspectRepoRequest {
+            repo: Some(pfs::Repo { name: "images".into() }),
+        };
+        let status = tx.pfs().inspect_repo(request).await.unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(status.message(), "`/pfs.API/InspectRepo` can't be run in a transaction");
+
+        // Calls that would reach the fake fail before being sent
+        let mut transactions = api_client::ApiClient::new(tx.channel());
+        let status = transactions
+            .start_transaction(StartTransactionRequest {})
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(fake.log().started, 0);
+    }
+}
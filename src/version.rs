@@ -0,0 +1,125 @@
+//! pachd's version API, as well as the range of pachd versions this library
+//! supports.
+
+use std::fmt;
+
+include_proto!("versionpb");
+
+/// A version of pachd's API that this library has bindings for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ApiVersion {
+    /// pachd 1.7.x.
+    V1_7,
+    /// pachd 1.8.x.
+    V1_8,
+    /// pachd 1.9.x.
+    V1_9,
+    /// pachd 1.10.x.
+    V1_10,
+    /// pachd 1.11.x, which the top-level modules (e.g. `pfs` and `pps`)
+    /// were generated for.
+    V1_11,
+}
+
+impl ApiVersion {
+    /// The oldest API version this library has bindings for, which the
+    /// `versioned` clients speak.
+    pub const OLDEST: ApiVersion = ApiVersion::V1_7;
+
+    /// The oldest API version a `Client` accepts when connecting. Only the
+    /// `versioned` clients speak older versions than the latest, so they're
+    /// only accepted with the `legacy-versions` feature.
+    pub const SUPPORTED_FROM: ApiVersion = if cfg!(feature = "legacy-versions") {
+        ApiVersion::OLDEST
+    } else {
+        ApiVersion::LATEST
+    };
+
+    /// The latest API version this library supports.
+    pub const LATEST: ApiVersion = ApiVersion::V1_11;
+
+    /// Gets the API version spoken by a given version of pachd, if it's one
+    /// this library has bindings for. pachd's API doesn't change across patch
+    /// releases, so only the major and minor versions matter.
+    pub fn of(version: &Version) -> Option<Self> {
+        match (version.major, version.minor) {
+            (1, 7) => Some(ApiVersion::V1_7),
+            (1, 8) => Some(ApiVersion::V1_8),
+            (1, 9) => Some(ApiVersion::V1_9),
+            (1, 10) => Some(ApiVersion::V1_10),
+            (1, 11) => Some(ApiVersion::V1_11),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ApiVersion::V1_7 => "1.7",
+            ApiVersion::V1_8 => "1.8",
+            ApiVersion::V1_9 => "1.9",
+            ApiVersion::V1_10 => "1.10",
+            ApiVersion::V1_11 => "1.11",
+        })
+    }
+}
+
+impl Version {
+    /// Gets the API version this version of pachd speaks, if it's one this
+    /// library has bindings for.
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        ApiVersion::of(self)
+    }
+
+    /// Whether this library can talk to a pachd of this version, i.e. it
+    /// speaks the latest API version, or an older one with the
+    /// `legacy-versions` feature.
+    pub fn is_supported(&self) -> bool {
+        self.api_version()
+            .is_some_and(|version| version >= ApiVersion::SUPPORTED_FROM)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)?;
+        if !self.additional.is_empty() {
+            write!(f, "{}", self.additional)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(major: u32, minor: u32) -> Version {
+        Version {
+            major,
+            minor,
+            micro: 3,
+            additional: String::new(),
+        }
+    }
+
+    #[test]
+    fn maps_versions_with_bindings() {
+        assert_eq!(version(1, 7).api_version(), Some(ApiVersion::V1_7));
+        assert_eq!(version(1, 10).api_version(), Some(ApiVersion::V1_10));
+        assert_eq!(version(1, 11).api_version(), Some(ApiVersion::V1_11));
+        assert_eq!(version(1, 6).api_version(), None);
+        assert_eq!(version(2, 0).api_version(), None);
+    }
+
+    #[test]
+    fn supports_legacy_versions_only_with_the_feature() {
+        assert!(version(1, 11).is_supported());
+        assert!(!version(1, 12).is_supported());
+        assert!(!version(1, 6).is_supported());
+        for minor in 7..=10 {
+            assert_eq!(version(1, minor).is_supported(), cfg!(feature = "legacy-versions"));
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! PFS and PPS clients that work with any version of pachd this library
+//! supports.
+//!
+//! The top-level `pfs` and `pps` modules speak pachd 1.11's API. The clients
+//! here instead pick the bindings matching the version pachd reported when
+//! connecting, while still taking and returning the top-level types. Requests
+//! and responses are converted between versions via `TryFrom`, mostly by
+//! re-encoding them with the other version's bindings. Messages whose fields
+//! were renumbered or restructured between versions, e.g. commits'
+//! provenance and pipelines' inputs, are converted field by field instead.
+//!
+//! Converting a request fails with `Error::Conversion` if it sets a field
+//! that pachd's version has no equivalent of, rather than silently dropping
+//! it. Fields of responses that the latest version doesn't have are dropped.
+//!
+//! Only the calls that every supported version has are available.
+
+use std::convert::TryFrom;
+use std::future::Future;
+use std::task::{Context, Poll};
+
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use futures::stream::{Stream, StreamExt};
+use prost::Message;
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
+use tonic::codegen::http::uri::{PathAndQuery, Uri};
+use tonic::codegen::http::Request as HttpRequest;
+use tonic::transport::Channel;
+use tonic::{Response, Status};
+
+use crate::Error;
+
+/// Implements `TryFrom` in both directions between each of the given
+/// messages in the current bindings and their equivalents in each of the
+/// legacy bindings.
+macro_rules! convert_messages {
+    ($current:ident <=> [$($legacy:ident),*] $messages:tt) => {
+        $(convert_messages!(@version $current $legacy $messages);)*
+    };
+    (@version $current:ident $legacy:ident [$($message:ident),* $(,)?]) => {
+        $(
+            impl std::convert::TryFrom<$current::$message> for $legacy::$message {
+                type Error = crate::Error;
+
+                fn try_from(message: $current::$message) -> Result<Self, Self::Error> {
+                    crate::versioned::downgrade(&message)
+                }
+            }
+
+            impl std::convert::TryFrom<$legacy::$message> for $current::$message {
+                type Error = crate::Error;
+
+                fn try_from(message: $legacy::$message) -> Result<Self, Self::Error> {
+                    crate::versioned::transcode(&message)
+                }
+            }
+        )*
+    };
+}
+
+/// Makes a unary call with whichever bindings match the server's version.
+/// `current`, `v1_7`, etc. must name the bindings for each version, and
+/// `Inner` the enum of clients.
+macro_rules! call_unary {
+    ($inner:expr, $method:ident($request:ident), $value:expr) => {{
+        use crate::versioned::convert;
+        match $inner {
+            Inner::V1_7(client) => convert(
+                client
+                    .$method(convert::<_, v1_7::$request>($value)?)
+                    .await?
+                    .into_inner(),
+            ),
+            Inner::V1_8(client) => convert(
+                client
+                    .$method(convert::<_, v1_8::$request>($value)?)
+                    .await?
+                    .into_inner(),
+            ),
+            Inner::V1_9(client) => convert(
+                client
+                    .$method(convert::<_, v1_9::$request>($value)?)
+                    .await?
+                    .into_inner(),
+            ),
+            Inner::V1_10(client) => convert(
+                client
+                    .$method(convert::<_, v1_10::$request>($value)?)
+                    .await?
+                    .into_inner(),
+            ),
+            Inner::V1_11(client) => Ok(client.$method($value).await?.into_inner()),
+        }
+    }};
+}
+
+/// Defines a method that makes a unary call via `call_unary!`. Calls marked
+/// `idempotent` are retried according to the client's retry policy, if any.
+macro_rules! unary {
+    ($(#[$attr:meta])* $method:ident($request:ident) -> $response:ty) => {
+        $(#[$attr])*
+        pub async fn $method(&mut self, request: current::$request) -> Result<$response, Error> {
+            call_unary!(&mut self.inner, $method($request), request)
+        }
+    };
+    ($(#[$attr:meta])* idempotent $method:ident($request:ident) -> $response:ty) => {
+        $(#[$attr])*
+        pub async fn $method(&mut self, request: current::$request) -> Result<$response, Error> {
+            let mut backoff = match &self.retry {
+                Some(policy) => policy.backoff(),
+                None => return call_unary!(&mut self.inner, $method($request), request),
+            };
+            loop {
+                let result: Result<$response, Error> = call_unary!(&mut self.inner, $method($request), request.clone());
+                match result {
+                    Err(err) => backoff.wait(err).await?,
+                    result => return result,
+                }
+            }
+        }
+    };
+}
+
+/// Like `unary!`, but for calls that stream back responses. These are never
+/// retried, since they may have been partially consumed. A different name
+/// and visibility can be given for the method, e.g. to wrap it.
+macro_rules! server_streaming {
+    ($(#[$attr:meta])* $method:ident($request:ident) -> $item:ty) => {
+        server_streaming!($(#[$attr])* pub $method = $method($request) -> $item);
+    };
+    ($(#[$attr:meta])* $vis:vis $name:ident = $method:ident($request:ident) -> $item:ty) => {
+        $(#[$attr])*
+        $vis async fn $name(&mut self, request: current::$request) -> Result<crate::versioned::Streaming<$item>, Error> {
+            use crate::versioned::{convert, convert_items};
+            Ok(match &mut self.inner {
+                Inner::V1_7(client) => convert_items(client.$method(convert::<_, v1_7::$request>(request)?).await?.into_inner()),
+                Inner::V1_8(client) => convert_items(client.$method(convert::<_, v1_8::$request>(request)?).await?.into_inner()),
+                Inner::V1_9(client) => convert_items(client.$method(convert::<_, v1_9::$request>(request)?).await?.into_inner()),
+                Inner::V1_10(client) => convert_items(client.$method(convert::<_, v1_10::$request>(request)?).await?.into_inner()),
+                Inner::V1_11(client) => convert_items(client.$method(request).await?.into_inner()),
+            })
+        }
+    };
+}
+
+mod pfs;
+mod pps;
+
+pub use self::pfs::PfsClient;
+pub use self::pps::PpsClient;
+
+pub use crate::api::Streaming;
+
+/// Converts a message between API versions by encoding it with one version's
+/// bindings and decoding it with the other's. This relies on every field
+/// having the same number and type in both versions, and drops fields the
+/// other version doesn't have.
+pub(crate) fn transcode<A: Message, B: Message + Default>(message: &A) -> Result<B, Error> {
+    let mut buf = Vec::with_capacity(message.encoded_len());
+    message.encode(&mut buf).expect("vec should grow to fit the message");
+    B::decode(buf.as_slice()).map_err(|err| Error::Conversion(format!("{} as `{}`", err, binding::<B>())))
+}
+
+/// Converts a message to a legacy API version via `transcode`, failing if
+/// any of the fields it sets were dropped, i.e. if the legacy version
+/// doesn't have them.
+pub(crate) fn downgrade<A: Message, B: Message + Default>(message: &A) -> Result<B, Error> {
+    let downgraded: B = transcode(message)?;
+    // Fields that survive re-encoding take up as many bytes as before
+    if downgraded.encoded_len() < message.encoded_len() {
+        return Err(Error::Conversion(format!(
+            "`{}` sets fields that `{}` doesn't have",
+            binding::<A>(),
+            binding::<B>()
+        )));
+    }
+    Ok(downgraded)
+}
+
+/// The error for a message that sets something the legacy version it's
+/// being converted to, whose message is `B`, has no equivalent of.
+pub(crate) fn unsupported<B>(what: &str) -> Error {
+    Error::Conversion(format!("`{}` has no equivalent of {}", binding::<B>(), what))
+}
+
+/// Names a message by its bindings' module, e.g. `pfs_1_7::Commit`.
+fn binding<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    // Without the crate's name
+    name.split_once("::").map_or(name, |(_, path)| path)
+}
+
+/// Converts a value between API versions. This also works for types that
+/// are the same in every version, e.g. `()`.
+pub(crate) fn convert<A, B>(value: A) -> Result<B, Error>
+where
+    B: TryFrom<A>,
+    Error: From<B::Error>,
+{
+    Ok(B::try_from(value)?)
+}
+
+/// Converts each item of a response stream to the current API version.
+pub(crate) fn convert_items<S, A, B>(stream: S) -> Streaming<B>
+where
+    S: Stream<Item = Result<A, Status>> + Send + 'static,
+    B: TryFrom<A> + 'static,
+    Error: From<B::Error>,
+{
+    Box::pin(stream.map(|item| convert(item?)))
+}
+
+/// Converts each message of a request stream to a legacy API version, via
+/// `stall_on_error`.
+pub(crate) fn convert_requests<S, A, B>(
+    requests: S,
+) -> (impl Stream<Item = B> + Send + Sync + 'static, oneshot::Receiver<Error>)
+where
+    S: Stream<Item = A> + Send + Sync + 'static,
+    A: 'static,
+    B: TryFrom<A, Error = Error> + Send + Sync + 'static,
+{
+    stall_on_error(requests.map(B::try_from))
+}
+
+/// Unwraps each message of a request stream. If there's an error instead,
+/// it is sent to the returned receiver, and the stream stalls, so that the
+/// call can be cancelled rather than sending pachd a truncated stream.
+pub(crate) fn stall_on_error<S, T>(
+    requests: S,
+) -> (impl Stream<Item = T> + Send + Sync + 'static, oneshot::Receiver<Error>)
+where
+    S: Stream<Item = Result<T, Error>> + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    let (failed_tx, failed_rx) = oneshot::channel();
+    let mut failed_tx = Some(failed_tx);
+    let requests = requests.then(move |request| match request {
+        Ok(request) => Either::Left(future::ready(request)),
+        Err(err) => {
+            if let Some(failed_tx) = failed_tx.take() {
+                let _ = failed_tx.send(err);
+            }
+            Either::Right(future::pending())
+        }
+    });
+    (requests, failed_rx)
+}
+
+/// Waits for a call made with a stream from `stall_on_error`, cancelling
+/// it if any of its requests couldn't be converted.
+pub(crate) async fn finish_converted<F, T>(call: F, failed: oneshot::Receiver<Error>) -> Result<T, Error>
+where
+    F: Future<Output = Result<Response<T>, Status>>,
+{
+    futures::pin_mut!(call);
+    match future::select(call, failed).await {
+        Either::Left((result, _)) => Ok(result?.into_inner()),
+        Either::Right((Ok(err), _)) => Err(err),
+        // Every request was converted, so just wait for the response
+        Either::Right((Err(_), call)) => Ok(call.await?.into_inner()),
+    }
+}
+
+/// A channel for the legacy bindings. They're generated from protos whose
+/// packages are suffixed with their version, e.g. `pfs_1_10`, but pachd
+/// serves them under the unsuffixed names, so this rewrites request paths to
+/// match, e.g. `/pfs_1_10.API/ListRepo` becomes `/pfs.API/ListRepo`.
+#[derive(Clone, Debug)]
+pub(crate) struct LegacyChannel(Channel);
+
+impl LegacyChannel {
+    pub(crate) fn new(channel: Channel) -> Self {
+        LegacyChannel(channel)
+    }
+}
+
+impl GrpcService<BoxBody> for LegacyChannel {
+    type ResponseBody = <Channel as GrpcService<BoxBody>>::ResponseBody;
+    type Error = <Channel as GrpcService<BoxBody>>::Error;
+    type Future = <Channel as GrpcService<BoxBody>>::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        GrpcService::poll_ready(&mut self.0, cx)
+    }
+
+    fn call(&mut self, mut request: HttpRequest<BoxBody>) -> Self::Future {
+        if let Some(path) = unversioned_path(request.uri().path()) {
+            let mut parts = request.uri().clone().into_parts();
+            parts.path_and_query = Some(PathAndQuery::from_maybe_shared(path).expect("rewritten path is valid"));
+            *request.uri_mut() = Uri::from_parts(parts).expect("rewritten uri is valid");
+        }
+        GrpcService::call(&mut self.0, request)
+    }
+}
+
+/// Strips the version suffix from the package in a gRPC request path, if
+/// there is one.
+fn unversioned_path(path: &str) -> Option<String> {
+    let service_start = path.find('.')?;
+    let version_start = path[..service_start].find("_1_")?;
+    Some(format!("{}{}", &path[..version_start], &path[service_start..]))
+}
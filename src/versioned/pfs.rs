@@ -0,0 +1,629 @@
+use std::convert::TryFrom;
+use std::mem;
+
+use futures::stream::{self, Stream, StreamExt};
+use tonic::transport::Channel;
+use tonic::Interceptor;
+
+use super::{convert, convert_requests, downgrade, finish_converted, transcode, unsupported, LegacyChannel, Streaming};
+use crate::pfs as current;
+use crate::pfs_1_10 as v1_10;
+use crate::pfs_1_7 as v1_7;
+use crate::pfs_1_8 as v1_8;
+use crate::pfs_1_9 as v1_9;
+use crate::retry::Backoff;
+use crate::version::ApiVersion;
+use crate::{Error, RetryPolicy};
+
+convert_messages!(current <=> [v1_7, v1_8, v1_9, v1_10] [
+    Repo,
+    Branch,
+    Commit,
+    File,
+    RepoInfo,
+    BranchInfo,
+    BranchInfos,
+    FileInfo,
+    FileInfos,
+    CreateRepoRequest,
+    InspectRepoRequest,
+    ListRepoRequest,
+    ListRepoResponse,
+    DeleteRepoRequest,
+    FinishCommitRequest,
+    InspectCommitRequest,
+    ListCommitRequest,
+    DeleteCommitRequest,
+    FlushCommitRequest,
+    SubscribeCommitRequest,
+    CreateBranchRequest,
+    InspectBranchRequest,
+    ListBranchRequest,
+    DeleteBranchRequest,
+    CopyFileRequest,
+    GetFileRequest,
+    InspectFileRequest,
+    ListFileRequest,
+    GlobFileRequest,
+    DiffFileRequest,
+    DiffFileResponse,
+    DeleteFileRequest,
+]);
+
+// 1.7 and 1.8 list commits' provenance under other field numbers, so
+// `convert_provenance!` converts these for them
+convert_messages!(current <=> [v1_9, v1_10] [
+    StartCommitRequest,
+    BuildCommitRequest,
+    CommitInfo,
+    CommitInfos,
+]);
+
+// 1.7 has a header and footer where later versions have `header_records`
+// and `delete`, so its conversions are written out below
+convert_messages!(current <=> [v1_8, v1_9, v1_10] [PutFileRequest]);
+
+/// Implements `TryFrom` in both directions between the messages holding
+/// commits' provenance in the current bindings and their equivalents in 1.7
+/// or 1.8. Those list provenance as plain commits rather than
+/// `CommitProvenance`s, along with, in `CommitInfo`, a parallel list of the
+/// branches the commits are on.
+macro_rules! convert_provenance {
+    ($($legacy:ident),*) => {
+        $(
+            convert_provenance!(@request $legacy StartCommitRequest);
+            convert_provenance!(@request $legacy BuildCommitRequest);
+
+            impl TryFrom<current::CommitInfo> for $legacy::CommitInfo {
+                type Error = Error;
+
+                fn try_from(mut info: current::CommitInfo) -> Result<Self, Error> {
+                    let provenance = mem::take(&mut info.provenance);
+                    let mut legacy: Self = downgrade(&info)?;
+                    let (commits, branches) = split_provenance::<Self, _, _>(provenance)?;
+                    legacy.provenance = commits;
+                    legacy.branch_provenance = branches;
+                    Ok(legacy)
+                }
+            }
+
+            impl TryFrom<$legacy::CommitInfo> for current::CommitInfo {
+                type Error = Error;
+
+                fn try_from(mut info: $legacy::CommitInfo) -> Result<Self, Error> {
+                    let commits = mem::take(&mut info.provenance);
+                    let branches = mem::take(&mut info.branch_provenance);
+                    let mut current: Self = transcode(&info)?;
+                    current.provenance = join_provenance(commits, branches)?;
+                    Ok(current)
+                }
+            }
+
+            impl TryFrom<current::CommitInfos> for $legacy::CommitInfos {
+                type Error = Error;
+
+                fn try_from(infos: current::CommitInfos) -> Result<Self, Error> {
+                    Ok($legacy::CommitInfos {
+                        commit_info: infos.commit_info.into_iter().map(convert).collect::<Result<_, _>>()?,
+                    })
+                }
+            }
+
+            impl TryFrom<$legacy::CommitInfos> for current::CommitInfos {
+                type Error = Error;
+
+                fn try_from(infos: $legacy::CommitInfos) -> Result<Self, Error> {
+                    Ok(current::CommitInfos {
+                        commit_info: infos.commit_info.into_iter().map(convert).collect::<Result<_, _>>()?,
+                    })
+                }
+            }
+        )*
+    };
+    (@request $legacy:ident $request:ident) => {
+        impl TryFrom<current::$request> for $legacy::$request {
+            type Error = Error;
+
+            fn try_from(mut request: current::$request) -> Result<Self, Error> {
+                let provenance = mem::take(&mut request.provenance);
+                let mut legacy: Self = downgrade(&request)?;
+                let (commits, branches) = split_provenance::<Self, _, $legacy::Branch>(provenance)?;
+                if !branches.is_empty() {
+                    return Err(unsupported::<Self>("provenance on a branch"));
+                }
+                legacy.provenance = commits;
+                Ok(legacy)
+            }
+        }
+
+        impl TryFrom<$legacy::$request> for current::$request {
+            type Error = Error;
+
+            fn try_from(mut request: $legacy::$request) -> Result<Self, Error> {
+                let commits = mem::take(&mut request.provenance);
+                let mut current: Self = transcode(&request)?;
+                current.provenance = join_provenance::<_, $legacy::Branch>(commits, Vec::new())?;
+                Ok(current)
+            }
+        }
+    };
+}
+
+convert_provenance!(v1_7, v1_8);
+
+impl TryFrom<current::PutFileRequest> for v1_7::PutFileRequest {
+    type Error = Error;
+
+    fn try_from(request: current::PutFileRequest) -> Result<Self, Error> {
+        // These have the field numbers of 1.7's header and footer
+        if request.header_records != 0 {
+            return Err(unsupported::<Self>("`header_records`"));
+        }
+        if request.delete {
+            return Err(unsupported::<Self>("`delete`"));
+        }
+        downgrade(&request)
+    }
+}
+
+impl TryFrom<v1_7::PutFileRequest> for current::PutFileRequest {
+    type Error = Error;
+
+    fn try_from(request: v1_7::PutFileRequest) -> Result<Self, Error> {
+        if request.header.is_some() || request.footer.is_some() {
+            return Err(Error::Conversion(
+                "`pfs::PutFileRequest` has no equivalent of a header or footer".into(),
+            ));
+        }
+        transcode(&request)
+    }
+}
+
+/// Splits provenance into the provenant commits and their branches, for
+/// 1.7 and 1.8, whose message `M` lists them in parallel. So either every
+/// commit or none of them must be on a branch.
+fn split_provenance<M, C, B>(provenance: Vec<current::CommitProvenance>) -> Result<(Vec<C>, Vec<B>), Error>
+where
+    C: TryFrom<current::Commit, Error = Error>,
+    B: TryFrom<current::Branch, Error = Error>,
+{
+    let on_branches = provenance
+        .iter()
+        .filter(|provenance| provenance.branch.is_some())
+        .count();
+    if on_branches != 0 && on_branches != provenance.len() {
+        return Err(unsupported::<M>("provenance on a branch for only some commits"));
+    }
+
+    let mut commits = Vec::with_capacity(provenance.len());
+    let mut branches = Vec::with_capacity(on_branches);
+    for provenance in provenance {
+        commits.push(convert(provenance.commit.unwrap_or_default())?);
+        if let Some(branch) = provenance.branch {
+            branches.push(convert(branch)?);
+        }
+    }
+    Ok((commits, branches))
+}
+
+/// Pairs up provenant commits with the branches they're on, from 1.7 and
+/// 1.8, which list them in parallel. Commits past the end of `branches` are
+/// on no branch.
+fn join_provenance<C, B>(commits: Vec<C>, branches: Vec<B>) -> Result<Vec<current::CommitProvenance>, Error>
+where
+    current::Commit: TryFrom<C, Error = Error>,
+    current::Branch: TryFrom<B, Error = Error>,
+{
+    let mut branches = branches.into_iter();
+    commits
+        .into_iter()
+        .map(|commit| {
+            Ok(current::CommitProvenance {
+                commit: Some(convert(commit)?),
+                branch: branches.next().map(convert).transpose()?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+enum Inner {
+    V1_7(v1_7::api_client::ApiClient<LegacyChannel>),
+    V1_8(v1_8::api_client::ApiClient<LegacyChannel>),
+    V1_9(v1_9::api_client::ApiClient<LegacyChannel>),
+    V1_10(v1_10::api_client::ApiClient<LegacyChannel>),
+    V1_11(current::api_client::ApiClient<Channel>),
+}
+
+/// A PFS client that works with any version of pachd this library supports.
+/// Create one via `Client::versioned_pfs`.
+#[derive(Clone, Debug)]
+pub struct PfsClient {
+    inner: Inner,
+    version: ApiVersion,
+    retry: Option<RetryPolicy>,
+}
+
+impl PfsClient {
+    pub(crate) fn new(
+        channel: Channel,
+        interceptor: Interceptor,
+        version: ApiVersion,
+        retry: Option<RetryPolicy>,
+    ) -> Self {
+        let legacy = LegacyChannel::new(channel.clone());
+        let inner = match version {
+            ApiVersion::V1_7 => Inner::V1_7(v1_7::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_8 => Inner::V1_8(v1_8::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_9 => Inner::V1_9(v1_9::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_10 => Inner::V1_10(v1_10::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_11 => Inner::V1_11(current::api_client::ApiClient::with_interceptor(channel, interceptor)),
+        };
+        PfsClient { inner, version, retry }
+    }
+
+    /// Gets the API version this client speaks.
+    pub fn api_version(&self) -> ApiVersion {
+        self.version
+    }
+
+    unary!(create_repo(CreateRepoRequest) -> ());
+    unary!(idempotent inspect_repo(InspectRepoRequest) -> current::RepoInfo);
+    unary!(idempotent list_repo(ListRepoRequest) -> current::ListRepoResponse);
+    unary!(delete_repo(DeleteRepoRequest) -> ());
+    unary!(start_commit(StartCommitRequest) -> current::Commit);
+    unary!(finish_commit(FinishCommitRequest) -> ());
+    unary!(idempotent inspect_commit(InspectCommitRequest) -> current::CommitInfo);
+    unary!(idempotent list_commit(ListCommitRequest) -> current::CommitInfos);
+    server_streaming!(list_commit_stream(ListCommitRequest) -> current::CommitInfo);
+    unary!(delete_commit(DeleteCommitRequest) -> ());
+    server_streaming!(flush_commit(FlushCommitRequest) -> current::CommitInfo);
+    server_streaming!(subscribe_commit(SubscribeCommitRequest) -> current::CommitInfo);
+    unary!(build_commit(BuildCommitRequest) -> current::Commit);
+    unary!(create_branch(CreateBranchRequest) -> ());
+    unary!(idempotent inspect_branch(InspectBranchRequest) -> current::BranchInfo);
+    unary!(idempotent list_branch(ListBranchRequest) -> current::BranchInfos);
+    unary!(delete_branch(DeleteBranchRequest) -> ());
+    unary!(copy_file(CopyFileRequest) -> ());
+    server_streaming!(open_get_file = get_file(GetFileRequest) -> Vec<u8>);
+    unary!(idempotent inspect_file(InspectFileRequest) -> current::FileInfo);
+    unary!(idempotent list_file(ListFileRequest) -> current::FileInfos);
+    server_streaming!(list_file_stream(ListFileRequest) -> current::FileInfo);
+    unary!(idempotent glob_file(GlobFileRequest) -> current::FileInfos);
+    server_streaming!(glob_file_stream(GlobFileRequest) -> current::FileInfo);
+    unary!(idempotent diff_file(DiffFileRequest) -> current::DiffFileResponse);
+    unary!(delete_file(DeleteFileRequest) -> ());
+
+    /// Streams the contents of a file. If the client has a retry policy,
+    /// transient failures are retried, including ones partway through the
+    /// file, in which case the file is re-requested from where the stream
+    /// left off.
+    pub async fn get_file(&mut self, request: current::GetFileRequest) -> Result<Streaming<Vec<u8>>, Error> {
+        let mut backoff = match &self.retry {
+            Some(policy) => policy.backoff(),
+            None => return self.open_get_file(request).await,
+        };
+        let chunks = loop {
+            match self.open_get_file(request.clone()).await {
+                Err(err) => backoff.wait(err).await?,
+                Ok(chunks) => break chunks,
+            }
+        };
+
+        let resumable = ResumableGetFile {
+            client: self.clone(),
+            request,
+            chunks: Some(chunks),
+            backoff,
+            read_everything: false,
+        };
+        Ok(Box::pin(stream::unfold(Some(resumable), |resumable| async move {
+            let mut resumable = resumable?;
+            match resumable.next().await {
+                Some(Ok(chunk)) => Some((Ok(chunk), Some(resumable))),
+                // Nothing more can be read after a non-transient error
+                Some(Err(err)) => Some((Err(err), None)),
+                None => None,
+            }
+        })))
+    }
+
+    /// Streams the contents of one or more files into PFS. If any request
+    /// can't be converted to pachd's API version, the call is cancelled
+    /// rather than committing a partial upload.
+    pub async fn put_file<S>(&mut self, requests: S) -> Result<(), Error>
+    where
+        S: Stream<Item = current::PutFileRequest> + Send + Sync + 'static,
+    {
+        match &mut self.inner {
+            Inner::V1_7(client) => {
+                let (requests, failed) = convert_requests::<_, _, v1_7::PutFileRequest>(requests);
+                finish_converted(client.put_file(requests), failed).await
+            }
+            Inner::V1_8(client) => {
+                let (requests, failed) = convert_requests::<_, _, v1_8::PutFileRequest>(requests);
+                finish_converted(client.put_file(requests), failed).await
+            }
+            Inner::V1_9(client) => {
+                let (requests, failed) = convert_requests::<_, _, v1_9::PutFileRequest>(requests);
+                finish_converted(client.put_file(requests), failed).await
+            }
+            Inner::V1_10(client) => {
+                let (requests, failed) = convert_requests::<_, _, v1_10::PutFileRequest>(requests);
+                finish_converted(client.put_file(requests), failed).await
+            }
+            Inner::V1_11(client) => {
+                client.put_file(requests).await?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Deletes everything in PFS.
+    pub async fn delete_all(&mut self) -> Result<(), Error> {
+        match &mut self.inner {
+            Inner::V1_7(client) => client.delete_all(()).await?,
+            Inner::V1_8(client) => client.delete_all(()).await?,
+            Inner::V1_9(client) => client.delete_all(()).await?,
+            Inner::V1_10(client) => client.delete_all(()).await?,
+            Inner::V1_11(client) => client.delete_all(()).await?,
+        };
+        Ok(())
+    }
+}
+
+/// The state of a `GetFile` call that resumes after transient failures.
+struct ResumableGetFile {
+    client: PfsClient,
+    request: current::GetFileRequest,
+    chunks: Option<Streaming<Vec<u8>>>,
+    backoff: Backoff,
+    read_everything: bool,
+}
+
+impl ResumableGetFile {
+    /// Gets the next chunk of the file, re-requesting the rest of the file
+    /// if the current stream fails transiently.
+    async fn next(&mut self) -> Option<Result<Vec<u8>, Error>> {
+        loop {
+            let chunks = match &mut self.chunks {
+                Some(chunks) => chunks,
+                None => match self.client.open_get_file(self.request.clone()).await {
+                    Ok(chunks) => self.chunks.get_or_insert(chunks),
+                    Err(err) => match self.backoff.wait(err).await {
+                        Ok(()) => continue,
+                        Err(err) => return Some(Err(err)),
+                    },
+                },
+            };
+
+            match chunks.next().await {
+                Some(Ok(chunk)) => {
+                    self.advance(chunk.len() as i64);
+                    self.backoff.reset();
+                    return Some(Ok(chunk));
+                }
+                Some(Err(_)) if self.read_everything => return None,
+                Some(Err(err)) => {
+                    self.chunks = None;
+                    if let Err(err) = self.backoff.wait(err).await {
+                        return Some(Err(err));
+                    }
+                }
+                None => return None,
+            }
+        }
+    }
+
+    /// Moves the requested range past bytes that have already been read, so
+    /// that a retried request picks up where the last one left off.
+    fn advance(&mut self, read: i64) {
+        self.request.offset_bytes += read;
+        // A size of 0 means the rest of the file
+        if self.request.size_bytes > 0 {
+            self.request.size_bytes -= read;
+            self.read_everything = self.request.size_bytes <= 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    /// Converts `message` to a legacy version and back, checking that it
+    /// comes back unchanged, and returns the legacy message.
+    fn round_trip<L, C>(message: &C) -> L
+    where
+        L: TryFrom<C, Error = Error> + Clone,
+        C: TryFrom<L, Error = Error> + Clone + PartialEq + Debug,
+    {
+        let legacy = L::try_from(message.clone()).expect("converting to the legacy version");
+        assert_eq!(&C::try_from(legacy.clone()).expect("converting back"), message);
+        legacy
+    }
+
+    fn commit(id: &str) -> current::Commit {
+        current::Commit {
+            repo: Some(current::Repo { name: "images".into() }),
+            id: id.into(),
+        }
+    }
+
+    fn branch(name: &str) -> current::Branch {
+        current::Branch {
+            repo: Some(current::Repo { name: "images".into() }),
+            name: name.into(),
+        }
+    }
+
+    fn provenance(id: &str, on: Option<&str>) -> current::CommitProvenance {
+        current::CommitProvenance {
+            commit: Some(commit(id)),
+            branch: on.map(branch),
+        }
+    }
+
+    #[test]
+    fn converts_start_commit_provenance() {
+        let request = current::StartCommitRequest {
+            parent: Some(commit("")),
+            branch: "master".into(),
+            description: "edges".into(),
+            provenance: vec![provenance("abc", None), provenance("def", None)],
+        };
+
+        let legacy = round_trip::<v1_7::StartCommitRequest, _>(&request);
+        assert_eq!(
+            legacy.provenance,
+            vec![
+                convert::<_, v1_7::Commit>(commit("abc")).unwrap(),
+                convert(commit("def")).unwrap()
+            ]
+        );
+        let legacy = round_trip::<v1_8::StartCommitRequest, _>(&request);
+        assert_eq!(legacy.provenance.len(), 2);
+        round_trip::<v1_9::StartCommitRequest, _>(&request);
+        round_trip::<v1_10::StartCommitRequest, _>(&request);
+    }
+
+    #[test]
+    fn converts_build_commit_provenance() {
+        let request = current::BuildCommitRequest {
+            parent: Some(commit("")),
+            branch: "master".into(),
+            id: "abc".into(),
+            provenance: vec![provenance("def", None)],
+            ..Default::default()
+        };
+
+        let legacy = round_trip::<v1_7::BuildCommitRequest, _>(&request);
+        assert_eq!(
+            legacy.provenance,
+            vec![convert::<_, v1_7::Commit>(commit("def")).unwrap()]
+        );
+        let legacy = round_trip::<v1_8::BuildCommitRequest, _>(&request);
+        assert_eq!(legacy.provenance.len(), 1);
+        round_trip::<v1_9::BuildCommitRequest, _>(&request);
+        round_trip::<v1_10::BuildCommitRequest, _>(&request);
+    }
+
+    #[test]
+    fn rejects_provenance_on_a_branch_before_1_9() {
+        let request = current::StartCommitRequest {
+            branch: "master".into(),
+            provenance: vec![provenance("abc", Some("master"))],
+            ..Default::default()
+        };
+
+        let result = v1_7::StartCommitRequest::try_from(request.clone());
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+        let result = v1_8::StartCommitRequest::try_from(request.clone());
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+        round_trip::<v1_9::StartCommitRequest, _>(&request);
+    }
+
+    #[test]
+    fn converts_commit_info_provenance() {
+        let info = current::CommitInfo {
+            commit: Some(commit("abc")),
+            description: "edges".into(),
+            size_bytes: 42,
+            provenance: vec![provenance("def", Some("master")), provenance("ghi", Some("staging"))],
+            ..Default::default()
+        };
+
+        let legacy = round_trip::<v1_7::CommitInfo, _>(&info);
+        assert_eq!(legacy.provenance.len(), 2);
+        assert_eq!(legacy.provenance[1].id, "ghi");
+        assert_eq!(legacy.branch_provenance[1].name, "staging");
+        let legacy = round_trip::<v1_8::CommitInfo, _>(&info);
+        assert_eq!(legacy.provenance[0].id, "def");
+        assert_eq!(legacy.branch_provenance[0].name, "master");
+        round_trip::<v1_9::CommitInfo, _>(&info);
+        round_trip::<v1_10::CommitInfo, _>(&info);
+
+        let infos = current::CommitInfos {
+            commit_info: vec![info],
+        };
+        let legacy = round_trip::<v1_7::CommitInfos, _>(&infos);
+        assert_eq!(legacy.commit_info[0].branch_provenance[0].name, "master");
+        round_trip::<v1_8::CommitInfos, _>(&infos);
+        round_trip::<v1_9::CommitInfos, _>(&infos);
+        round_trip::<v1_10::CommitInfos, _>(&infos);
+    }
+
+    #[test]
+    fn converts_commit_info_provenance_without_branches() {
+        let info = current::CommitInfo {
+            commit: Some(commit("abc")),
+            provenance: vec![provenance("def", None)],
+            ..Default::default()
+        };
+
+        let legacy = round_trip::<v1_7::CommitInfo, _>(&info);
+        assert!(legacy.branch_provenance.is_empty());
+        round_trip::<v1_8::CommitInfo, _>(&info);
+    }
+
+    #[test]
+    fn rejects_provenance_on_a_branch_for_only_some_commits_before_1_9() {
+        let info = current::CommitInfo {
+            commit: Some(commit("abc")),
+            provenance: vec![provenance("def", Some("master")), provenance("ghi", None)],
+            ..Default::default()
+        };
+
+        let result = v1_7::CommitInfo::try_from(info.clone());
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+        let result = v1_8::CommitInfo::try_from(info);
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+    }
+
+    #[test]
+    fn converts_put_file_request() {
+        let request = current::PutFileRequest {
+            file: Some(current::File {
+                commit: Some(commit("master")),
+                path: "/cat.png".into(),
+            }),
+            value: b"meow".to_vec(),
+            delimiter: current::Delimiter::Line as i32,
+            ..Default::default()
+        };
+
+        round_trip::<v1_7::PutFileRequest, _>(&request);
+        round_trip::<v1_8::PutFileRequest, _>(&request);
+        round_trip::<v1_9::PutFileRequest, _>(&request);
+        round_trip::<v1_10::PutFileRequest, _>(&request);
+
+        let with_header = current::PutFileRequest {
+            header_records: 1,
+            ..request.clone()
+        };
+        let result = v1_7::PutFileRequest::try_from(with_header.clone());
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+        round_trip::<v1_8::PutFileRequest, _>(&with_header);
+
+        let delete = current::PutFileRequest {
+            delete: true,
+            ..request
+        };
+        let result = v1_7::PutFileRequest::try_from(delete.clone());
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+        let result = v1_10::PutFileRequest::try_from(delete);
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+    }
+
+    #[test]
+    fn rejects_put_file_headers_from_1_7() {
+        let request = v1_7::PutFileRequest {
+            header: Some(v1_7::Metadata::default()),
+            ..Default::default()
+        };
+        let result = current::PutFileRequest::try_from(request);
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+    }
+}
@@ -0,0 +1,525 @@
+use std::convert::TryFrom;
+use std::mem;
+
+use tonic::transport::Channel;
+use tonic::Interceptor;
+
+use super::{convert, downgrade, transcode, unsupported, LegacyChannel};
+use crate::pps as current;
+use crate::pps_1_10 as v1_10;
+use crate::pps_1_7 as v1_7;
+use crate::pps_1_8 as v1_8;
+use crate::pps_1_9 as v1_9;
+use crate::version::ApiVersion;
+use crate::{Error, RetryPolicy};
+
+convert_messages!(current <=> [v1_7, v1_8, v1_9, v1_10] [
+    Job,
+    Pipeline,
+    DatumInfo,
+    LogMessage,
+    CreateJobRequest,
+    InspectJobRequest,
+    ListJobRequest,
+    FlushJobRequest,
+    DeleteJobRequest,
+    StopJobRequest,
+    InspectDatumRequest,
+    ListDatumRequest,
+    ListDatumResponse,
+    ListDatumStreamResponse,
+    RestartDatumRequest,
+    InspectPipelineRequest,
+    ListPipelineRequest,
+    DeletePipelineRequest,
+    StartPipelineRequest,
+    StopPipelineRequest,
+    GetLogsRequest,
+    GarbageCollectRequest,
+    GarbageCollectResponse,
+    ActivateAuthRequest,
+    ActivateAuthResponse,
+]);
+
+// 1.7 and 1.8 take PFS inputs as atom inputs, and 1.7 counts GPUs rather
+// than specifying them, so `convert_pipelines!` converts these for them
+convert_messages!(current <=> [v1_9, v1_10] [
+    Input,
+    JobInfo,
+    JobInfos,
+    PipelineInfo,
+    PipelineInfos,
+    CreatePipelineRequest,
+]);
+
+convert_messages!(current <=> [v1_8, v1_9, v1_10] [ResourceSpec]);
+
+/// The type of GPU 1.7 requests when given a number of them.
+const NVIDIA_GPU: &str = "nvidia.com/gpu";
+
+impl TryFrom<current::Input> for v1_7::Input {
+    type Error = Error;
+
+    fn try_from(mut input: current::Input) -> Result<Self, Error> {
+        let pfs = input.pfs.take();
+        let cross = mem::take(&mut input.cross);
+        let union = mem::take(&mut input.union);
+        // Fails on a join, which 1.7 doesn't have
+        let mut legacy: Self = downgrade(&input)?;
+        legacy.atom = pfs.map(atom_input::<Self>).transpose()?;
+        legacy.cross = cross.into_iter().map(convert).collect::<Result<_, _>>()?;
+        legacy.union = union.into_iter().map(convert).collect::<Result<_, _>>()?;
+        Ok(legacy)
+    }
+}
+
+impl TryFrom<current::Input> for v1_8::Input {
+    type Error = Error;
+
+    fn try_from(input: current::Input) -> Result<Self, Error> {
+        // 1.8 has `pfs` under the same field number, so it's only missing
+        // joins and the fields `PfsInput` gained since
+        downgrade(&input)
+    }
+}
+
+impl TryFrom<current::ResourceSpec> for v1_7::ResourceSpec {
+    type Error = Error;
+
+    fn try_from(mut spec: current::ResourceSpec) -> Result<Self, Error> {
+        let gpu = match spec.gpu.take() {
+            None => 0,
+            Some(gpu) if gpu.r#type.is_empty() || gpu.r#type == NVIDIA_GPU => gpu.number,
+            Some(gpu) => return Err(unsupported::<Self>(&format!("GPUs of type `{}`", gpu.r#type))),
+        };
+        let mut legacy: Self = downgrade(&spec)?;
+        legacy.gpu = gpu;
+        Ok(legacy)
+    }
+}
+
+impl TryFrom<v1_7::ResourceSpec> for current::ResourceSpec {
+    type Error = Error;
+
+    fn try_from(mut spec: v1_7::ResourceSpec) -> Result<Self, Error> {
+        let gpu = mem::take(&mut spec.gpu);
+        let mut current: Self = transcode(&spec)?;
+        if gpu != 0 {
+            current.gpu = Some(current::GpuSpec {
+                r#type: NVIDIA_GPU.into(),
+                number: gpu,
+            });
+        }
+        Ok(current)
+    }
+}
+
+/// Implements `TryFrom` from the given versions' `Input`s, which may take
+/// PFS inputs as atom inputs, and in both directions between the messages
+/// holding inputs and resource specs in the current bindings and their
+/// equivalents in those versions.
+macro_rules! convert_pipelines {
+    ($($legacy:ident),*) => {
+        $(
+            impl TryFrom<$legacy::Input> for current::Input {
+                type Error = Error;
+
+                fn try_from(mut input: $legacy::Input) -> Result<Self, Error> {
+                    let atom = input.atom.take();
+                    let cross = mem::take(&mut input.cross);
+                    let union = mem::take(&mut input.union);
+                    let mut current: Self = transcode(&input)?;
+                    if let Some(atom) = atom {
+                        if current.pfs.is_some() {
+                            return Err(Error::Conversion(format!(
+                                "`{}::Input` sets both an atom and a PFS input",
+                                stringify!($legacy)
+                            )));
+                        }
+                        current.pfs = Some(current::PfsInput {
+                            name: atom.name,
+                            repo: atom.repo,
+                            branch: atom.branch,
+                            commit: atom.commit,
+                            glob: atom.glob,
+                            lazy: atom.lazy,
+                            empty_files: atom.empty_files,
+                            ..Default::default()
+                        });
+                    }
+                    current.cross = cross.into_iter().map(convert).collect::<Result<_, _>>()?;
+                    current.union = union.into_iter().map(convert).collect::<Result<_, _>>()?;
+                    Ok(current)
+                }
+            }
+
+            convert_pipelines!(@pipeline $legacy CreatePipelineRequest);
+            convert_pipelines!(@pipeline $legacy PipelineInfo);
+            convert_pipelines!(@pipeline $legacy JobInfo);
+            convert_pipelines!(@list $legacy PipelineInfos pipeline_info);
+            convert_pipelines!(@list $legacy JobInfos job_info);
+        )*
+    };
+    (@pipeline $legacy:ident $message:ident) => {
+        impl TryFrom<current::$message> for $legacy::$message {
+            type Error = Error;
+
+            fn try_from(mut message: current::$message) -> Result<Self, Error> {
+                let input = message.input.take();
+                let requests = message.resource_requests.take();
+                let limits = message.resource_limits.take();
+                // Fails on sidecar resource limits, which neither version has
+                let mut legacy: Self = downgrade(&message)?;
+                legacy.input = input.map(convert).transpose()?;
+                legacy.resource_requests = requests.map(convert).transpose()?;
+                legacy.resource_limits = limits.map(convert).transpose()?;
+                Ok(legacy)
+            }
+        }
+
+        impl TryFrom<$legacy::$message> for current::$message {
+            type Error = Error;
+
+            fn try_from(mut message: $legacy::$message) -> Result<Self, Error> {
+                let input = message.input.take();
+                let requests = message.resource_requests.take();
+                let limits = message.resource_limits.take();
+                let mut current: Self = transcode(&message)?;
+                current.input = input.map(convert).transpose()?;
+                current.resource_requests = requests.map(convert).transpose()?;
+                current.resource_limits = limits.map(convert).transpose()?;
+                Ok(current)
+            }
+        }
+    };
+    (@list $legacy:ident $message:ident $items:ident) => {
+        impl TryFrom<current::$message> for $legacy::$message {
+            type Error = Error;
+
+            fn try_from(message: current::$message) -> Result<Self, Error> {
+                Ok($legacy::$message {
+                    $items: message.$items.into_iter().map(convert).collect::<Result<_, _>>()?,
+                })
+            }
+        }
+
+        impl TryFrom<$legacy::$message> for current::$message {
+            type Error = Error;
+
+            fn try_from(message: $legacy::$message) -> Result<Self, Error> {
+                Ok(current::$message {
+                    $items: message.$items.into_iter().map(convert).collect::<Result<_, _>>()?,
+                })
+            }
+        }
+    };
+}
+
+convert_pipelines!(v1_7, v1_8);
+
+/// Converts a PFS input to an atom input of `M`, for 1.7, which only has
+/// those.
+fn atom_input<M>(pfs: current::PfsInput) -> Result<v1_7::AtomInput, Error> {
+    if !pfs.join_on.is_empty() {
+        return Err(unsupported::<M>("`join_on`"));
+    }
+    if pfs.s3 {
+        return Err(unsupported::<M>("S3 inputs"));
+    }
+    Ok(v1_7::AtomInput {
+        name: pfs.name,
+        repo: pfs.repo,
+        branch: pfs.branch,
+        commit: pfs.commit,
+        glob: pfs.glob,
+        lazy: pfs.lazy,
+        empty_files: pfs.empty_files,
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Inner {
+    V1_7(v1_7::api_client::ApiClient<LegacyChannel>),
+    V1_8(v1_8::api_client::ApiClient<LegacyChannel>),
+    V1_9(v1_9::api_client::ApiClient<LegacyChannel>),
+    V1_10(v1_10::api_client::ApiClient<LegacyChannel>),
+    V1_11(current::api_client::ApiClient<Channel>),
+}
+
+/// A PPS client that works with any version of pachd this library supports.
+/// Create one via `Client::versioned_pps`.
+#[derive(Clone, Debug)]
+pub struct PpsClient {
+    inner: Inner,
+    version: ApiVersion,
+    retry: Option<RetryPolicy>,
+}
+
+impl PpsClient {
+    pub(crate) fn new(
+        channel: Channel,
+        interceptor: Interceptor,
+        version: ApiVersion,
+        retry: Option<RetryPolicy>,
+    ) -> Self {
+        let legacy = LegacyChannel::new(channel.clone());
+        let inner = match version {
+            ApiVersion::V1_7 => Inner::V1_7(v1_7::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_8 => Inner::V1_8(v1_8::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_9 => Inner::V1_9(v1_9::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_10 => Inner::V1_10(v1_10::api_client::ApiClient::with_interceptor(legacy, interceptor)),
+            ApiVersion::V1_11 => Inner::V1_11(current::api_client::ApiClient::with_interceptor(channel, interceptor)),
+        };
+        PpsClient { inner, version, retry }
+    }
+
+    /// Gets the API version this client speaks.
+    pub fn api_version(&self) -> ApiVersion {
+        self.version
+    }
+
+    unary!(create_job(CreateJobRequest) -> current::Job);
+    unary!(idempotent inspect_job(InspectJobRequest) -> current::JobInfo);
+    unary!(idempotent list_job(ListJobRequest) -> current::JobInfos);
+    server_streaming!(list_job_stream(ListJobRequest) -> current::JobInfo);
+    server_streaming!(flush_job(FlushJobRequest) -> current::JobInfo);
+    unary!(delete_job(DeleteJobRequest) -> ());
+    unary!(stop_job(StopJobRequest) -> ());
+    unary!(idempotent inspect_datum(InspectDatumRequest) -> current::DatumInfo);
+    unary!(idempotent list_datum(ListDatumRequest) -> current::ListDatumResponse);
+    server_streaming!(list_datum_stream(ListDatumRequest) -> current::ListDatumStreamResponse);
+    unary!(restart_datum(RestartDatumRequest) -> ());
+    unary!(create_pipeline(CreatePipelineRequest) -> ());
+    unary!(idempotent inspect_pipeline(InspectPipelineRequest) -> current::PipelineInfo);
+    unary!(idempotent list_pipeline(ListPipelineRequest) -> current::PipelineInfos);
+    unary!(delete_pipeline(DeletePipelineRequest) -> ());
+    unary!(start_pipeline(StartPipelineRequest) -> ());
+    unary!(stop_pipeline(StopPipelineRequest) -> ());
+    server_streaming!(get_logs(GetLogsRequest) -> current::LogMessage);
+    unary!(garbage_collect(GarbageCollectRequest) -> current::GarbageCollectResponse);
+    unary!(activate_auth(ActivateAuthRequest) -> current::ActivateAuthResponse);
+
+    /// Deletes every pipeline and job.
+    pub async fn delete_all(&mut self) -> Result<(), Error> {
+        match &mut self.inner {
+            Inner::V1_7(client) => client.delete_all(()).await?,
+            Inner::V1_8(client) => client.delete_all(()).await?,
+            Inner::V1_9(client) => client.delete_all(()).await?,
+            Inner::V1_10(client) => client.delete_all(()).await?,
+            Inner::V1_11(client) => client.delete_all(()).await?,
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    /// Converts `message` to a legacy version and back, checking that it
+    /// comes back unchanged, and returns the legacy message.
+    fn round_trip<L, C>(message: &C) -> L
+    where
+        L: TryFrom<C, Error = Error> + Clone,
+        C: TryFrom<L, Error = Error> + Clone + PartialEq + Debug,
+    {
+        let legacy = L::try_from(message.clone()).expect("converting to the legacy version");
+        assert_eq!(&C::try_from(legacy.clone()).expect("converting back"), message);
+        legacy
+    }
+
+    fn pfs_input(repo: &str) -> current::Input {
+        current::Input {
+            pfs: Some(current::PfsInput {
+                repo: repo.into(),
+                branch: "master".into(),
+                glob: "/*".into(),
+                empty_files: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn cross_input() -> current::Input {
+        current::Input {
+            cross: vec![pfs_input("images"), pfs_input("labels")],
+            ..Default::default()
+        }
+    }
+
+    fn gpus(number: i64) -> current::ResourceSpec {
+        current::ResourceSpec {
+            memory: "1G".into(),
+            gpu: Some(current::GpuSpec {
+                r#type: NVIDIA_GPU.into(),
+                number,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn assert_unconvertible<L: TryFrom<C, Error = Error> + Debug, C>(message: C) {
+        let result = L::try_from(message);
+        assert!(matches!(result, Err(Error::Conversion(_))), "{:?}", result);
+    }
+
+    #[test]
+    fn converts_pfs_inputs_to_atom_inputs() {
+        let input = cross_input();
+
+        let legacy = round_trip::<v1_7::Input, _>(&input);
+        let atom = legacy.cross[1].atom.as_ref().unwrap();
+        assert_eq!(atom.repo, "labels");
+        assert!(atom.empty_files);
+        round_trip::<v1_8::Input, _>(&input);
+        round_trip::<v1_9::Input, _>(&input);
+        round_trip::<v1_10::Input, _>(&input);
+    }
+
+    #[test]
+    fn converts_atom_inputs_from_1_8() {
+        let legacy = v1_8::Input {
+            union: vec![v1_8::Input {
+                atom: Some(v1_8::AtomInput {
+                    repo: "images".into(),
+                    branch: "master".into(),
+                    glob: "/*".into(),
+                    empty_files: true,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let input = current::Input::try_from(legacy).unwrap();
+        assert_eq!(input.union, vec![pfs_input("images")]);
+    }
+
+    #[test]
+    fn rejects_inputs_older_versions_lack() {
+        let join = current::Input {
+            join: vec![pfs_input("images"), pfs_input("labels")],
+            ..Default::default()
+        };
+        assert_unconvertible::<v1_7::Input, _>(join.clone());
+        assert_unconvertible::<v1_8::Input, _>(join.clone());
+        round_trip::<v1_9::Input, _>(&join);
+
+        let mut join_on = pfs_input("images");
+        join_on.pfs.as_mut().unwrap().join_on = "$1".into();
+        let nested = current::Input {
+            cross: vec![join_on.clone()],
+            ..Default::default()
+        };
+        assert_unconvertible::<v1_7::Input, _>(nested);
+        assert_unconvertible::<v1_8::Input, _>(join_on);
+    }
+
+    #[test]
+    fn converts_gpu_counts() {
+        let spec = gpus(2);
+
+        let legacy = round_trip::<v1_7::ResourceSpec, _>(&spec);
+        assert_eq!(legacy.gpu, 2);
+        round_trip::<v1_8::ResourceSpec, _>(&spec);
+        round_trip::<v1_9::ResourceSpec, _>(&spec);
+        round_trip::<v1_10::ResourceSpec, _>(&spec);
+
+        let without = current::ResourceSpec {
+            cpu: 0.5,
+            ..Default::default()
+        };
+        assert_eq!(round_trip::<v1_7::ResourceSpec, _>(&without).gpu, 0);
+    }
+
+    #[test]
+    fn rejects_other_gpus_for_1_7() {
+        let spec = current::ResourceSpec {
+            gpu: Some(current::GpuSpec {
+                r#type: "amd.com/gpu".into(),
+                number: 1,
+            }),
+            ..Default::default()
+        };
+        assert_unconvertible::<v1_7::ResourceSpec, _>(spec.clone());
+        round_trip::<v1_8::ResourceSpec, _>(&spec);
+    }
+
+    #[test]
+    fn converts_create_pipeline_request() {
+        let request = current::CreatePipelineRequest {
+            pipeline: Some(current::Pipeline { name: "edges".into() }),
+            output_branch: "master".into(),
+            input: Some(cross_input()),
+            resource_limits: Some(gpus(1)),
+            ..Default::default()
+        };
+
+        let legacy = round_trip::<v1_7::CreatePipelineRequest, _>(&request);
+        assert!(legacy.input.unwrap().cross[0].atom.is_some());
+        assert_eq!(legacy.resource_limits.unwrap().gpu, 1);
+        round_trip::<v1_8::CreatePipelineRequest, _>(&request);
+        round_trip::<v1_9::CreatePipelineRequest, _>(&request);
+        round_trip::<v1_10::CreatePipelineRequest, _>(&request);
+
+        let sidecar = current::CreatePipelineRequest {
+            sidecar_resource_limits: Some(gpus(1)),
+            ..request
+        };
+        assert_unconvertible::<v1_7::CreatePipelineRequest, _>(sidecar.clone());
+        assert_unconvertible::<v1_8::CreatePipelineRequest, _>(sidecar.clone());
+        assert_unconvertible::<v1_9::CreatePipelineRequest, _>(sidecar.clone());
+        assert_unconvertible::<v1_10::CreatePipelineRequest, _>(sidecar);
+    }
+
+    #[test]
+    fn converts_pipeline_infos() {
+        let info = current::PipelineInfo {
+            pipeline: Some(current::Pipeline { name: "edges".into() }),
+            input: Some(pfs_input("images")),
+            resource_requests: Some(gpus(1)),
+            ..Default::default()
+        };
+
+        let legacy = round_trip::<v1_7::PipelineInfo, _>(&info);
+        assert_eq!(legacy.input.unwrap().atom.unwrap().repo, "images");
+        round_trip::<v1_8::PipelineInfo, _>(&info);
+        round_trip::<v1_9::PipelineInfo, _>(&info);
+        round_trip::<v1_10::PipelineInfo, _>(&info);
+
+        let infos = current::PipelineInfos {
+            pipeline_info: vec![info],
+        };
+        round_trip::<v1_7::PipelineInfos, _>(&infos);
+        round_trip::<v1_8::PipelineInfos, _>(&infos);
+        round_trip::<v1_9::PipelineInfos, _>(&infos);
+        round_trip::<v1_10::PipelineInfos, _>(&infos);
+    }
+
+    #[test]
+    fn converts_job_infos() {
+        let info = current::JobInfo {
+            job: Some(current::Job { id: "abc".into() }),
+            pipeline: Some(current::Pipeline { name: "edges".into() }),
+            input: Some(cross_input()),
+            resource_limits: Some(gpus(1)),
+            ..Default::default()
+        };
+
+        let legacy = round_trip::<v1_7::JobInfo, _>(&info);
+        assert_eq!(legacy.resource_limits.unwrap().gpu, 1);
+        round_trip::<v1_8::JobInfo, _>(&info);
+        round_trip::<v1_9::JobInfo, _>(&info);
+        round_trip::<v1_10::JobInfo, _>(&info);
+
+        let infos = current::JobInfos { job_info: vec![info] };
+        round_trip::<v1_7::JobInfos, _>(&infos);
+        round_trip::<v1_8::JobInfos, _>(&infos);
+        round_trip::<v1_9::JobInfos, _>(&infos);
+        round_trip::<v1_10::JobInfos, _>(&infos);
+    }
+}